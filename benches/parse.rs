@@ -1,8 +1,42 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 use criterion::{criterion_group, criterion_main};
 use geo_traits::to_geo::ToGeoGeometry;
-use geo_types::Geometry;
+use geo_traits::{GeometryTrait, GeometryType, LineStringTrait, MultiPolygonTrait, PolygonTrait};
+use geo_types::{Geometry, GeometryCollection, MultiPolygon};
 use wkt::TryFromWkt;
 
+/// The system allocator, counting each allocation so that the benchmarks can show which parses
+/// allocate
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// The number of allocations made while parsing each of `bufs`
+fn allocations_per_parse(bufs: &[Vec<u8>]) -> f64 {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for buf in bufs {
+        black_box(wkb::reader::read_wkb(buf).unwrap());
+    }
+    (ALLOCATIONS.load(Ordering::Relaxed) - before) as f64 / bufs.len() as f64
+}
+
 fn load_small_wkt() -> Geometry {
     let s = include_str!("./small.wkt");
     Geometry::try_from_wkt_str(s).unwrap()
@@ -51,6 +85,74 @@ fn bench_parse(c: &mut criterion::Criterion) {
         });
     });
 
+    // Parsing a Polygon or MultiPolygon with few rings and polygons does not allocate, while a
+    // GeometryCollection of the same polygons still keeps its members in a `Vec`, as every
+    // Polygon and MultiPolygon once did
+    let small_polygons = vec![small_wkb.clone(); 1000];
+    c.bench_function("parse 1000 small polygons", |bencher| {
+        bencher.iter(|| {
+            for buf in &small_polygons {
+                black_box(wkb::reader::read_wkb(buf).unwrap());
+            }
+        });
+    });
+
+    let Geometry::Polygon(small_polygon) = &small else {
+        unreachable!()
+    };
+    let small_multi_polygon =
+        Geometry::MultiPolygon(MultiPolygon::new(vec![small_polygon.clone(); 4]));
+    let small_multi_polygons = vec![to_wkb(&small_multi_polygon); 1000];
+    c.bench_function("parse 1000 small multi polygons", |bencher| {
+        bencher.iter(|| {
+            for buf in &small_multi_polygons {
+                black_box(wkb::reader::read_wkb(buf).unwrap());
+            }
+        });
+    });
+
+    let members = vec![Geometry::Polygon(small_polygon.clone()); 4];
+    let small_collection = Geometry::GeometryCollection(GeometryCollection::new_from(members));
+    let small_collections = vec![to_wkb(&small_collection); 1000];
+    c.bench_function("parse 1000 small collections", |bencher| {
+        bencher.iter(|| {
+            for buf in &small_collections {
+                black_box(wkb::reader::read_wkb(buf).unwrap());
+            }
+        });
+    });
+
+    for (name, bufs) in [
+        ("small polygon", &small_polygons),
+        ("small multi polygon", &small_multi_polygons),
+        ("small collection", &small_collections),
+    ] {
+        println!(
+            "{name}: {} allocations per parse",
+            allocations_per_parse(bufs)
+        );
+    }
+
+    c.bench_function("parse and count 1000 small multi polygons", |bencher| {
+        bencher.iter(|| {
+            let mut num_coords = 0;
+            for buf in &small_multi_polygons {
+                let wkb_geom = wkb::reader::read_wkb(buf).unwrap();
+                let GeometryType::MultiPolygon(multi_polygon) = wkb_geom.as_type() else {
+                    unreachable!()
+                };
+                for polygon in multi_polygon.polygons() {
+                    num_coords += polygon.exterior().map_or(0, |ring| ring.num_coords());
+                    num_coords += polygon
+                        .interiors()
+                        .map(|ring| ring.num_coords())
+                        .sum::<usize>();
+                }
+            }
+            black_box(num_coords)
+        });
+    });
+
     c.bench_function("encode small", |bencher| {
         bencher.iter(|| {
             let mut buf = Vec::new();
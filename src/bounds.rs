@@ -0,0 +1,126 @@
+//! Bounding box computation shared by the formats that embed an envelope.
+
+use geo_traits::{
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    LineTrait, MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+    RectTrait, TriangleTrait,
+};
+
+/// The minimum and maximum of each of the x, y, z, and m ordinates of a geometry.
+///
+/// Coordinates with a NaN x value, which are used to encode empty points, are skipped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Bounds {
+    min: [f64; 4],
+    max: [f64; 4],
+}
+
+impl Bounds {
+    pub(crate) fn new() -> Self {
+        Self {
+            min: [f64::INFINITY; 4],
+            max: [f64::NEG_INFINITY; 4],
+        }
+    }
+
+    /// Compute the bounds of a geometry
+    pub(crate) fn of_geometry(geom: &impl GeometryTrait<T = f64>) -> Self {
+        let mut bounds = Self::new();
+        bounds.add_geometry(geom);
+        bounds
+    }
+
+    /// Whether no coordinates have been added
+    pub(crate) fn is_empty(&self) -> bool {
+        self.min[0] > self.max[0]
+    }
+
+    /// The minimum and maximum x values
+    pub(crate) fn x(&self) -> (f64, f64) {
+        (self.min[0], self.max[0])
+    }
+
+    /// The minimum and maximum y values
+    pub(crate) fn y(&self) -> (f64, f64) {
+        (self.min[1], self.max[1])
+    }
+
+    /// The minimum and maximum z values, if any coordinate had a z value
+    pub(crate) fn z(&self) -> Option<(f64, f64)> {
+        self.range(2)
+    }
+
+    /// The minimum and maximum m values, if any coordinate had an m value
+    pub(crate) fn m(&self) -> Option<(f64, f64)> {
+        self.range(3)
+    }
+
+    fn range(&self, i: usize) -> Option<(f64, f64)> {
+        (self.min[i] <= self.max[i]).then_some((self.min[i], self.max[i]))
+    }
+
+    fn add(&mut self, i: usize, value: f64) {
+        // f64::min and f64::max ignore NaN
+        self.min[i] = self.min[i].min(value);
+        self.max[i] = self.max[i].max(value);
+    }
+
+    pub(crate) fn add_coord(&mut self, coord: &impl CoordTrait<T = f64>) {
+        if coord.x().is_nan() {
+            return;
+        }
+        self.add(0, coord.x());
+        self.add(1, coord.y());
+        match coord.dim() {
+            Dimensions::Xyz | Dimensions::Unknown(3) => self.add(2, coord.nth_or_panic(2)),
+            Dimensions::Xym => self.add(3, coord.nth_or_panic(2)),
+            Dimensions::Xyzm | Dimensions::Unknown(4) => {
+                self.add(2, coord.nth_or_panic(2));
+                self.add(3, coord.nth_or_panic(3));
+            }
+            _ => (),
+        }
+    }
+
+    fn add_line_string(&mut self, line_string: &impl LineStringTrait<T = f64>) {
+        line_string
+            .coords()
+            .for_each(|coord| self.add_coord(&coord));
+    }
+
+    fn add_polygon(&mut self, polygon: &impl PolygonTrait<T = f64>) {
+        if let Some(exterior) = polygon.exterior() {
+            self.add_line_string(&exterior);
+        }
+        polygon
+            .interiors()
+            .for_each(|interior| self.add_line_string(&interior));
+    }
+
+    pub(crate) fn add_geometry(&mut self, geom: &impl GeometryTrait<T = f64>) {
+        use GeometryType::*;
+        match geom.as_type() {
+            Point(p) => {
+                if let Some(coord) = p.coord() {
+                    self.add_coord(&coord);
+                }
+            }
+            LineString(ls) => self.add_line_string(ls),
+            Polygon(p) => self.add_polygon(p),
+            MultiPoint(mp) => mp.points().for_each(|p| {
+                if let Some(coord) = p.coord() {
+                    self.add_coord(&coord);
+                }
+            }),
+            MultiLineString(ml) => ml.line_strings().for_each(|ls| self.add_line_string(&ls)),
+            MultiPolygon(mp) => mp.polygons().for_each(|p| self.add_polygon(&p)),
+            GeometryCollection(gc) => gc.geometries().for_each(|g| self.add_geometry(&g)),
+            Rect(r) => {
+                self.add_coord(&r.min());
+                self.add_coord(&r.max());
+            }
+            Triangle(tri) => tri.coords().iter().for_each(|coord| self.add_coord(coord)),
+            Line(line) => line.coords().iter().for_each(|coord| self.add_coord(coord)),
+        }
+    }
+}
@@ -1,9 +1,7 @@
-use std::io::Cursor;
-
-use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
-use crate::error::{WkbError, WkbResult};
+use crate::error::{WkbError, WkbPath, WkbResult};
+use crate::reader::GeometryType;
 
 /// Bit flag for EWKB Geometry with a z coordinate
 const EWKB_FLAG_Z: u32 = 0x80000000;
@@ -42,6 +40,16 @@ impl Dimension {
             Self::Xyzm => 4,
         }
     }
+
+    /// Whether coordinates of this dimension have a z value
+    pub(crate) fn has_z(&self) -> bool {
+        matches!(self, Self::Xyz | Self::Xyzm)
+    }
+
+    /// Whether coordinates of this dimension have an m value
+    pub(crate) fn has_m(&self) -> bool {
+        matches!(self, Self::Xym | Self::Xyzm)
+    }
 }
 
 impl TryFrom<geo_traits::Dimensions> for Dimension {
@@ -96,34 +104,45 @@ impl WkbGeometryCode {
         self.0 & EWKB_FLAG_SRID == EWKB_FLAG_SRID
     }
 
+    /// Decode the geometry type and dimension from this code.
+    ///
+    /// The code is split into the EWKB flag bits, the ISO thousands offset, and the base type.
+    /// Codes with an unknown base type or dimension offset, codes that mix ISO dimension offsets
+    /// with EWKB dimension flags, and codes for abstract types are rejected.
     pub(crate) fn get_type(&self) -> WkbResult<WkbType> {
         let code = self.0;
-        let mut dim = Dimension::Xy;
+
+        // For extended WKB, higher dimensions are provided via bit flags
+        let is_ewkb_z = code & EWKB_FLAG_Z == EWKB_FLAG_Z;
+        let is_ewkb_m = code & EWKB_FLAG_M == EWKB_FLAG_M;
+
+        // Strip any extended WKB flags before interpreting the ISO code
+        let iso_code = code & !(EWKB_FLAG_Z | EWKB_FLAG_M | EWKB_FLAG_SRID);
 
         // For ISO WKB:
         // Values 1, 2, 3 are 2D,
         // 1001, 1002, 1003 are XYZ,
         // 2001 etc are XYM,
         // 3001 etc are XYZM
-        match code / 1000 {
-            1 => dim = Dimension::Xyz,
-            2 => dim = Dimension::Xym,
-            3 => dim = Dimension::Xyzm,
-            _ => (),
+        let iso_dim = match iso_code / 1000 {
+            0 => None,
+            1 => Some(Dimension::Xyz),
+            2 => Some(Dimension::Xym),
+            3 => Some(Dimension::Xyzm),
+            _ => return Err(Self::unknown_type_code(code)),
         };
 
-        // For extended WKB, higher dimensions are provided via bit flags
-        let is_ewkb_z = code & EWKB_FLAG_Z == EWKB_FLAG_Z;
-        let is_ewkb_m = code & EWKB_FLAG_M == EWKB_FLAG_M;
-
-        match (is_ewkb_z, is_ewkb_m) {
-            (true, true) => dim = Dimension::Xyzm,
-            (true, false) => dim = Dimension::Xyz,
-            (false, true) => dim = Dimension::Xym,
-            _ => (),
-        }
+        let dim = match (iso_dim, is_ewkb_z, is_ewkb_m) {
+            (Some(dim), false, false) => dim,
+            // An ISO dimension offset combined with EWKB dimension flags is contradictory
+            (Some(_), _, _) => return Err(Self::unknown_type_code(code)),
+            (None, false, false) => Dimension::Xy,
+            (None, true, false) => Dimension::Xyz,
+            (None, false, true) => Dimension::Xym,
+            (None, true, true) => Dimension::Xyzm,
+        };
 
-        let typ = match code & 0x7 {
+        let typ = match iso_code % 1000 {
             1 => WkbType::Point(dim),
             2 => WkbType::LineString(dim),
             3 => WkbType::Polygon(dim),
@@ -131,15 +150,29 @@ impl WkbGeometryCode {
             5 => WkbType::MultiLineString(dim),
             6 => WkbType::MultiPolygon(dim),
             7 => WkbType::GeometryCollection(dim),
-            _ => {
-                return Err(WkbError::General(format!(
-                    "WKB type code out of range. Got: {}",
-                    code
-                )))
-            }
+            8 => WkbType::CircularString(dim),
+            9 => WkbType::CompoundCurve(dim),
+            10 => WkbType::CurvePolygon(dim),
+            11 => WkbType::MultiCurve(dim),
+            12 => WkbType::MultiSurface(dim),
+            15 => WkbType::PolyhedralSurface(dim),
+            16 => WkbType::Tin(dim),
+            17 => WkbType::Triangle(dim),
+            // This includes 13 and 14, the abstract Curve and Surface types, which cannot be
+            // instantiated.
+            _ => return Err(Self::unknown_type_code(code)),
         };
         Ok(typ)
     }
+
+    #[cold]
+    fn unknown_type_code(code: u32) -> WkbError {
+        WkbError::UnknownTypeCode {
+            code,
+            offset: 0,
+            path: WkbPath::default(),
+        }
+    }
 }
 
 /// The various WKB types supported by this crate
@@ -159,36 +192,125 @@ pub(crate) enum WkbType {
     MultiPolygon(Dimension),
     /// A WKB GeometryCollection
     GeometryCollection(Dimension),
+    /// A WKB CircularString
+    CircularString(Dimension),
+    /// A WKB CompoundCurve
+    CompoundCurve(Dimension),
+    /// A WKB CurvePolygon
+    CurvePolygon(Dimension),
+    /// A WKB MultiCurve
+    MultiCurve(Dimension),
+    /// A WKB MultiSurface
+    MultiSurface(Dimension),
+    /// A WKB PolyhedralSurface
+    PolyhedralSurface(Dimension),
+    /// A WKB TIN
+    Tin(Dimension),
+    /// A WKB Triangle
+    Triangle(Dimension),
 }
 
 impl WkbType {
-    /// Construct from a byte slice representing a WKB geometry
-    pub(crate) fn from_buffer(buf: &[u8]) -> WkbResult<Self> {
-        let mut reader = Cursor::new(buf);
-        let byte_order = reader.read_u8()?;
-        let geometry_code = match byte_order {
-            0 => reader.read_u32::<BigEndian>()?,
-            1 => reader.read_u32::<LittleEndian>()?,
-            other => {
-                return Err(WkbError::General(format!(
-                    "Unexpected byte order: {}",
-                    other
-                )))
-            }
-        };
-        WkbGeometryCode(geometry_code).get_type()
+    /// The type code for a geometry type with the given dimension
+    pub(crate) fn new(geometry_type: GeometryType, dim: Dimension) -> Self {
+        match geometry_type {
+            GeometryType::Point => Self::Point(dim),
+            GeometryType::LineString => Self::LineString(dim),
+            GeometryType::Polygon => Self::Polygon(dim),
+            GeometryType::MultiPoint => Self::MultiPoint(dim),
+            GeometryType::MultiLineString => Self::MultiLineString(dim),
+            GeometryType::MultiPolygon => Self::MultiPolygon(dim),
+            GeometryType::GeometryCollection => Self::GeometryCollection(dim),
+            GeometryType::CircularString => Self::CircularString(dim),
+            GeometryType::CompoundCurve => Self::CompoundCurve(dim),
+            GeometryType::CurvePolygon => Self::CurvePolygon(dim),
+            GeometryType::MultiCurve => Self::MultiCurve(dim),
+            GeometryType::MultiSurface => Self::MultiSurface(dim),
+            GeometryType::PolyhedralSurface => Self::PolyhedralSurface(dim),
+            GeometryType::Tin => Self::Tin(dim),
+            GeometryType::Triangle => Self::Triangle(dim),
+        }
     }
 
+    /// The public geometry type corresponding to this type code
+    pub(crate) fn geometry_type(&self) -> GeometryType {
+        match self {
+            Self::Point(_) => GeometryType::Point,
+            Self::LineString(_) => GeometryType::LineString,
+            Self::Polygon(_) => GeometryType::Polygon,
+            Self::MultiPoint(_) => GeometryType::MultiPoint,
+            Self::MultiLineString(_) => GeometryType::MultiLineString,
+            Self::MultiPolygon(_) => GeometryType::MultiPolygon,
+            Self::GeometryCollection(_) => GeometryType::GeometryCollection,
+            Self::CircularString(_) => GeometryType::CircularString,
+            Self::CompoundCurve(_) => GeometryType::CompoundCurve,
+            Self::CurvePolygon(_) => GeometryType::CurvePolygon,
+            Self::MultiCurve(_) => GeometryType::MultiCurve,
+            Self::MultiSurface(_) => GeometryType::MultiSurface,
+            Self::PolyhedralSurface(_) => GeometryType::PolyhedralSurface,
+            Self::Tin(_) => GeometryType::Tin,
+            Self::Triangle(_) => GeometryType::Triangle,
+        }
+    }
+
+    /// The 2D geometry code, without any dimension offset or flags
+    fn base_code(&self) -> u32 {
+        match self {
+            Self::Point(_) => 1,
+            Self::LineString(_) => 2,
+            Self::Polygon(_) => 3,
+            Self::MultiPoint(_) => 4,
+            Self::MultiLineString(_) => 5,
+            Self::MultiPolygon(_) => 6,
+            Self::GeometryCollection(_) => 7,
+            Self::CircularString(_) => 8,
+            Self::CompoundCurve(_) => 9,
+            Self::CurvePolygon(_) => 10,
+            Self::MultiCurve(_) => 11,
+            Self::MultiSurface(_) => 12,
+            Self::PolyhedralSurface(_) => 15,
+            Self::Tin(_) => 16,
+            Self::Triangle(_) => 17,
+        }
+    }
+
+    pub(crate) fn dimension(&self) -> Dimension {
+        match self {
+            Self::Point(dim)
+            | Self::LineString(dim)
+            | Self::Polygon(dim)
+            | Self::MultiPoint(dim)
+            | Self::MultiLineString(dim)
+            | Self::MultiPolygon(dim)
+            | Self::GeometryCollection(dim)
+            | Self::CircularString(dim)
+            | Self::CompoundCurve(dim)
+            | Self::CurvePolygon(dim)
+            | Self::MultiCurve(dim)
+            | Self::MultiSurface(dim)
+            | Self::PolyhedralSurface(dim)
+            | Self::Tin(dim)
+            | Self::Triangle(dim) => *dim,
+        }
+    }
+
+    /// The ISO WKB geometry code
     pub(crate) fn as_geometry_code(&self) -> WkbGeometryCode {
-        let code = match self {
-            Self::Point(dim) => 1 + dim.as_u32_offset(),
-            Self::LineString(dim) => 2 + dim.as_u32_offset(),
-            Self::Polygon(dim) => 3 + dim.as_u32_offset(),
-            Self::MultiPoint(dim) => 4 + dim.as_u32_offset(),
-            Self::MultiLineString(dim) => 5 + dim.as_u32_offset(),
-            Self::MultiPolygon(dim) => 6 + dim.as_u32_offset(),
-            Self::GeometryCollection(dim) => 7 + dim.as_u32_offset(),
-        };
+        WkbGeometryCode(self.base_code() + self.dimension().as_u32_offset())
+    }
+
+    /// The extended WKB geometry code, with Z, M, and SRID bit flags
+    pub(crate) fn as_ewkb_geometry_code(&self, has_srid: bool) -> WkbGeometryCode {
+        let mut code = self.base_code();
+        match self.dimension() {
+            Dimension::Xy => (),
+            Dimension::Xyz => code |= EWKB_FLAG_Z,
+            Dimension::Xym => code |= EWKB_FLAG_M,
+            Dimension::Xyzm => code |= EWKB_FLAG_Z | EWKB_FLAG_M,
+        }
+        if has_srid {
+            code |= EWKB_FLAG_SRID;
+        }
         WkbGeometryCode(code)
     }
 }
@@ -199,6 +321,12 @@ impl From<WkbType> for u32 {
     }
 }
 
+impl From<WkbGeometryCode> for u32 {
+    fn from(value: WkbGeometryCode) -> Self {
+        value.0
+    }
+}
+
 /// Endianness
 #[derive(Debug, Clone, Copy, Default, TryFromPrimitive, IntoPrimitive, PartialEq)]
 #[repr(u8)]
@@ -209,3 +337,24 @@ pub enum Endianness {
     #[default]
     LittleEndian = 1,
 }
+
+/// Format `value` with at most `precision` decimal places, removing any trailing zeros.
+///
+/// The digits are written to `buf`, which is cleared first so that it can be reused for each
+/// value of a geometry.
+pub(crate) fn format_rounded(buf: &mut String, value: f64, precision: usize) -> &str {
+    use std::fmt::Write;
+
+    buf.clear();
+    // Writing to a String cannot fail
+    let _ = write!(buf, "{value:.precision$}");
+    let mut digits = buf.as_str();
+    if digits.contains('.') {
+        digits = digits.trim_end_matches('0').trim_end_matches('.');
+    }
+    // Small negative values that round to zero
+    if digits == "-0" {
+        digits = "0";
+    }
+    digits
+}
@@ -1,10 +1,16 @@
 //! Defines [`WkbError`], representing all errors returned by this crate.
 
 use std::borrow::Cow;
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
 use thiserror::Error;
 
+use crate::reader::{GeometryType, ReadLimit};
+
 /// Enum with all errors in this crate.
+///
+/// Errors raised while parsing WKB carry the absolute byte `offset` at which the problem was
+/// detected and a [`WkbPath`] to the nested geometry or coordinate being read, such as
+/// `MultiPolygon[3].rings[1].coords[17]`.
 #[derive(Error, Debug)]
 #[non_exhaustive]
 pub enum WkbError {
@@ -20,6 +26,156 @@ pub enum WkbError {
     #[error("General error: {0}")]
     General(String),
 
+    /// The buffer ended before the geometry was complete.
+    #[error(
+        "Unexpected end of WKB at byte {offset}{}: {needed} bytes needed, but only {available} remain",
+        located(path)
+    )]
+    UnexpectedEof {
+        /// The byte offset of the value that could not be read
+        offset: u64,
+        /// The number of bytes needed from `offset`
+        needed: u64,
+        /// The number of bytes remaining from `offset`
+        available: u64,
+        /// The location of the value that could not be read
+        path: WkbPath,
+    },
+
+    /// The byte order marker is neither `0` (big endian) nor `1` (little endian).
+    #[error("Invalid byte order {byte:#04x} at byte {offset}{}", located(path))]
+    InvalidByteOrder {
+        /// The invalid byte order marker
+        byte: u8,
+        /// The byte offset of the byte order marker
+        offset: u64,
+        /// The location of the geometry with the invalid byte order
+        path: WkbPath,
+    },
+
+    /// The WKB geometry type code is unknown or internally inconsistent, such as an ISO dimension
+    /// offset combined with EWKB dimension flags.
+    #[error(
+        "Unknown WKB geometry type code {code} ({code:#010x}) at byte {offset}{}",
+        located(path)
+    )]
+    UnknownTypeCode {
+        /// The raw geometry type code
+        code: u32,
+        /// The byte offset of the geometry type code
+        offset: u64,
+        /// The location of the geometry with the unknown type code
+        path: WkbPath,
+    },
+
+    /// An element count is larger than the rest of the buffer could possibly hold.
+    #[error(
+        "Element count {count} at byte {offset}{} exceeds the maximum of {max} that fits in the remaining buffer",
+        located(path)
+    )]
+    CountOverflow {
+        /// The number of elements declared in the WKB
+        count: u32,
+        /// The largest number of elements that could fit in the remaining buffer
+        max: u64,
+        /// The byte offset of the element count
+        offset: u64,
+        /// The location of the geometry declaring the count
+        path: WkbPath,
+    },
+
+    /// A nested geometry has a type that is not allowed in its parent, such as a Point inside a
+    /// CompoundCurve.
+    #[error(
+        "Expected {expected} at byte {offset}{}, found {found:?}",
+        located(path)
+    )]
+    UnexpectedType {
+        /// A description of the allowed geometry types
+        expected: &'static str,
+        /// The type that was found
+        found: GeometryType,
+        /// The byte offset of the nested geometry
+        offset: u64,
+        /// The location of the nested geometry
+        path: WkbPath,
+    },
+
+    /// A geometry is well-formed WKB but violates a constraint of its type, such as a Triangle
+    /// without exactly four points.
+    #[error("Invalid geometry at byte {offset}{}: {reason}", located(path))]
+    InvalidGeometry {
+        /// A description of the violated constraint
+        reason: &'static str,
+        /// The byte offset of the invalid geometry
+        offset: u64,
+        /// The location of the invalid geometry
+        path: WkbPath,
+    },
+
+    /// A limit set in [`ReadOptions`](crate::reader::ReadOptions) was exceeded.
+    #[error(
+        "The {limit} limit of {max} was exceeded at byte {offset}{}",
+        located(path)
+    )]
+    LimitExceeded {
+        /// The limit that was exceeded
+        limit: ReadLimit,
+        /// The configured value of the limit
+        max: usize,
+        /// The byte offset of the geometry or element count exceeding the limit
+        offset: u64,
+        /// The location of the geometry exceeding the limit
+        path: WkbPath,
+    },
+
+    /// The header of a container format that wraps WKB, such as GeoPackage, is invalid.
+    #[error("Invalid {format} header at byte {offset}: {reason}")]
+    InvalidHeader {
+        /// The name of the container format
+        format: &'static str,
+        /// A description of the problem
+        reason: &'static str,
+        /// The byte offset of the invalid value
+        offset: u64,
+    },
+
+    /// Hex-encoded WKB contains a character that is not a hex digit or an odd number of digits.
+    #[error("Invalid hex-encoded WKB at character {offset}: {reason}")]
+    InvalidHex {
+        /// The offset of the invalid character in the hex string
+        offset: u64,
+        /// A description of the problem
+        reason: &'static str,
+    },
+
+    /// Well-Known Text could not be parsed.
+    #[error("Invalid WKT at character {offset}: {reason}")]
+    InvalidWkt {
+        /// The byte offset of the problem in the WKT string
+        offset: u64,
+        /// A description of the problem
+        reason: &'static str,
+    },
+
+    /// A GeoJSON geometry object could not be parsed.
+    #[error("Invalid GeoJSON at byte {offset}: {reason}")]
+    InvalidGeoJson {
+        /// The byte offset of the problem in the JSON text
+        offset: u64,
+        /// A description of the problem
+        reason: &'static str,
+    },
+
+    /// The buffer continues after the end of the geometry.
+    #[error("{count} trailing bytes after the end of the WKB geometry at byte {offset}")]
+    TrailingBytes {
+        /// The byte offset where the geometry ended
+        offset: u64,
+        /// The number of bytes left over
+        count: u64,
+    },
+
     /// [std::io::Error]
     #[error(transparent)]
     IOError(#[from] std::io::Error),
@@ -29,5 +185,136 @@ pub enum WkbError {
     OverflowError(#[from] std::num::TryFromIntError),
 }
 
+impl WkbError {
+    /// The absolute byte offset at which this error was detected, if known
+    pub fn offset(&self) -> Option<u64> {
+        match self {
+            Self::UnexpectedEof { offset, .. }
+            | Self::InvalidByteOrder { offset, .. }
+            | Self::UnknownTypeCode { offset, .. }
+            | Self::CountOverflow { offset, .. }
+            | Self::UnexpectedType { offset, .. }
+            | Self::InvalidGeometry { offset, .. }
+            | Self::LimitExceeded { offset, .. }
+            | Self::InvalidHeader { offset, .. }
+            | Self::InvalidHex { offset, .. }
+            | Self::InvalidWkt { offset, .. }
+            | Self::InvalidGeoJson { offset, .. }
+            | Self::TrailingBytes { offset, .. } => Some(*offset),
+            _ => None,
+        }
+    }
+
+    /// The location of the nested geometry or coordinate at which this error was detected, if
+    /// known
+    pub fn path(&self) -> Option<&WkbPath> {
+        match self {
+            Self::UnexpectedEof { path, .. }
+            | Self::InvalidByteOrder { path, .. }
+            | Self::UnknownTypeCode { path, .. }
+            | Self::CountOverflow { path, .. }
+            | Self::UnexpectedType { path, .. }
+            | Self::InvalidGeometry { path, .. }
+            | Self::LimitExceeded { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Relocate an error raised while reading a nested value that starts `base_offset` bytes into
+    /// its parent.
+    ///
+    /// Errors are created relative to the innermost value being read and are moved outwards as
+    /// they propagate, so that no bookkeeping is needed while parsing succeeds.
+    pub(crate) fn nested(mut self, base_offset: u64, segment: Option<PathSegment>) -> Self {
+        match &mut self {
+            Self::UnexpectedEof { offset, path, .. }
+            | Self::InvalidByteOrder { offset, path, .. }
+            | Self::UnknownTypeCode { offset, path, .. }
+            | Self::CountOverflow { offset, path, .. }
+            | Self::UnexpectedType { offset, path, .. }
+            | Self::InvalidGeometry { offset, path, .. }
+            | Self::LimitExceeded { offset, path, .. } => {
+                *offset += base_offset;
+                if let Some(segment) = segment {
+                    path.push_outer(segment);
+                }
+            }
+            Self::TrailingBytes { offset, .. } | Self::InvalidHeader { offset, .. } => {
+                *offset += base_offset
+            }
+            _ => (),
+        }
+        self
+    }
+}
+
+/// A single step in a [`WkbPath`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PathSegment {
+    /// A geometry of the given type, such as `Polygon`
+    Geometry(GeometryType),
+    /// The member at the given index of a multi-geometry or collection, such as `[3]`
+    Member(usize),
+    /// The element at the given index of a named part of a geometry, such as `rings[1]`
+    Field(&'static str, usize),
+}
+
+/// The location of a nested geometry or coordinate within a WKB buffer, such as
+/// `MultiPolygon[3].rings[1].coords[17]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WkbPath {
+    /// Segments ordered from the innermost outwards, as they are added while an error propagates
+    segments: Vec<PathSegment>,
+}
+
+impl WkbPath {
+    /// Whether this path has no segments, which is the case for errors in the outermost header
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// The segments of this path, from the outermost geometry inwards
+    pub fn segments(&self) -> impl Iterator<Item = &PathSegment> {
+        self.segments.iter().rev()
+    }
+
+    fn push_outer(&mut self, segment: PathSegment) {
+        self.segments.push(segment);
+    }
+}
+
+impl fmt::Display for WkbPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.segments().enumerate() {
+            match segment {
+                PathSegment::Geometry(geometry_type) => {
+                    if i > 0 {
+                        f.write_str(".")?;
+                    }
+                    write!(f, "{:?}", geometry_type)?;
+                }
+                PathSegment::Member(index) => write!(f, "[{}]", index)?,
+                PathSegment::Field(name, index) => {
+                    if i > 0 {
+                        f.write_str(".")?;
+                    }
+                    write!(f, "{}[{}]", name, index)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Format a path as a suffix to an error message, or nothing if the path is empty
+fn located(path: &WkbPath) -> String {
+    if path.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", path)
+    }
+}
+
 /// Crate-specific result type.
 pub type WkbResult<T> = std::result::Result<T, WkbError>;
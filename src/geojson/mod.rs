@@ -0,0 +1,46 @@
+//! Convert between WKB and [RFC 7946](https://datatracker.ietf.org/doc/html/rfc7946) GeoJSON
+//! geometries.
+//!
+//! [`write_geojson`] writes any [`GeometryTrait`][geo_traits::GeometryTrait], including a parsed
+//! [`Wkb`][crate::reader::Wkb], as a GeoJSON geometry object without building intermediate
+//! objects. [`read_geojson`] and [`geojson_to_wkb`] go the other way, writing WKB as the JSON is
+//! parsed.
+//!
+//! ```
+//! use geo_types::line_string;
+//! use wkb::geojson::{write_geojson, GeoJsonWriteOptions};
+//! use wkb::reader::read_wkb;
+//! use wkb::writer::write_geometry;
+//!
+//! let line_string = line_string![(x: 1., y: 2.), (x: 5.6789, y: 4.)];
+//! let mut buf = Vec::new();
+//! write_geometry(&mut buf, &line_string, &Default::default()).unwrap();
+//! let geometry = read_wkb(&buf).unwrap();
+//!
+//! let options = GeoJsonWriteOptions {
+//!     precision: Some(2),
+//!     include_bbox: true,
+//!     ..Default::default()
+//! };
+//! let mut json = Vec::new();
+//! write_geojson(&mut json, &geometry, &options).unwrap();
+//! assert_eq!(
+//!     String::from_utf8(json).unwrap(),
+//!     r#"{"type":"LineString","bbox":[1,2,5.68,4],"coordinates":[[1,2],[5.68,4]]}"#
+//! );
+//! ```
+//!
+//! ```
+//! use wkb::geojson::read_geojson;
+//!
+//! let json = r#"{"coordinates":[[1.5,2,3],[4,5,6]],"type":"LineString"}"#;
+//! let mut buf = Vec::new();
+//! let geometry = read_geojson(json, &mut buf).unwrap();
+//! assert_eq!(geometry.to_string(), "LINESTRING Z (1.5 2 3,4 5 6)");
+//! ```
+
+mod parse;
+mod write;
+
+pub use parse::{geojson_to_wkb, read_geojson};
+pub use write::{write_geojson, GeoJsonWriteOptions};
@@ -0,0 +1,460 @@
+use std::borrow::Cow;
+
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+
+use crate::common::{Dimension, WkbType};
+use crate::error::{WkbError, WkbResult};
+use crate::reader::{GeometryType, Wkb};
+use crate::writer::text::{starts_number, TextInput, WkbOutput};
+use crate::writer::WriteOptions;
+use crate::Endianness;
+
+/// Parse a GeoJSON geometry object by converting it to little-endian ISO WKB in `buf`.
+///
+/// The previous contents of `buf` are replaced. See [`geojson_to_wkb`] for details.
+pub fn read_geojson<'a>(json: impl AsRef<[u8]>, buf: &'a mut Vec<u8>) -> WkbResult<Wkb<'a>> {
+    buf.clear();
+    Parser::<LittleEndian>::new(json.as_ref(), buf).parse(&WriteOptions::default())?;
+
+    let buf: &'a [u8] = buf;
+    Wkb::try_new(buf)
+}
+
+/// Convert a GeoJSON geometry object to WKB, appending it to `out`.
+///
+/// The geometry is written as it is parsed, without building intermediate objects.
+///
+/// Each geometry is 3D if its first position has a third element, and all of its other
+/// positions must then have one as well. Elements after the third are ignored. A
+/// GeometryCollection takes the dimension of its first member, and its members may differ in
+/// dimension, as they may in [`wkt_to_wkb`][crate::wkt::wkt_to_wkb]. A Point with an empty
+/// `coordinates` array is written as an empty Point with NaN coordinates.
+///
+/// Members of the geometry object may appear in any order, and members other than `type`,
+/// `coordinates`, and `geometries` are skipped. Invalid input is reported as
+/// [`WkbError::InvalidGeoJson`] with the byte offset of the problem. Nested GeometryCollections
+/// are limited to the default [`ReadOptions::max_depth`].
+///
+/// [`ReadOptions::max_depth`]: crate::reader::ReadOptions::max_depth
+pub fn geojson_to_wkb(
+    json: impl AsRef<[u8]>,
+    out: &mut Vec<u8>,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    match options.endianness {
+        Endianness::LittleEndian => Parser::<LittleEndian>::new(json.as_ref(), out).parse(options),
+        Endianness::BigEndian => Parser::<BigEndian>::new(json.as_ref(), out).parse(options),
+    }
+}
+
+struct Parser<'j, 'o, B: ByteOrder> {
+    input: TextInput<'j>,
+    out: WkbOutput<'o, B>,
+}
+
+impl<'j, 'o, B: ByteOrder> Parser<'j, 'o, B> {
+    fn new(json: &'j [u8], out: &'o mut Vec<u8>) -> Self {
+        Self {
+            input: TextInput::new(json, 0),
+            out: WkbOutput::new(out),
+        }
+    }
+
+    fn parse(mut self, options: &WriteOptions) -> WkbResult<()> {
+        self.geometry(options)?;
+        if self.input.peek().is_some() {
+            return Err(invalid_geojson(
+                self.input.pos,
+                "unexpected text after the geometry",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Parse a geometry object, returning its dimension
+    fn geometry(&mut self, options: &WriteOptions) -> WkbResult<Dimension> {
+        self.input.skip_whitespace();
+        let start = self.input.pos;
+        self.expect(b'{')?;
+        self.out.enter(start)?;
+        let result = self.members(start, options);
+        self.out.leave();
+        result
+    }
+
+    /// Parse the members of a geometry object that starts at `start`, returning its dimension
+    fn members(&mut self, start: usize, options: &WriteOptions) -> WkbResult<Dimension> {
+        let mut geometry_type = None;
+        // The offsets of `coordinates` and `geometries` values that came before the `type`
+        let mut deferred = [None, None];
+        let mut dim = None;
+        if !self.input.consume(b'}') {
+            loop {
+                self.input.skip_whitespace();
+                let key_offset = self.input.pos;
+                let key = self.string()?;
+                self.expect(b':')?;
+                match key.as_ref() {
+                    b"type" => {
+                        if geometry_type.is_some() {
+                            return Err(invalid_geojson(key_offset, "duplicate type member"));
+                        }
+                        geometry_type = Some(self.geometry_type()?);
+                    }
+                    key @ (b"coordinates" | b"geometries") => {
+                        let is_geometries = key == b"geometries";
+                        match geometry_type {
+                            Some(geometry_type)
+                                if is_geometries
+                                    == (geometry_type == GeometryType::GeometryCollection) =>
+                            {
+                                if dim.is_some() {
+                                    return Err(invalid_geojson(key_offset, "duplicate member"));
+                                }
+                                dim = Some(self.body(geometry_type, options)?);
+                            }
+                            Some(_) => self.skip_value()?,
+                            None => {
+                                self.input.skip_whitespace();
+                                deferred[usize::from(is_geometries)] = Some(self.input.pos);
+                                self.skip_value()?;
+                            }
+                        }
+                    }
+                    _ => self.skip_value()?,
+                }
+                if !self.input.consume(b',') {
+                    self.expect(b'}')?;
+                    break;
+                }
+            }
+        }
+
+        let Some(geometry_type) = geometry_type else {
+            return Err(invalid_geojson(start, "missing type member"));
+        };
+        if let Some(dim) = dim {
+            return Ok(dim);
+        }
+        let is_geometries = geometry_type == GeometryType::GeometryCollection;
+        let Some(offset) = deferred[usize::from(is_geometries)] else {
+            let reason = if is_geometries {
+                "missing geometries member"
+            } else {
+                "missing coordinates member"
+            };
+            return Err(invalid_geojson(start, reason));
+        };
+        let end = self.input.pos;
+        self.input.pos = offset;
+        let dim = self.body(geometry_type, options)?;
+        self.input.pos = end;
+        Ok(dim)
+    }
+
+    /// Write the header and parse the `coordinates` or `geometries` value of a geometry
+    fn body(
+        &mut self,
+        geometry_type: GeometryType,
+        options: &WriteOptions,
+    ) -> WkbResult<Dimension> {
+        let nested = options.nested();
+        if geometry_type == GeometryType::GeometryCollection {
+            let header_offset = self
+                .out
+                .header(WkbType::GeometryCollection(Dimension::Xy), options)?;
+
+            let mut first_dim = None;
+            self.list(|p| {
+                let dim = p.geometry(&nested)?;
+                first_dim.get_or_insert(dim);
+                Ok(())
+            })?;
+
+            // The members come after the header, so fill in the dimension now that it is known
+            let dim = first_dim.unwrap_or(Dimension::Xy);
+            self.out
+                .rewrite_header(header_offset, WkbType::GeometryCollection(dim), options)?;
+            return Ok(dim);
+        }
+
+        let dim = self.infer_dimension();
+        self.out.header(WkbType::new(geometry_type, dim), options)?;
+        match geometry_type {
+            GeometryType::Point => self.position(dim, true)?,
+            GeometryType::LineString => self.positions(dim)?,
+            GeometryType::Polygon => self.list(|p| p.positions(dim))?,
+            GeometryType::MultiPoint => self.list(|p| {
+                p.out.header(WkbType::Point(dim), &nested)?;
+                p.position(dim, true)
+            })?,
+            GeometryType::MultiLineString => self.list(|p| {
+                p.out.header(WkbType::LineString(dim), &nested)?;
+                p.positions(dim)
+            })?,
+            GeometryType::MultiPolygon => self.list(|p| {
+                p.out.header(WkbType::Polygon(dim), &nested)?;
+                p.list(|p| p.positions(dim))
+            })?,
+            _ => unreachable!("only GeoJSON geometry types are parsed"),
+        }
+        Ok(dim)
+    }
+
+    fn geometry_type(&mut self) -> WkbResult<GeometryType> {
+        self.input.skip_whitespace();
+        let offset = self.input.pos;
+        let geometry_type = match self.string()?.as_ref() {
+            b"Point" => GeometryType::Point,
+            b"LineString" => GeometryType::LineString,
+            b"Polygon" => GeometryType::Polygon,
+            b"MultiPoint" => GeometryType::MultiPoint,
+            b"MultiLineString" => GeometryType::MultiLineString,
+            b"MultiPolygon" => GeometryType::MultiPolygon,
+            b"GeometryCollection" => GeometryType::GeometryCollection,
+            _ => return Err(invalid_geojson(offset, "unknown geometry type")),
+        };
+        Ok(geometry_type)
+    }
+
+    fn positions(&mut self, dim: Dimension) -> WkbResult<()> {
+        self.list(|p| p.position(dim, false))
+    }
+
+    /// Parse a position, writing the ordinates that fit in `dim`
+    fn position(&mut self, dim: Dimension, allow_empty: bool) -> WkbResult<()> {
+        self.input.skip_whitespace();
+        let start = self.input.pos;
+        self.expect(b'[')?;
+        if allow_empty && self.input.consume(b']') {
+            return self.out.empty_coord(dim);
+        }
+
+        let mut len = 0;
+        loop {
+            let value = self.number()?;
+            if len < dim.size() {
+                self.out.ordinate(value)?;
+            }
+            len += 1;
+            if !self.input.consume(b',') {
+                self.expect(b']')?;
+                break;
+            }
+        }
+        if len < 2 {
+            return Err(invalid_geojson(
+                start,
+                "a position needs at least two numbers",
+            ));
+        }
+        if len < dim.size() || (dim == Dimension::Xy && len > 2) {
+            return Err(invalid_geojson(
+                start,
+                "position has a different dimension than the first position",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Parse a JSON array, writing the number of elements followed by each element
+    fn list(&mut self, mut element: impl FnMut(&mut Self) -> WkbResult<()>) -> WkbResult<()> {
+        self.expect(b'[')?;
+        let slot = self.out.begin_count()?;
+        if self.input.consume(b']') {
+            return self.out.end_count(slot, 0);
+        }
+
+        let mut count = 0usize;
+        loop {
+            element(self)?;
+            count += 1;
+            if !self.input.consume(b',') {
+                self.expect(b']')?;
+                break;
+            }
+        }
+        self.out.end_count(slot, count)
+    }
+
+    /// Whether the first position in the `coordinates` value that follows has a Z value
+    fn infer_dimension(&self) -> Dimension {
+        let mut depth = 0;
+        for (i, &b) in self.input.text[self.input.pos..].iter().enumerate() {
+            match b {
+                b'[' => depth += 1,
+                b']' if depth <= 1 => break,
+                b']' => depth -= 1,
+                b if starts_number(b) => {
+                    let position = &self.input.text[self.input.pos + i..];
+                    let len = position.iter().position(|&b| b == b']');
+                    let separators = position[..len.unwrap_or(position.len())]
+                        .iter()
+                        .filter(|&&b| b == b',')
+                        .count();
+                    return if separators >= 2 {
+                        Dimension::Xyz
+                    } else {
+                        Dimension::Xy
+                    };
+                }
+                _ => (),
+            }
+        }
+        Dimension::Xy
+    }
+
+    fn number(&mut self) -> WkbResult<f64> {
+        self.input.skip_whitespace();
+        let start = self.input.pos;
+        let len = self.input.text[start..]
+            .iter()
+            .take_while(|&&b| starts_number(b) || matches!(b, b'e' | b'E'))
+            .count();
+        if len == 0 {
+            return Err(invalid_geojson(start, "expected a number"));
+        }
+        self.input.pos += len;
+        std::str::from_utf8(&self.input.text[start..self.input.pos])
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .ok_or_else(|| invalid_geojson(start, "invalid number"))
+    }
+
+    /// Parse a string, decoding any escape sequences
+    fn string(&mut self) -> WkbResult<Cow<'j, [u8]>> {
+        self.expect(b'"')?;
+        let start = self.input.pos;
+        let len = self.skip_string_contents()?;
+        let raw = &self.input.text[start..start + len];
+        if !raw.contains(&b'\\') {
+            return Ok(Cow::Borrowed(raw));
+        }
+
+        let mut decoded = Vec::with_capacity(raw.len());
+        let mut i = 0;
+        while i < raw.len() {
+            if raw[i] != b'\\' {
+                decoded.push(raw[i]);
+                i += 1;
+                continue;
+            }
+            let escape_offset = start + i;
+            let unescaped = match raw.get(i + 1) {
+                Some(b'"') => '"',
+                Some(b'\\') => '\\',
+                Some(b'/') => '/',
+                Some(b'b') => '\u{8}',
+                Some(b'f') => '\u{c}',
+                Some(b'n') => '\n',
+                Some(b'r') => '\r',
+                Some(b't') => '\t',
+                Some(b'u') => {
+                    let mut code = hex_code_unit(raw, i + 2, escape_offset)?;
+                    i += 4;
+                    if (0xD800..0xDC00).contains(&code) && raw.get(i + 2..i + 4) == Some(b"\\u") {
+                        let low = hex_code_unit(raw, i + 4, escape_offset)?;
+                        if (0xDC00..0xE000).contains(&low) {
+                            code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                            i += 6;
+                        }
+                    }
+                    char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+                }
+                _ => return Err(invalid_geojson(escape_offset, "invalid escape sequence")),
+            };
+            let mut utf8 = [0; 4];
+            decoded.extend_from_slice(unescaped.encode_utf8(&mut utf8).as_bytes());
+            i += 2;
+        }
+        Ok(Cow::Owned(decoded))
+    }
+
+    /// Skip to the closing quote of a string, returning the length of its contents
+    fn skip_string_contents(&mut self) -> WkbResult<usize> {
+        let start = self.input.pos;
+        let mut escaped = false;
+        for (len, &b) in self.input.text[start..].iter().enumerate() {
+            match b {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => {
+                    self.input.pos = start + len + 1;
+                    return Ok(len);
+                }
+                _ => (),
+            }
+        }
+        Err(invalid_geojson(
+            self.input.text.len(),
+            "unterminated string",
+        ))
+    }
+
+    /// Skip over any JSON value, such as a `bbox` or foreign member
+    fn skip_value(&mut self) -> WkbResult<()> {
+        let mut depth = 0usize;
+        loop {
+            match self.input.peek() {
+                Some(b'{' | b'[') => {
+                    depth += 1;
+                    self.input.pos += 1;
+                }
+                Some(b'}' | b']') if depth > 0 => {
+                    depth -= 1;
+                    self.input.pos += 1;
+                }
+                Some(b',' | b':') if depth > 0 => self.input.pos += 1,
+                Some(b'"') => {
+                    self.input.pos += 1;
+                    self.skip_string_contents()?;
+                }
+                Some(b) if b.is_ascii_alphanumeric() || b == b'-' => {
+                    self.input.pos += self.input.text[self.input.pos..]
+                        .iter()
+                        .take_while(|&&b| {
+                            b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.')
+                        })
+                        .count();
+                }
+                Some(_) => return Err(invalid_geojson(self.input.pos, "expected a value")),
+                None => return Err(invalid_geojson(self.input.pos, "unexpected end of JSON")),
+            }
+            if depth == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: u8) -> WkbResult<()> {
+        if self.input.consume(expected) {
+            return Ok(());
+        }
+        let reason = match (self.input.peek(), expected) {
+            (None, _) => "unexpected end of JSON",
+            (_, b'{') => "expected '{'",
+            (_, b'}') => "expected ',' or '}'",
+            (_, b'[') => "expected '['",
+            (_, b']') => "expected ',' or ']'",
+            (_, b':') => "expected ':'",
+            _ => "expected a string",
+        };
+        Err(invalid_geojson(self.input.pos, reason))
+    }
+}
+
+/// Read the four hex digits of a `\u` escape starting at `i`
+fn hex_code_unit(raw: &[u8], i: usize, escape_offset: usize) -> WkbResult<u32> {
+    raw.get(i..i + 4)
+        .and_then(|digits| std::str::from_utf8(digits).ok())
+        .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+        .ok_or_else(|| invalid_geojson(escape_offset, "invalid unicode escape"))
+}
+
+#[cold]
+fn invalid_geojson(offset: usize, reason: &'static str) -> WkbError {
+    WkbError::InvalidGeoJson {
+        offset: offset as u64,
+        reason,
+    }
+}
@@ -0,0 +1,220 @@
+use std::io::Write;
+
+use geo_traits::{
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    LineTrait, MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+    RectTrait, TriangleTrait,
+};
+
+use crate::bounds::Bounds;
+use crate::common::format_rounded;
+use crate::error::{WkbError, WkbResult};
+
+/// Options for writing geometries as GeoJSON
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeoJsonWriteOptions {
+    /// The maximum number of decimal places to write for each ordinate.
+    ///
+    /// Trailing zeros are removed. By default, each ordinate is written with the fewest digits
+    /// that read back as the same `f64`. RFC 7946 suggests 6 decimal places for longitude and
+    /// latitude, which is about 10 centimeters.
+    pub precision: Option<usize>,
+
+    /// Whether to write Z values as the third element of each position.
+    ///
+    /// GeoJSON has no M values, so these are always dropped.
+    pub include_z: bool,
+
+    /// Whether to add a `bbox` member to the outermost geometry.
+    ///
+    /// Empty geometries have no `bbox`.
+    pub include_bbox: bool,
+}
+
+impl Default for GeoJsonWriteOptions {
+    fn default() -> Self {
+        Self {
+            precision: None,
+            include_z: true,
+            include_bbox: false,
+        }
+    }
+}
+
+/// Write a geometry as a GeoJSON geometry object.
+///
+/// Lines are written as LineStrings, and Rects and Triangles as Polygons, with Rects wound
+/// counterclockwise as recommended by RFC 7946. Curves and surfaces from a parsed
+/// [`Wkb`][crate::reader::Wkb] are written through their [`geo_traits`] representation, so a
+/// CircularString becomes a LineString through its control points.
+///
+/// An empty Point is written with an empty `coordinates` array, and empty Points inside a
+/// MultiPoint are skipped. Returns an error if any other coordinate is NaN or infinite, which
+/// cannot be represented in JSON.
+pub fn write_geojson(
+    writer: &mut impl Write,
+    geom: &impl GeometryTrait<T = f64>,
+    options: &GeoJsonWriteOptions,
+) -> WkbResult<()> {
+    GeoJsonWriter {
+        out: writer,
+        options,
+        scratch: String::new(),
+    }
+    .geometry(geom, options.include_bbox)
+}
+
+struct GeoJsonWriter<'o, 'p, W: Write> {
+    out: &'o mut W,
+    options: &'p GeoJsonWriteOptions,
+    /// Reused to format each rounded ordinate
+    scratch: String,
+}
+
+impl<W: Write> GeoJsonWriter<'_, '_, W> {
+    fn geometry(&mut self, geom: &impl GeometryTrait<T = f64>, bbox: bool) -> WkbResult<()> {
+        use GeometryType::*;
+
+        let with_z = self.options.include_z && has_z(geom.dim());
+        let (type_name, member) = match geom.as_type() {
+            Point(_) => ("Point", "coordinates"),
+            LineString(_) | Line(_) => ("LineString", "coordinates"),
+            Polygon(_) | Rect(_) | Triangle(_) => ("Polygon", "coordinates"),
+            MultiPoint(_) => ("MultiPoint", "coordinates"),
+            MultiLineString(_) => ("MultiLineString", "coordinates"),
+            MultiPolygon(_) => ("MultiPolygon", "coordinates"),
+            GeometryCollection(_) => ("GeometryCollection", "geometries"),
+        };
+        write!(self.out, r#"{{"type":"{type_name}""#)?;
+        if bbox {
+            self.bbox(geom, with_z)?;
+        }
+        write!(self.out, r#","{member}":"#)?;
+
+        match geom.as_type() {
+            Point(p) => match p.coord() {
+                Some(coord) => self.position(&coord, with_z)?,
+                None => self.out.write_all(b"[]")?,
+            },
+            LineString(ls) => self.line_string(ls, with_z)?,
+            Polygon(p) => self.polygon(p, with_z)?,
+            MultiPoint(mp) => {
+                let points = mp.points().filter(|p| p.coord().is_some());
+                self.list(points, |w, p| match p.coord() {
+                    Some(coord) => w.position(&coord, with_z),
+                    None => Ok(()),
+                })?
+            }
+            MultiLineString(ml) => {
+                self.list(ml.line_strings(), |w, ls| w.line_string(&ls, with_z))?
+            }
+            MultiPolygon(mp) => self.list(mp.polygons(), |w, p| w.polygon(&p, with_z))?,
+            GeometryCollection(gc) => self.list(gc.geometries(), |w, g| w.geometry(&g, false))?,
+            Rect(r) => {
+                let (min, max) = (r.min(), r.max());
+                let ring = [
+                    (min.x(), min.y()),
+                    (max.x(), min.y()),
+                    (max.x(), max.y()),
+                    (min.x(), max.y()),
+                    (min.x(), min.y()),
+                ];
+                self.list([ring], |w, ring| {
+                    w.list(ring, |w, coord| w.position(&coord, false))
+                })?
+            }
+            Triangle(t) => {
+                let ring = [t.first(), t.second(), t.third(), t.first()];
+                self.list([ring], |w, ring| {
+                    w.list(ring, |w, coord| w.position(&coord, with_z))
+                })?
+            }
+            Line(l) => self.list([l.start(), l.end()], |w, coord| w.position(&coord, with_z))?,
+        }
+        self.out.write_all(b"}")?;
+        Ok(())
+    }
+
+    fn line_string(
+        &mut self,
+        line_string: &impl LineStringTrait<T = f64>,
+        with_z: bool,
+    ) -> WkbResult<()> {
+        self.list(line_string.coords(), |w, coord| w.position(&coord, with_z))
+    }
+
+    fn polygon(&mut self, polygon: &impl PolygonTrait<T = f64>, with_z: bool) -> WkbResult<()> {
+        let rings = polygon.exterior().into_iter().chain(polygon.interiors());
+        self.list(rings, |w, ring| w.line_string(&ring, with_z))
+    }
+
+    fn position(&mut self, coord: &impl CoordTrait<T = f64>, with_z: bool) -> WkbResult<()> {
+        self.out.write_all(b"[")?;
+        self.ordinate(coord.x())?;
+        self.out.write_all(b",")?;
+        self.ordinate(coord.y())?;
+        if with_z {
+            self.out.write_all(b",")?;
+            self.ordinate(coord.nth_or_panic(2))?;
+        }
+        self.out.write_all(b"]")?;
+        Ok(())
+    }
+
+    fn bbox(&mut self, geom: &impl GeometryTrait<T = f64>, with_z: bool) -> WkbResult<()> {
+        let bounds = Bounds::of_geometry(geom);
+        if bounds.is_empty() {
+            return Ok(());
+        }
+
+        let (min_x, max_x) = bounds.x();
+        let (min_y, max_y) = bounds.y();
+        let (values, len) = match bounds.z().filter(|_| with_z) {
+            Some((min_z, max_z)) => ([min_x, min_y, min_z, max_x, max_y, max_z], 6),
+            None => ([min_x, min_y, max_x, max_y, 0., 0.], 4),
+        };
+
+        self.out.write_all(br#","bbox":"#)?;
+        self.list(&values[..len], |w, &value| w.ordinate(value))
+    }
+
+    /// Write items separated by commas inside square brackets
+    fn list<I>(
+        &mut self,
+        items: impl IntoIterator<Item = I>,
+        mut write_item: impl FnMut(&mut Self, I) -> WkbResult<()>,
+    ) -> WkbResult<()> {
+        self.out.write_all(b"[")?;
+        for (i, item) in items.into_iter().enumerate() {
+            if i > 0 {
+                self.out.write_all(b",")?;
+            }
+            write_item(self, item)?;
+        }
+        self.out.write_all(b"]")?;
+        Ok(())
+    }
+
+    fn ordinate(&mut self, value: f64) -> WkbResult<()> {
+        if !value.is_finite() {
+            return Err(WkbError::General(format!(
+                "GeoJSON cannot represent the coordinate value {value}"
+            )));
+        }
+        match self.options.precision {
+            Some(precision) => self
+                .out
+                .write_all(format_rounded(&mut self.scratch, value, precision).as_bytes())?,
+            None => write!(self.out, "{value}")?,
+        }
+        Ok(())
+    }
+}
+
+/// Whether coordinates with these dimensions have a Z value as their third ordinate
+fn has_z(dim: Dimensions) -> bool {
+    matches!(
+        dim,
+        Dimensions::Xyz | Dimensions::Xyzm | Dimensions::Unknown(3) | Dimensions::Unknown(4)
+    )
+}
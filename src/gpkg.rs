@@ -0,0 +1,319 @@
+//! Read and write geometries in the GeoPackage binary format (GPB).
+//!
+//! A GeoPackage geometry blob is standard WKB preceded by a header holding the `GP` magic bytes,
+//! a version, a flags byte, the SRS id, and an optional envelope. See
+//! [the GeoPackage specification](https://www.geopackage.org/spec/#gpb_format).
+//!
+//! ```
+//! use geo_types::line_string;
+//! use wkb::gpkg::{read_gpkg, write_gpkg};
+//! use wkb::writer::WriteOptions;
+//!
+//! let line_string = line_string![(x: 0., y: 1.), (x: 2., y: 3.)];
+//! let mut buf = Vec::new();
+//! write_gpkg(&mut buf, &line_string, 4326, &WriteOptions::default()).unwrap();
+//!
+//! let geometry = read_gpkg(&buf).unwrap();
+//! assert_eq!(geometry.srs_id(), 4326);
+//! assert_eq!(geometry.envelope().unwrap().max_y, 3.);
+//! ```
+
+use std::io::Write;
+
+use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
+use geo_traits::{GeometryTrait, GeometryType};
+
+use crate::bounds::Bounds;
+use crate::error::{WkbError, WkbResult};
+use crate::reader::Wkb;
+use crate::writer::{write_geometry, WkbFlavor, WriteOptions};
+use crate::Endianness;
+
+const MAGIC: [u8; 2] = *b"GP";
+
+/// Bit 0 of the flags byte: the byte order of the SRS id and envelope
+const FLAG_LITTLE_ENDIAN: u8 = 0b0000_0001;
+/// Bits 1-3 of the flags byte: the envelope contents indicator
+const FLAG_ENVELOPE_MASK: u8 = 0b0000_1110;
+/// Bit 4 of the flags byte: whether the geometry is empty
+const FLAG_EMPTY: u8 = 0b0001_0000;
+/// Bit 5 of the flags byte: whether this is an extended GeoPackage geometry
+const FLAG_EXTENDED: u8 = 0b0010_0000;
+
+/// The size of the header before the envelope
+const FIXED_HEADER_BYTES: usize = 8;
+
+/// The bounding box stored in a GeoPackage binary header
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GpkgEnvelope {
+    /// The minimum x value
+    pub min_x: f64,
+    /// The maximum x value
+    pub max_x: f64,
+    /// The minimum y value
+    pub min_y: f64,
+    /// The maximum y value
+    pub max_y: f64,
+    /// The minimum and maximum z values, if the envelope includes z
+    pub z_range: Option<(f64, f64)>,
+    /// The minimum and maximum m values, if the envelope includes m
+    pub m_range: Option<(f64, f64)>,
+}
+
+impl GpkgEnvelope {
+    /// The envelope contents indicator code stored in the flags byte
+    fn indicator(&self) -> u8 {
+        match (self.z_range.is_some(), self.m_range.is_some()) {
+            (false, false) => 1,
+            (true, false) => 2,
+            (false, true) => 3,
+            (true, true) => 4,
+        }
+    }
+
+    fn from_bounds(bounds: &Bounds) -> Self {
+        let (min_x, max_x) = bounds.x();
+        let (min_y, max_y) = bounds.y();
+        Self {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+            z_range: bounds.z(),
+            m_range: bounds.m(),
+        }
+    }
+}
+
+/// A geometry stored in the GeoPackage binary format
+#[derive(Debug, Clone)]
+pub struct GpkgGeometry<'a> {
+    version: u8,
+    byte_order: Endianness,
+    is_empty: bool,
+    srs_id: i32,
+    envelope: Option<GpkgEnvelope>,
+    header_size: usize,
+    wkb: Wkb<'a>,
+}
+
+impl<'a> GpkgGeometry<'a> {
+    /// Parse a GeoPackage geometry blob, including the WKB geometry that follows the header.
+    ///
+    /// Errors in the WKB geometry are reported with offsets relative to the start of `buf`.
+    pub fn try_new(buf: &'a [u8]) -> WkbResult<Self> {
+        if buf.len() < FIXED_HEADER_BYTES {
+            return Err(WkbError::UnexpectedEof {
+                offset: 0,
+                needed: FIXED_HEADER_BYTES as u64,
+                available: buf.len() as u64,
+                path: Default::default(),
+            });
+        }
+        if buf[0..2] != MAGIC {
+            return Err(invalid_header("missing GP magic bytes", 0));
+        }
+
+        let version = buf[2];
+        if version != 0 {
+            return Err(invalid_header("unsupported version", 2));
+        }
+
+        let flags = buf[3];
+        if flags & FLAG_EXTENDED != 0 {
+            return Err(WkbError::NotYetImplemented(
+                "Extended GeoPackage geometries".to_string(),
+            ));
+        }
+        let byte_order = if flags & FLAG_LITTLE_ENDIAN != 0 {
+            Endianness::LittleEndian
+        } else {
+            Endianness::BigEndian
+        };
+        let is_empty = flags & FLAG_EMPTY != 0;
+        let indicator = (flags & FLAG_ENVELOPE_MASK) >> 1;
+        if indicator > 4 {
+            return Err(invalid_header("invalid envelope contents indicator", 3));
+        }
+
+        let header_size = FIXED_HEADER_BYTES + envelope_size(indicator);
+        if buf.len() < header_size {
+            return Err(WkbError::UnexpectedEof {
+                offset: FIXED_HEADER_BYTES as u64,
+                needed: envelope_size(indicator) as u64,
+                available: (buf.len() - FIXED_HEADER_BYTES) as u64,
+                path: Default::default(),
+            });
+        }
+
+        let (srs_id, envelope) = match byte_order {
+            Endianness::BigEndian => read_header_values::<BigEndian>(buf, indicator),
+            Endianness::LittleEndian => read_header_values::<LittleEndian>(buf, indicator),
+        };
+
+        let wkb =
+            Wkb::try_new(&buf[header_size..]).map_err(|e| e.nested(header_size as u64, None))?;
+
+        Ok(Self {
+            version,
+            byte_order,
+            is_empty,
+            srs_id,
+            envelope,
+            header_size,
+            wkb,
+        })
+    }
+
+    /// The GeoPackage binary format version, where `0` means version 1
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// The byte order of the SRS id and envelope in the header.
+    ///
+    /// The WKB geometry has its own byte order.
+    pub fn byte_order(&self) -> Endianness {
+        self.byte_order
+    }
+
+    /// Whether the header marks the geometry as empty
+    pub fn is_empty(&self) -> bool {
+        self.is_empty
+    }
+
+    /// The SRS id, which refers to the `gpkg_spatial_ref_sys` table
+    pub fn srs_id(&self) -> i32 {
+        self.srs_id
+    }
+
+    /// The envelope stored in the header, if any
+    pub fn envelope(&self) -> Option<&GpkgEnvelope> {
+        self.envelope.as_ref()
+    }
+
+    /// The number of bytes in the header, including the envelope
+    pub fn header_size(&self) -> usize {
+        self.header_size
+    }
+
+    /// The WKB geometry following the header
+    pub fn wkb(&self) -> &Wkb<'a> {
+        &self.wkb
+    }
+
+    /// Consume this geometry, returning the WKB geometry following the header
+    pub fn into_wkb(self) -> Wkb<'a> {
+        self.wkb
+    }
+}
+
+/// Parse a GeoPackage geometry blob.
+///
+/// This is an alias for [`GpkgGeometry::try_new`].
+pub fn read_gpkg(buf: &[u8]) -> WkbResult<GpkgGeometry<'_>> {
+    GpkgGeometry::try_new(buf)
+}
+
+/// Write a geometry as a GeoPackage geometry blob.
+///
+/// The header is written in the byte order given by `options`, and the WKB geometry is written
+/// with `options`. The envelope is computed from the geometry and includes z and m ranges when
+/// the geometry has them. As recommended by the specification, no envelope is written for points.
+/// Geometries without any coordinates are flagged as empty.
+///
+/// The SRS id belongs in the header, so an error is returned if `options` uses the EWKB flavor or
+/// sets an SRID.
+pub fn write_gpkg(
+    writer: &mut impl Write,
+    geom: &impl GeometryTrait<T = f64>,
+    srs_id: i32,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    if options.flavor != WkbFlavor::Iso || options.srid.is_some() {
+        return Err(WkbError::General(
+            "GeoPackage geometries must be written with the ISO flavor and no SRID".to_string(),
+        ));
+    }
+
+    let bounds = Bounds::of_geometry(geom);
+    let envelope = match geom.as_type() {
+        GeometryType::Point(_) => None,
+        _ if bounds.is_empty() => None,
+        _ => Some(GpkgEnvelope::from_bounds(&bounds)),
+    };
+
+    let mut flags = envelope.as_ref().map_or(0, |e| e.indicator() << 1);
+    if bounds.is_empty() {
+        flags |= FLAG_EMPTY;
+    }
+    if options.endianness == Endianness::LittleEndian {
+        flags |= FLAG_LITTLE_ENDIAN;
+    }
+
+    writer.write_all(&MAGIC)?;
+    writer.write_u8(0)?;
+    writer.write_u8(flags)?;
+    match options.endianness {
+        Endianness::BigEndian => write_header_values::<BigEndian>(writer, srs_id, envelope)?,
+        Endianness::LittleEndian => write_header_values::<LittleEndian>(writer, srs_id, envelope)?,
+    }
+
+    write_geometry(writer, geom, options)
+}
+
+fn envelope_size(indicator: u8) -> usize {
+    match indicator {
+        0 => 0,
+        1 => 32,
+        2 | 3 => 48,
+        _ => 64,
+    }
+}
+
+/// Read the SRS id and envelope from a header whose length has been validated
+fn read_header_values<B: ByteOrder>(buf: &[u8], indicator: u8) -> (i32, Option<GpkgEnvelope>) {
+    let srs_id = B::read_i32(&buf[4..8]);
+    let value = |i: usize| B::read_f64(&buf[FIXED_HEADER_BYTES + i * 8..]);
+    let envelope = (indicator > 0).then(|| GpkgEnvelope {
+        min_x: value(0),
+        max_x: value(1),
+        min_y: value(2),
+        max_y: value(3),
+        z_range: matches!(indicator, 2 | 4).then(|| (value(4), value(5))),
+        m_range: match indicator {
+            3 => Some((value(4), value(5))),
+            4 => Some((value(6), value(7))),
+            _ => None,
+        },
+    });
+    (srs_id, envelope)
+}
+
+fn write_header_values<B: ByteOrder>(
+    writer: &mut impl Write,
+    srs_id: i32,
+    envelope: Option<GpkgEnvelope>,
+) -> WkbResult<()> {
+    writer.write_i32::<B>(srs_id)?;
+    if let Some(envelope) = envelope {
+        writer.write_f64::<B>(envelope.min_x)?;
+        writer.write_f64::<B>(envelope.max_x)?;
+        writer.write_f64::<B>(envelope.min_y)?;
+        writer.write_f64::<B>(envelope.max_y)?;
+        for (min, max) in [envelope.z_range, envelope.m_range].into_iter().flatten() {
+            writer.write_f64::<B>(min)?;
+            writer.write_f64::<B>(max)?;
+        }
+    }
+    Ok(())
+}
+
+#[cold]
+fn invalid_header(reason: &'static str, offset: u64) -> WkbError {
+    WkbError::InvalidHeader {
+        format: "GeoPackage",
+        reason,
+        offset,
+    }
+}
@@ -0,0 +1,193 @@
+//! Read and write WKB as hexadecimal text, as used by PostGIS for HEXEWKB output, CSV exports,
+//! and logs.
+//!
+//! Hex input may use upper or lower case digits and may start with the `\x` prefix used by
+//! PostgreSQL `bytea` output.
+//!
+//! ```
+//! use wkb::hex::{decode_hex, HexCase, HexWriter};
+//! use wkb::reader::read_wkb;
+//! use wkb::writer::{write_geometry, WriteOptions};
+//!
+//! let bytes = decode_hex("\\x0101000000000000000000F03F0000000000000040").unwrap();
+//! let geometry = read_wkb(&bytes).unwrap();
+//!
+//! let mut hex = Vec::new();
+//! let mut writer = HexWriter::new(&mut hex, HexCase::Lower);
+//! write_geometry(&mut writer, &geometry, &WriteOptions::default()).unwrap();
+//! assert_eq!(hex, b"0101000000000000000000f03f0000000000000040");
+//! ```
+
+use std::io::{self, Read, Write};
+
+use crate::error::{WkbError, WkbResult};
+
+/// The case of hexadecimal digits to write
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HexCase {
+    /// Upper case digits, as written by PostGIS
+    #[default]
+    Upper,
+    /// Lower case digits
+    Lower,
+}
+
+/// Decode hex-encoded WKB into a new buffer
+pub fn decode_hex(hex: impl AsRef<[u8]>) -> WkbResult<Vec<u8>> {
+    let mut out = Vec::new();
+    decode_hex_into(hex, &mut out)?;
+    Ok(out)
+}
+
+/// Decode hex-encoded WKB, replacing the contents of `out`.
+///
+/// Reusing `out` across calls avoids an allocation for each decoded geometry.
+pub fn decode_hex_into(hex: impl AsRef<[u8]>, out: &mut Vec<u8>) -> WkbResult<()> {
+    let (prefix_len, digits) = strip_prefix(hex.as_ref());
+    if digits.len() % 2 != 0 {
+        return Err(invalid_hex(
+            (prefix_len + digits.len()) as u64,
+            "odd number of hex digits",
+        ));
+    }
+
+    out.clear();
+    out.reserve(digits.len() / 2);
+    for (i, pair) in digits.chunks_exact(2).enumerate() {
+        out.push(decode_pair(pair, (prefix_len + i * 2) as u64)?);
+    }
+    Ok(())
+}
+
+/// Encode a WKB buffer as hex
+pub fn encode_hex(buf: &[u8], case: HexCase) -> String {
+    let digits = digits(case);
+    let mut out = String::with_capacity(buf.len() * 2);
+    for byte in buf {
+        out.push(digits[(byte >> 4) as usize] as char);
+        out.push(digits[(byte & 0x0F) as usize] as char);
+    }
+    out
+}
+
+/// A [`Read`] adapter that decodes hex-encoded WKB as it is read.
+///
+/// This allows hex input to be parsed with a [`WkbStreamReader`][crate::reader::WkbStreamReader]
+/// without first decoding it into a separate buffer. Invalid hex is reported as an
+/// [`io::Error`] of kind [`InvalidData`][io::ErrorKind::InvalidData] that wraps a
+/// [`WkbError::InvalidHex`].
+#[derive(Debug, Clone)]
+pub struct HexReader<'a> {
+    digits: &'a [u8],
+    /// The offset of `digits` in the original input, for error reporting
+    offset: usize,
+}
+
+impl<'a> HexReader<'a> {
+    /// Create a reader over hex-encoded WKB, which may start with a `\x` prefix
+    pub fn new(hex: &'a (impl AsRef<[u8]> + ?Sized)) -> Self {
+        let (offset, digits) = strip_prefix(hex.as_ref());
+        Self { digits, offset }
+    }
+}
+
+impl Read for HexReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = buf.len().min(self.digits.len() / 2);
+        if n == 0 && self.digits.len() == 1 {
+            let err = invalid_hex(self.offset as u64 + 1, "odd number of hex digits");
+            return Err(io::Error::new(io::ErrorKind::InvalidData, err));
+        }
+
+        for (i, out) in buf[..n].iter_mut().enumerate() {
+            let pair = &self.digits[i * 2..i * 2 + 2];
+            *out = decode_pair(pair, (self.offset + i * 2) as u64)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        }
+        self.digits = &self.digits[n * 2..];
+        self.offset += n * 2;
+        Ok(n)
+    }
+}
+
+/// A [`Write`] adapter that hex-encodes everything written to it.
+///
+/// Pass this to any of the functions in [`writer`][crate::writer] to produce hex-encoded WKB
+/// directly, without first writing binary WKB to a separate buffer.
+#[derive(Debug)]
+pub struct HexWriter<W> {
+    writer: W,
+    digits: &'static [u8; 16],
+}
+
+impl<W: Write> HexWriter<W> {
+    /// Create a writer that writes hex digits of the given case to `writer`
+    pub fn new(writer: W, case: HexCase) -> Self {
+        Self {
+            writer,
+            digits: digits(case),
+        }
+    }
+
+    /// Consume this writer, returning the underlying writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> Write for HexWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Encode in fixed-size chunks so that large writes do not allocate
+        let mut encoded = [0u8; 512];
+        for chunk in buf.chunks(encoded.len() / 2) {
+            for (i, byte) in chunk.iter().enumerate() {
+                encoded[i * 2] = self.digits[(byte >> 4) as usize];
+                encoded[i * 2 + 1] = self.digits[(byte & 0x0F) as usize];
+            }
+            self.writer.write_all(&encoded[..chunk.len() * 2])?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Split off an optional `\x` prefix, returning its length and the remaining digits
+fn strip_prefix(hex: &[u8]) -> (usize, &[u8]) {
+    match hex.strip_prefix(b"\\x") {
+        Some(digits) => (2, digits),
+        None => (0, hex),
+    }
+}
+
+fn digits(case: HexCase) -> &'static [u8; 16] {
+    match case {
+        HexCase::Upper => b"0123456789ABCDEF",
+        HexCase::Lower => b"0123456789abcdef",
+    }
+}
+
+/// Decode two hex digits starting at `offset` in the original input
+#[inline]
+fn decode_pair(pair: &[u8], offset: u64) -> WkbResult<u8> {
+    let high = hex_value(pair[0]).ok_or_else(|| invalid_hex(offset, "invalid hex digit"))?;
+    let low = hex_value(pair[1]).ok_or_else(|| invalid_hex(offset + 1, "invalid hex digit"))?;
+    Ok((high << 4) | low)
+}
+
+#[inline]
+fn hex_value(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[cold]
+fn invalid_hex(offset: u64, reason: &'static str) -> WkbError {
+    WkbError::InvalidHex { offset, reason }
+}
@@ -7,11 +7,19 @@
     html_favicon_url = "https://github.com/georust.png?size=32"
 )]
 
+mod bounds;
 mod common;
 pub mod error;
+pub mod geojson;
+pub mod gpkg;
+pub mod hex;
+pub mod mysql;
 pub mod reader;
+pub mod spatialite;
 #[cfg(test)]
 mod test;
+pub mod twkb;
+pub mod wkt;
 pub mod writer;
 
 pub use common::Endianness;
@@ -0,0 +1,91 @@
+//! Read and write geometries in the internal format used by MySQL and MariaDB.
+//!
+//! `GEOMETRY` column values are stored as a 4-byte little-endian SRID followed by standard WKB.
+//!
+//! ```
+//! use wkb::mysql::{read_mysql, write_mysql};
+//! use wkb::writer::WriteOptions;
+//!
+//! let point = geo_types::Point::new(1., 2.);
+//! let mut buf = Vec::new();
+//! write_mysql(&mut buf, &point, 4326, &WriteOptions::default()).unwrap();
+//!
+//! let geometry = read_mysql(&buf).unwrap();
+//! assert_eq!(geometry.srid(), 4326);
+//! ```
+
+use std::io::Write;
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use geo_traits::GeometryTrait;
+
+use crate::error::{WkbError, WkbResult};
+use crate::reader::util::read_u32_at;
+use crate::reader::Wkb;
+use crate::writer::{write_geometry, WkbFlavor, WriteOptions};
+use crate::Endianness;
+
+/// The size of the SRID prefix
+const SRID_BYTES: usize = 4;
+
+/// A geometry stored in the MySQL internal format
+#[derive(Debug, Clone)]
+pub struct MysqlGeometry<'a> {
+    srid: u32,
+    wkb: Wkb<'a>,
+}
+
+impl<'a> MysqlGeometry<'a> {
+    /// Parse a MySQL geometry value, including the WKB geometry that follows the SRID.
+    ///
+    /// Errors in the WKB geometry are reported with offsets relative to the start of `buf`.
+    pub fn try_new(buf: &'a [u8]) -> WkbResult<Self> {
+        let srid = read_u32_at(buf, 0, Endianness::LittleEndian)?;
+        let wkb =
+            Wkb::try_new(&buf[SRID_BYTES..]).map_err(|e| e.nested(SRID_BYTES as u64, None))?;
+        Ok(Self { srid, wkb })
+    }
+
+    /// The SRID prefix, where `0` means no spatial reference system
+    pub fn srid(&self) -> u32 {
+        self.srid
+    }
+
+    /// The WKB geometry following the SRID
+    pub fn wkb(&self) -> &Wkb<'a> {
+        &self.wkb
+    }
+
+    /// Consume this geometry, returning the WKB geometry following the SRID
+    pub fn into_wkb(self) -> Wkb<'a> {
+        self.wkb
+    }
+}
+
+/// Parse a MySQL geometry value.
+///
+/// This is an alias for [`MysqlGeometry::try_new`].
+pub fn read_mysql(buf: &[u8]) -> WkbResult<MysqlGeometry<'_>> {
+    MysqlGeometry::try_new(buf)
+}
+
+/// Write a geometry in the MySQL internal format.
+///
+/// The SRID is always little-endian, while the WKB geometry is written with `options`. MySQL
+/// expects plain 2D WKB, so an error is returned if `options` uses the EWKB flavor or sets an
+/// SRID.
+pub fn write_mysql(
+    writer: &mut impl Write,
+    geom: &impl GeometryTrait<T = f64>,
+    srid: u32,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    if options.flavor != WkbFlavor::Iso || options.srid.is_some() {
+        return Err(WkbError::General(
+            "MySQL geometries must be written with the ISO flavor and no SRID".to_string(),
+        ));
+    }
+
+    writer.write_u32::<LittleEndian>(srid)?;
+    write_geometry(writer, geom, options)
+}
@@ -0,0 +1,309 @@
+use std::fmt;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use geo_traits::{Dimensions, GeometryTrait, UnimplementedLine, UnimplementedRect};
+
+use crate::error::WkbResult;
+use crate::reader::{
+    GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
+    ReadOptions, Triangle, Wkb,
+};
+
+mod sealed {
+    /// Converts a container to the form a [`WkbBuf`][super::WkbBuf] stores it in.
+    ///
+    /// A `Box` asserts unique access to its contents whenever it is moved, which the geometry
+    /// borrowing from it would violate, so a `Box<[u8]>` is stored as a `Vec<u8>` instead.
+    pub trait Sealed {
+        type Storage: AsRef<[u8]> + Clone;
+
+        fn into_storage(self) -> Self::Storage;
+
+        fn from_storage(storage: Self::Storage) -> Self;
+    }
+}
+
+/// An owned byte container that can back a [`WkbBuf`].
+///
+/// The contents of each of these containers stay at the same address when the container is
+/// moved, which lets a `WkbBuf` keep its parsed geometry alongside its bytes. This trait is
+/// sealed and implemented for `Vec<u8>`, `Box<[u8]>`, `Arc<[u8]>`, `Rc<[u8]>`, and, with the
+/// `bytes` feature, [`bytes::Bytes`].
+pub trait WkbBytes: sealed::Sealed {}
+
+macro_rules! impl_wkb_bytes {
+    ($($container:ty),*) => {
+        $(
+            impl sealed::Sealed for $container {
+                type Storage = Self;
+
+                fn into_storage(self) -> Self {
+                    self
+                }
+
+                fn from_storage(storage: Self) -> Self {
+                    storage
+                }
+            }
+
+            impl WkbBytes for $container {}
+        )*
+    };
+}
+
+impl_wkb_bytes!(Vec<u8>, Arc<[u8]>, Rc<[u8]>);
+#[cfg(feature = "bytes")]
+impl_wkb_bytes!(bytes::Bytes);
+
+impl sealed::Sealed for Box<[u8]> {
+    type Storage = Vec<u8>;
+
+    fn into_storage(self) -> Vec<u8> {
+        self.into_vec()
+    }
+
+    fn from_storage(storage: Vec<u8>) -> Self {
+        storage.into_boxed_slice()
+    }
+}
+
+impl WkbBytes for Box<[u8]> {}
+
+/// A parsed WKB geometry that owns its bytes.
+///
+/// [`Wkb`] borrows the buffer it was parsed from, which makes it awkward to store in structs,
+/// send through channels, or keep in caches. A `WkbBuf` holds the bytes in any [`WkbBytes`]
+/// container together with the parsed geometry. The bytes are validated once when the `WkbBuf`
+/// is created, and [`WkbBuf::as_wkb`] then borrows the parsed geometry without any further work.
+///
+/// `WkbBuf` implements [`GeometryTrait`] by borrowing from the parsed geometry, so it can be
+/// passed anywhere a `Wkb` can.
+///
+/// ```
+/// use geo_traits::{GeometryTrait, GeometryType, PointTrait, CoordTrait};
+/// use geo_types::point;
+/// use wkb::reader::WkbBuf;
+/// use wkb::writer::write_geometry;
+///
+/// let mut bytes = Vec::new();
+/// write_geometry(&mut bytes, &point!(x: 1., y: 2.), &Default::default()).unwrap();
+///
+/// // The geometry can outlive the scope that created it
+/// let geometry = std::thread::spawn(move || WkbBuf::try_new(bytes).unwrap())
+///     .join()
+///     .unwrap();
+/// let GeometryType::Point(point) = geometry.as_type() else {
+///     unreachable!()
+/// };
+/// assert_eq!(point.coord().unwrap().x(), 1.);
+/// ```
+pub struct WkbBuf<B: WkbBytes = Vec<u8>> {
+    /// Borrows from `bytes`, so it is declared first to be dropped first. Its `'static` lifetime
+    /// is never exposed, and is shortened to the lifetime of `&self` whenever it is borrowed.
+    pub(super) wkb: Wkb<'static>,
+    pub(super) bytes: B::Storage,
+}
+
+impl<B: WkbBytes> WkbBuf<B> {
+    /// Parse the geometry at the start of `bytes`, taking ownership of them.
+    ///
+    /// This applies the default [`ReadOptions`]. Use [`WkbBuf::try_new_with_options`] to parse
+    /// untrusted input with tighter limits.
+    pub fn try_new(bytes: B) -> WkbResult<Self> {
+        Self::try_new_with_options(bytes, &ReadOptions::default())
+    }
+
+    /// Parse the geometry at the start of `bytes`, taking ownership of them and enforcing the
+    /// limits in `options`.
+    ///
+    /// See [`Wkb::try_new_with_options`] for the errors this returns.
+    pub fn try_new_with_options(bytes: B, options: &ReadOptions) -> WkbResult<Self> {
+        Self::parse(bytes.into_storage(), options)
+    }
+
+    fn parse(bytes: B::Storage, options: &ReadOptions) -> WkbResult<Self> {
+        // SAFETY: `WkbBytes` is only implemented for containers whose storage neither moves its
+        // contents nor asserts unique access to them when it is moved, and `WkbBuf` never gives
+        // out mutable access to `bytes`, so the slice stays valid until `bytes` is dropped, which
+        // happens after `wkb` is dropped.
+        let buf: &'static [u8] = unsafe { &*(bytes.as_ref() as *const [u8]) };
+        let wkb = Wkb::try_new_with_options(buf, options)?;
+        Ok(Self { wkb, bytes })
+    }
+
+    /// Borrow the parsed geometry.
+    pub fn as_wkb(&self) -> &Wkb<'_> {
+        &self.wkb
+    }
+
+    /// The bytes this geometry was parsed from.
+    ///
+    /// This includes any bytes after the end of the geometry, which
+    /// [`Wkb::buf`] excludes.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_ref()
+    }
+
+    /// Consume this geometry, returning the container of its bytes.
+    pub fn into_inner(self) -> B {
+        B::from_storage(self.bytes)
+    }
+}
+
+impl<B: WkbBytes> Clone for WkbBuf<B> {
+    /// Clone the container and parse the geometry again, since a cloned `Vec<u8>` or `Box<[u8]>`
+    /// holds its bytes at a new address.
+    fn clone(&self) -> Self {
+        // The geometry was already validated, so only the limits could fail a second parse
+        let options = ReadOptions {
+            max_depth: usize::MAX,
+            ..Default::default()
+        };
+        Self::parse(self.bytes.clone(), &options).expect("a validated geometry parses again")
+    }
+}
+
+impl<B: WkbBytes> fmt::Debug for WkbBuf<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("WkbBuf").field(self.as_wkb()).finish()
+    }
+}
+
+impl<B: WkbBytes> AsRef<[u8]> for WkbBuf<B> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<B: WkbBytes> GeometryTrait for WkbBuf<B> {
+    type T = f64;
+    type PointType<'b>
+        = Point<'b>
+    where
+        Self: 'b;
+    type LineStringType<'b>
+        = LineString<'b>
+    where
+        Self: 'b;
+    type PolygonType<'b>
+        = Polygon<'b>
+    where
+        Self: 'b;
+    type MultiPointType<'b>
+        = MultiPoint<'b>
+    where
+        Self: 'b;
+    type MultiLineStringType<'b>
+        = MultiLineString<'b>
+    where
+        Self: 'b;
+    type MultiPolygonType<'b>
+        = MultiPolygon<'b>
+    where
+        Self: 'b;
+    type GeometryCollectionType<'b>
+        = GeometryCollection<'b>
+    where
+        Self: 'b;
+    type RectType<'b>
+        = UnimplementedRect<f64>
+    where
+        Self: 'b;
+    type TriangleType<'b>
+        = Triangle<'b>
+    where
+        Self: 'b;
+    type LineType<'b>
+        = UnimplementedLine<f64>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.as_wkb().dim()
+    }
+
+    fn as_type(
+        &self,
+    ) -> geo_traits::GeometryType<
+        '_,
+        Self::PointType<'_>,
+        Self::LineStringType<'_>,
+        Self::PolygonType<'_>,
+        Self::MultiPointType<'_>,
+        Self::MultiLineStringType<'_>,
+        Self::MultiPolygonType<'_>,
+        Self::GeometryCollectionType<'_>,
+        Self::RectType<'_>,
+        Self::TriangleType<'_>,
+        Self::LineType<'_>,
+    > {
+        self.as_wkb().as_type()
+    }
+}
+
+impl<B: WkbBytes> GeometryTrait for &WkbBuf<B> {
+    type T = f64;
+    type PointType<'b>
+        = Point<'b>
+    where
+        Self: 'b;
+    type LineStringType<'b>
+        = LineString<'b>
+    where
+        Self: 'b;
+    type PolygonType<'b>
+        = Polygon<'b>
+    where
+        Self: 'b;
+    type MultiPointType<'b>
+        = MultiPoint<'b>
+    where
+        Self: 'b;
+    type MultiLineStringType<'b>
+        = MultiLineString<'b>
+    where
+        Self: 'b;
+    type MultiPolygonType<'b>
+        = MultiPolygon<'b>
+    where
+        Self: 'b;
+    type GeometryCollectionType<'b>
+        = GeometryCollection<'b>
+    where
+        Self: 'b;
+    type RectType<'b>
+        = UnimplementedRect<f64>
+    where
+        Self: 'b;
+    type TriangleType<'b>
+        = Triangle<'b>
+    where
+        Self: 'b;
+    type LineType<'b>
+        = UnimplementedLine<f64>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.as_wkb().dim()
+    }
+
+    fn as_type(
+        &self,
+    ) -> geo_traits::GeometryType<
+        '_,
+        Self::PointType<'_>,
+        Self::LineStringType<'_>,
+        Self::PolygonType<'_>,
+        Self::MultiPointType<'_>,
+        Self::MultiLineStringType<'_>,
+        Self::MultiPolygonType<'_>,
+        Self::GeometryCollectionType<'_>,
+        Self::RectType<'_>,
+        Self::TriangleType<'_>,
+        Self::LineType<'_>,
+    > {
+        self.as_wkb().as_type()
+    }
+}
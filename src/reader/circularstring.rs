@@ -0,0 +1,248 @@
+use crate::common::Dimension;
+use crate::error::WkbResult;
+use crate::reader::coord::Coord;
+use crate::reader::linestring::LineString;
+use crate::reader::ReadState;
+use crate::Endianness;
+use geo_traits::{
+    GeometryTrait, LineStringTrait, UnimplementedGeometryCollection, UnimplementedLine,
+    UnimplementedMultiLineString, UnimplementedMultiPoint, UnimplementedMultiPolygon,
+    UnimplementedPoint, UnimplementedPolygon, UnimplementedRect, UnimplementedTriangle,
+};
+
+/// A WKB CircularString
+///
+/// A CircularString has the same binary layout as a LineString, but each consecutive triple of
+/// points describes a circular arc instead of two straight segments. The [`LineStringTrait`]
+/// implementation exposes the control points of the arcs.
+///
+/// This has been preprocessed, so access to any internal coordinate is `O(1)`.
+#[derive(Debug, Clone, Copy)]
+pub struct CircularString<'a> {
+    /// The control points of this CircularString, which share the LineString layout
+    points: LineString<'a>,
+}
+
+impl<'a> CircularString<'a> {
+    /// Construct a new CircularString from a WKB buffer.
+    ///
+    /// This will parse the WKB header and validate the buffer length.
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        dim: Dimension,
+        state: &mut ReadState,
+    ) -> WkbResult<Self> {
+        let points = LineString::try_new(buf, byte_order, dim, state)?;
+        Ok(Self { points })
+    }
+
+    /// The number of bytes in this object, including any header
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.points.size()
+    }
+
+    /// The dimension of this CircularString
+    #[inline]
+    pub fn dimension(&self) -> Dimension {
+        self.points.dimension()
+    }
+
+    /// The SRID embedded in this CircularString's EWKB header, if any
+    #[inline]
+    pub fn srid(&self) -> Option<u32> {
+        self.points.srid()
+    }
+
+    /// The slice of bytes containing the control points of this CircularString. The byte order
+    /// of CircularString can be obtained by calling [CircularString::byte_order].
+    #[inline]
+    pub fn coords_slice(&self) -> &'a [u8] {
+        self.points.coords_slice()
+    }
+
+    /// Get the byte order of WKB CircularString
+    #[inline]
+    pub fn byte_order(&self) -> Endianness {
+        self.points.byte_order()
+    }
+
+    /// Get the underlying buffer of this CircularString
+    #[inline]
+    pub fn buf(&self) -> &'a [u8] {
+        self.points.buf()
+    }
+
+    /// View the control points of this CircularString as a [LineString].
+    #[inline]
+    pub fn as_line_string(&self) -> &LineString<'a> {
+        &self.points
+    }
+}
+
+impl<'a> LineStringTrait for CircularString<'a> {
+    type CoordType<'b>
+        = Coord<'a>
+    where
+        Self: 'b;
+
+    #[inline]
+    fn num_coords(&self) -> usize {
+        self.points.num_coords()
+    }
+
+    #[inline]
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        self.points.coord_unchecked(i)
+    }
+}
+
+impl<'a> LineStringTrait for &CircularString<'a> {
+    type CoordType<'b>
+        = Coord<'a>
+    where
+        Self: 'b;
+
+    #[inline]
+    fn num_coords(&self) -> usize {
+        self.points.num_coords()
+    }
+
+    #[inline]
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        self.points.coord_unchecked(i)
+    }
+}
+
+impl<'a> GeometryTrait for CircularString<'a> {
+    type T = f64;
+    type PointType<'b>
+        = UnimplementedPoint<f64>
+    where
+        Self: 'b;
+    type LineStringType<'b>
+        = CircularString<'a>
+    where
+        Self: 'b;
+    type PolygonType<'b>
+        = UnimplementedPolygon<f64>
+    where
+        Self: 'b;
+    type MultiPointType<'b>
+        = UnimplementedMultiPoint<f64>
+    where
+        Self: 'b;
+    type MultiLineStringType<'b>
+        = UnimplementedMultiLineString<f64>
+    where
+        Self: 'b;
+    type MultiPolygonType<'b>
+        = UnimplementedMultiPolygon<f64>
+    where
+        Self: 'b;
+    type GeometryCollectionType<'b>
+        = UnimplementedGeometryCollection<f64>
+    where
+        Self: 'b;
+    type RectType<'b>
+        = UnimplementedRect<f64>
+    where
+        Self: 'b;
+    type LineType<'b>
+        = UnimplementedLine<f64>
+    where
+        Self: 'b;
+    type TriangleType<'b>
+        = UnimplementedTriangle<f64>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.dimension().into()
+    }
+
+    fn as_type(
+        &self,
+    ) -> geo_traits::GeometryType<
+        '_,
+        Self::PointType<'_>,
+        Self::LineStringType<'_>,
+        Self::PolygonType<'_>,
+        Self::MultiPointType<'_>,
+        Self::MultiLineStringType<'_>,
+        Self::MultiPolygonType<'_>,
+        Self::GeometryCollectionType<'_>,
+        Self::RectType<'_>,
+        Self::TriangleType<'_>,
+        Self::LineType<'_>,
+    > {
+        geo_traits::GeometryType::LineString(self)
+    }
+}
+
+impl<'a> GeometryTrait for &CircularString<'a> {
+    type T = f64;
+    type PointType<'b>
+        = UnimplementedPoint<f64>
+    where
+        Self: 'b;
+    type LineStringType<'b>
+        = CircularString<'a>
+    where
+        Self: 'b;
+    type PolygonType<'b>
+        = UnimplementedPolygon<f64>
+    where
+        Self: 'b;
+    type MultiPointType<'b>
+        = UnimplementedMultiPoint<f64>
+    where
+        Self: 'b;
+    type MultiLineStringType<'b>
+        = UnimplementedMultiLineString<f64>
+    where
+        Self: 'b;
+    type MultiPolygonType<'b>
+        = UnimplementedMultiPolygon<f64>
+    where
+        Self: 'b;
+    type GeometryCollectionType<'b>
+        = UnimplementedGeometryCollection<f64>
+    where
+        Self: 'b;
+    type RectType<'b>
+        = UnimplementedRect<f64>
+    where
+        Self: 'b;
+    type LineType<'b>
+        = UnimplementedLine<f64>
+    where
+        Self: 'b;
+    type TriangleType<'b>
+        = UnimplementedTriangle<f64>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.dimension().into()
+    }
+
+    fn as_type(
+        &self,
+    ) -> geo_traits::GeometryType<
+        '_,
+        Self::PointType<'_>,
+        Self::LineStringType<'_>,
+        Self::PolygonType<'_>,
+        Self::MultiPointType<'_>,
+        Self::MultiLineStringType<'_>,
+        Self::MultiPolygonType<'_>,
+        Self::GeometryCollectionType<'_>,
+        Self::RectType<'_>,
+        Self::TriangleType<'_>,
+        Self::LineType<'_>,
+    > {
+        geo_traits::GeometryType::LineString(self)
+    }
+}
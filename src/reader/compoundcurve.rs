@@ -0,0 +1,88 @@
+use crate::common::Dimension;
+use crate::error::{PathSegment, WkbResult};
+use crate::reader::util::{offset_within, unexpected_type};
+use crate::reader::ReadState;
+use crate::reader::{GeometryCollection, GeometryType, Wkb};
+use crate::Endianness;
+use geo_traits::GeometryCollectionTrait;
+
+/// A WKB CompoundCurve
+///
+/// A CompoundCurve is a sequence of contiguous LineString and CircularString segments. It has the
+/// same binary layout as a GeometryCollection, and each segment is a complete WKB geometry.
+#[derive(Debug, Clone)]
+pub struct CompoundCurve<'a> {
+    /// The segments of this CompoundCurve
+    segments: GeometryCollection<'a>,
+}
+
+impl<'a> CompoundCurve<'a> {
+    /// Construct a new CompoundCurve from a WKB buffer.
+    ///
+    /// This will parse the WKB header and extract all contained segments.
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        dim: Dimension,
+        state: &mut ReadState,
+    ) -> WkbResult<Self> {
+        let segments = GeometryCollection::try_new(buf, byte_order, dim, state)?;
+        for (i, segment) in segments.geometries().enumerate() {
+            match segment.geometry_type() {
+                GeometryType::LineString | GeometryType::CircularString => (),
+                other => {
+                    let offset = offset_within(buf, segment.buf());
+                    return Err(
+                        unexpected_type("a LineString or CircularString", other, offset)
+                            .nested(0, Some(PathSegment::Member(i))),
+                    );
+                }
+            }
+        }
+        Ok(Self { segments })
+    }
+
+    /// The number of bytes in this object, including any header
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.segments.size()
+    }
+
+    /// The dimension of this CompoundCurve
+    pub fn dimension(&self) -> Dimension {
+        self.segments.dimension()
+    }
+
+    /// The SRID embedded in this CompoundCurve's EWKB header, if any
+    #[inline]
+    pub fn srid(&self) -> Option<u32> {
+        self.segments.srid()
+    }
+
+    /// The number of segments in this CompoundCurve
+    pub fn num_segments(&self) -> usize {
+        self.segments.num_geometries()
+    }
+
+    /// Access the segment at index `i`, which is either a LineString or a CircularString.
+    pub fn segment(&self, i: usize) -> Option<&Wkb<'a>> {
+        self.segments.geometry(i)
+    }
+
+    /// An iterator over the segments of this CompoundCurve
+    pub fn segments(&self) -> impl Iterator<Item = &Wkb<'a>> {
+        self.segments.geometries()
+    }
+
+    /// Get the underlying buffer of this CompoundCurve
+    #[inline]
+    pub fn buf(&self) -> &'a [u8] {
+        self.segments.buf()
+    }
+
+    /// View the segments of this CompoundCurve as a [GeometryCollection].
+    #[inline]
+    pub fn as_geometry_collection(&self) -> &GeometryCollection<'a> {
+        &self.segments
+    }
+}
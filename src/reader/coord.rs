@@ -1,11 +1,9 @@
-use std::io::Cursor;
-
 use crate::common::Dimension;
-use crate::reader::util::ReadBytesExt;
+use crate::reader::util::read_f64_at;
 use crate::Endianness;
 use geo_traits::{CoordTrait, Dimensions};
 
-const F64_WIDTH: u64 = 8;
+const F64_WIDTH: usize = 8;
 
 /// A coordinate in a WKB buffer.
 ///
@@ -29,7 +27,7 @@ pub struct Coord<'a> {
 impl<'a> Coord<'a> {
     pub(crate) fn new(buf: &'a [u8], byte_order: Endianness, dim: Dimension) -> Self {
         Self {
-            buf: &buf[..dim.size() * F64_WIDTH as usize],
+            buf: &buf[..dim.size() * F64_WIDTH],
             byte_order,
             dim,
         }
@@ -37,23 +35,18 @@ impl<'a> Coord<'a> {
 
     #[inline]
     fn get_x(&self) -> f64 {
-        let mut reader = Cursor::new(self.buf);
-        reader.read_f64(self.byte_order).unwrap()
+        read_f64_at(self.buf, 0, self.byte_order)
     }
 
     #[inline]
     fn get_y(&self) -> f64 {
-        let mut reader = Cursor::new(self.buf);
-        reader.set_position(F64_WIDTH);
-        reader.read_f64(self.byte_order).unwrap()
+        read_f64_at(self.buf, F64_WIDTH, self.byte_order)
     }
 
     #[inline]
     fn get_nth_unchecked(&self, n: usize) -> f64 {
         debug_assert!(n < self.dim.size());
-        let mut reader = Cursor::new(self.buf);
-        reader.set_position(n as u64 * F64_WIDTH);
-        reader.read_f64(self.byte_order).unwrap()
+        read_f64_at(self.buf, n * F64_WIDTH, self.byte_order)
     }
 
     /// Get the byte order of WKB coordinate
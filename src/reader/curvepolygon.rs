@@ -0,0 +1,103 @@
+use crate::common::Dimension;
+use crate::error::{PathSegment, WkbResult};
+use crate::reader::util::{offset_within, unexpected_type};
+use crate::reader::ReadState;
+use crate::reader::{GeometryCollection, GeometryType, Wkb};
+use crate::Endianness;
+use geo_traits::GeometryCollectionTrait;
+
+/// A WKB CurvePolygon
+///
+/// A CurvePolygon is a polygon whose rings may be LineStrings, CircularStrings, or
+/// CompoundCurves. Unlike a Polygon, each ring is a complete WKB geometry, so a CurvePolygon has
+/// the same binary layout as a GeometryCollection.
+#[derive(Debug, Clone)]
+pub struct CurvePolygon<'a> {
+    /// The rings of this CurvePolygon
+    rings: GeometryCollection<'a>,
+}
+
+impl<'a> CurvePolygon<'a> {
+    /// Construct a new CurvePolygon from a WKB buffer.
+    ///
+    /// This will parse the WKB header and extract all contained rings.
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        dim: Dimension,
+        state: &mut ReadState,
+    ) -> WkbResult<Self> {
+        let rings = GeometryCollection::try_new(buf, byte_order, dim, state)?;
+        for (i, ring) in rings.geometries().enumerate() {
+            match ring.geometry_type() {
+                GeometryType::LineString
+                | GeometryType::CircularString
+                | GeometryType::CompoundCurve => (),
+                other => {
+                    let offset = offset_within(buf, ring.buf());
+                    return Err(unexpected_type(
+                        "a LineString, CircularString or CompoundCurve",
+                        other,
+                        offset,
+                    )
+                    .nested(0, Some(PathSegment::Member(i))));
+                }
+            }
+        }
+        Ok(Self { rings })
+    }
+
+    /// The number of bytes in this object, including any header
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.rings.size()
+    }
+
+    /// The dimension of this CurvePolygon
+    pub fn dimension(&self) -> Dimension {
+        self.rings.dimension()
+    }
+
+    /// The SRID embedded in this CurvePolygon's EWKB header, if any
+    #[inline]
+    pub fn srid(&self) -> Option<u32> {
+        self.rings.srid()
+    }
+
+    /// The number of rings in this CurvePolygon, including the exterior ring
+    pub fn num_rings(&self) -> usize {
+        self.rings.num_geometries()
+    }
+
+    /// The exterior ring of this CurvePolygon, or `None` if it is empty
+    pub fn exterior(&self) -> Option<&Wkb<'a>> {
+        self.rings.geometry(0)
+    }
+
+    /// The number of interior rings in this CurvePolygon
+    pub fn num_interiors(&self) -> usize {
+        self.num_rings().saturating_sub(1)
+    }
+
+    /// Access the interior ring at index `i`
+    pub fn interior(&self, i: usize) -> Option<&Wkb<'a>> {
+        self.rings.geometry(i + 1)
+    }
+
+    /// An iterator over all rings of this CurvePolygon, starting with the exterior ring
+    pub fn rings(&self) -> impl Iterator<Item = &Wkb<'a>> {
+        self.rings.geometries()
+    }
+
+    /// Get the underlying buffer of this CurvePolygon
+    #[inline]
+    pub fn buf(&self) -> &'a [u8] {
+        self.rings.buf()
+    }
+
+    /// View the rings of this CurvePolygon as a [GeometryCollection].
+    #[inline]
+    pub fn as_geometry_collection(&self) -> &GeometryCollection<'a> {
+        &self.rings
+    }
+}
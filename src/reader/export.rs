@@ -0,0 +1,248 @@
+use geo_traits::{
+    GeometryCollectionTrait, MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PolygonTrait,
+};
+
+use crate::common::Dimension;
+use crate::reader::util::read_f64s;
+use crate::reader::{
+    CircularString, CompoundCurve, CurvePolygon, GeometryCollection, LineString, LinearRing,
+    MultiCurve, MultiLineString, MultiPoint, MultiPolygon, MultiSurface, Point, Polygon,
+    PolyhedralSurface, Tin, Triangle, Wkb, WkbInner,
+};
+use crate::Endianness;
+
+/// A geometry whose coordinates are stored in one or more contiguous runs of bytes
+trait CoordRuns {
+    /// Call `f` with each run of coordinates in order, along with its byte order and dimension
+    fn for_each_run(&self, f: &mut impl FnMut(&[u8], Endianness, Dimension));
+}
+
+impl CoordRuns for Point<'_> {
+    fn for_each_run(&self, f: &mut impl FnMut(&[u8], Endianness, Dimension)) {
+        f(self.coord_slice(), self.byte_order(), self.dimension())
+    }
+}
+
+impl CoordRuns for LineString<'_> {
+    fn for_each_run(&self, f: &mut impl FnMut(&[u8], Endianness, Dimension)) {
+        f(self.coords_slice(), self.byte_order(), self.dimension())
+    }
+}
+
+impl CoordRuns for LinearRing<'_> {
+    fn for_each_run(&self, f: &mut impl FnMut(&[u8], Endianness, Dimension)) {
+        f(self.coords_slice(), self.byte_order(), self.dimension())
+    }
+}
+
+impl CoordRuns for Polygon<'_> {
+    fn for_each_run(&self, f: &mut impl FnMut(&[u8], Endianness, Dimension)) {
+        for ring in self.exterior().into_iter().chain(self.interiors()) {
+            ring.for_each_run(f);
+        }
+    }
+}
+
+impl CoordRuns for MultiPoint<'_> {
+    fn for_each_run(&self, f: &mut impl FnMut(&[u8], Endianness, Dimension)) {
+        for point in self.points() {
+            point.for_each_run(f);
+        }
+    }
+}
+
+impl CoordRuns for MultiLineString<'_> {
+    fn for_each_run(&self, f: &mut impl FnMut(&[u8], Endianness, Dimension)) {
+        for line_string in self.line_strings() {
+            line_string.for_each_run(f);
+        }
+    }
+}
+
+impl CoordRuns for MultiPolygon<'_> {
+    fn for_each_run(&self, f: &mut impl FnMut(&[u8], Endianness, Dimension)) {
+        for polygon in self.polygons() {
+            polygon.for_each_run(f);
+        }
+    }
+}
+
+impl CoordRuns for GeometryCollection<'_> {
+    fn for_each_run(&self, f: &mut impl FnMut(&[u8], Endianness, Dimension)) {
+        for geometry in self.geometries() {
+            geometry.for_each_run(f);
+        }
+    }
+}
+
+impl CoordRuns for CircularString<'_> {
+    fn for_each_run(&self, f: &mut impl FnMut(&[u8], Endianness, Dimension)) {
+        self.as_line_string().for_each_run(f)
+    }
+}
+
+impl CoordRuns for CompoundCurve<'_> {
+    fn for_each_run(&self, f: &mut impl FnMut(&[u8], Endianness, Dimension)) {
+        for segment in self.segments() {
+            segment.for_each_run(f);
+        }
+    }
+}
+
+impl CoordRuns for CurvePolygon<'_> {
+    fn for_each_run(&self, f: &mut impl FnMut(&[u8], Endianness, Dimension)) {
+        for ring in self.rings() {
+            ring.for_each_run(f);
+        }
+    }
+}
+
+impl CoordRuns for MultiCurve<'_> {
+    fn for_each_run(&self, f: &mut impl FnMut(&[u8], Endianness, Dimension)) {
+        for curve in self.curves() {
+            curve.for_each_run(f);
+        }
+    }
+}
+
+impl CoordRuns for MultiSurface<'_> {
+    fn for_each_run(&self, f: &mut impl FnMut(&[u8], Endianness, Dimension)) {
+        for surface in self.surfaces() {
+            surface.for_each_run(f);
+        }
+    }
+}
+
+impl CoordRuns for PolyhedralSurface<'_> {
+    fn for_each_run(&self, f: &mut impl FnMut(&[u8], Endianness, Dimension)) {
+        self.as_multi_polygon().for_each_run(f)
+    }
+}
+
+impl CoordRuns for Tin<'_> {
+    fn for_each_run(&self, f: &mut impl FnMut(&[u8], Endianness, Dimension)) {
+        self.as_multi_polygon().for_each_run(f)
+    }
+}
+
+impl CoordRuns for Triangle<'_> {
+    fn for_each_run(&self, f: &mut impl FnMut(&[u8], Endianness, Dimension)) {
+        self.as_polygon().for_each_run(f)
+    }
+}
+
+impl CoordRuns for Wkb<'_> {
+    fn for_each_run(&self, f: &mut impl FnMut(&[u8], Endianness, Dimension)) {
+        match self.inner() {
+            WkbInner::Point(g) => g.for_each_run(f),
+            WkbInner::LineString(g) => g.for_each_run(f),
+            WkbInner::Polygon(g) => g.for_each_run(f),
+            WkbInner::MultiPoint(g) => g.for_each_run(f),
+            WkbInner::MultiLineString(g) => g.for_each_run(f),
+            WkbInner::MultiPolygon(g) => g.for_each_run(f),
+            WkbInner::GeometryCollection(g) => g.for_each_run(f),
+            WkbInner::CircularString(g) => g.for_each_run(f),
+            WkbInner::CompoundCurve(g) => g.for_each_run(f),
+            WkbInner::CurvePolygon(g) => g.for_each_run(f),
+            WkbInner::MultiCurve(g) => g.for_each_run(f),
+            WkbInner::MultiSurface(g) => g.for_each_run(f),
+            WkbInner::PolyhedralSurface(g) => g.for_each_run(f),
+            WkbInner::Tin(g) => g.for_each_run(f),
+            WkbInner::Triangle(g) => g.for_each_run(f),
+        }
+    }
+}
+
+/// Decode a run of coordinates onto the end of `out`
+fn extend_interleaved(out: &mut Vec<f64>, coords: &[u8], byte_order: Endianness) {
+    let start = out.len();
+    out.resize(start + coords.len() / 8, 0.);
+    read_f64s(coords, byte_order, &mut out[start..]);
+}
+
+/// The number of values [`extend_separated`] decodes at a time: a whole number of coordinates in
+/// every dimension
+const SEPARATED_BLOCK: usize = 240;
+
+/// Decode each ordinate of a run of coordinates onto the end of its own buffer
+fn extend_separated(
+    out: &mut [Vec<f64>; 4],
+    coords: &[u8],
+    byte_order: Endianness,
+    dim: Dimension,
+) {
+    let slots: &[usize] = match dim {
+        Dimension::Xy => &[0, 1],
+        Dimension::Xyz => &[0, 1, 2],
+        Dimension::Xym => &[0, 1, 3],
+        Dimension::Xyzm => &[0, 1, 2, 3],
+    };
+    let size = dim.size();
+    for &slot in slots {
+        out[slot].reserve(coords.len() / 8 / size);
+    }
+    // Bulk decode a block at a time, then de-interleave the block into each buffer
+    let mut block = [0.; SEPARATED_BLOCK];
+    for chunk in coords.chunks(SEPARATED_BLOCK * 8) {
+        let values = &mut block[..chunk.len() / 8];
+        read_f64s(chunk, byte_order, values);
+        for (n, &slot) in slots.iter().enumerate() {
+            out[slot].extend(values[n..].iter().step_by(size));
+        }
+    }
+}
+
+macro_rules! impl_write_coords {
+    ($($geometry_type:ident),*) => {
+        $(
+            impl $geometry_type<'_> {
+                /// Decode every coordinate onto the end of `out`, with the ordinates of each
+                /// coordinate one after another, as in `[x0, y0, x1, y1, ...]`.
+                ///
+                /// Members of polygons, multi geometries, and collections are written in order.
+                /// Each coordinate has as many ordinates as the geometry it belongs to, so a
+                /// collection with members of different dimensions cannot be split back into
+                /// coordinates. An empty Point is written as its NaN coordinate. Runs of
+                /// coordinates in the platform's native byte order are copied without swapping
+                /// any bytes.
+                pub fn write_coords_interleaved(&self, out: &mut Vec<f64>) {
+                    self.for_each_run(&mut |coords, byte_order, _| {
+                        extend_interleaved(out, coords, byte_order)
+                    });
+                }
+
+                /// Decode every coordinate onto the ends of `out`, with x values in `out[0]`,
+                /// y values in `out[1]`, z values in `out[2]`, and m values in `out[3]`.
+                ///
+                /// Members of polygons, multi geometries, and collections are written in order.
+                /// Coordinates without a z or m value add nothing to `out[2]` or `out[3]`. An
+                /// empty Point is written as its NaN coordinate.
+                pub fn write_coords_separated(&self, out: &mut [Vec<f64>; 4]) {
+                    self.for_each_run(&mut |coords, byte_order, dim| {
+                        extend_separated(out, coords, byte_order, dim)
+                    });
+                }
+            }
+        )*
+    };
+}
+
+impl_write_coords!(
+    Wkb,
+    Point,
+    LineString,
+    LinearRing,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    CircularString,
+    CompoundCurve,
+    CurvePolygon,
+    MultiCurve,
+    MultiSurface,
+    PolyhedralSurface,
+    Tin,
+    Triangle
+);
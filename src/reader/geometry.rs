@@ -1,17 +1,13 @@
-use std::io::Cursor;
-
-use byteorder::ReadBytesExt;
-
 use crate::common::{Dimension, WkbType};
-use crate::error::{WkbError, WkbResult};
+use crate::error::{PathSegment, WkbError, WkbResult};
+use crate::reader::util::read_header;
 use crate::reader::{
-    GeometryCollection, GeometryType, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
-    Polygon,
+    CircularString, CompoundCurve, CurvePolygon, GeometryCollection, GeometryType,
+    LazyGeometryCollection, LineString, MultiCurve, MultiLineString, MultiPoint, MultiPolygon,
+    MultiSurface, Point, Polygon, PolyhedralSurface, ReadOptions, ReadState, Tin, Triangle,
 };
 use crate::Endianness;
-use geo_traits::{
-    Dimensions, GeometryTrait, UnimplementedLine, UnimplementedRect, UnimplementedTriangle,
-};
+use geo_traits::{Dimensions, GeometryTrait, UnimplementedLine, UnimplementedRect};
 
 /// Parse a WKB byte slice into a geometry.
 ///
@@ -19,6 +15,18 @@ use geo_traits::{
 /// access the underlying data.
 ///
 /// The contained [dimension][geo_traits::Dimensions] will never be `Unknown`.
+///
+/// Curve and surface types have no equivalent in [`geo_traits`], so [`GeometryTrait`] exposes them
+/// through their closest linear counterpart:
+///
+/// - a CircularString is exposed as a LineString of its control points,
+/// - a CompoundCurve, CurvePolygon, MultiCurve, or MultiSurface is exposed as a GeometryCollection
+///   of its segments, rings, or members,
+/// - a PolyhedralSurface or TIN is exposed as a MultiPolygon of its patches,
+/// - an empty Triangle is exposed as an empty Polygon.
+///
+/// Use [`Wkb::geometry_type`] to tell them apart and the `as_*` accessors, such as
+/// [`Wkb::as_circular_string`], to access the type-specific structure.
 #[derive(Debug, Clone)]
 pub struct Wkb<'a> {
     inner: WkbInner<'a>,
@@ -38,8 +46,33 @@ impl<'a> Wkb<'a> {
     /// access** but **not zero-copy**. This is because the raw WKB buffer is not 8-byte aligned,
     /// so when accessing a coordinate the underlying bytes need to be copied into a
     /// newly-allocated `f64`.
+    ///
+    /// This applies the default [ReadOptions]. Use [`Wkb::try_new_with_options`] to parse
+    /// untrusted input with tighter limits.
     pub fn try_new(buf: &'a [u8]) -> WkbResult<Self> {
-        let inner = WkbInner::try_new(buf)?;
+        Self::try_new_with_options(buf, &ReadOptions::default())
+    }
+
+    /// Parse a WKB byte slice into a geometry, enforcing the limits in `options`.
+    ///
+    /// Returns [`WkbError::LimitExceeded`] if the geometry is nested too deeply or declares too
+    /// many elements or coordinates, and [`WkbError::TrailingBytes`] if
+    /// [`ReadOptions::reject_trailing_bytes`] is set and the geometry does not consume all of
+    /// `buf`.
+    pub fn try_new_with_options(buf: &'a [u8], options: &ReadOptions) -> WkbResult<Self> {
+        let wkb = Self::try_new_with_state(buf, &mut ReadState::new(options))?;
+        if options.reject_trailing_bytes && wkb.size() < buf.len() as u64 {
+            return Err(WkbError::TrailingBytes {
+                offset: wkb.size(),
+                count: buf.len() as u64 - wkb.size(),
+            });
+        }
+        Ok(wkb)
+    }
+
+    /// Parse a geometry that may be nested inside another one
+    pub(crate) fn try_new_with_state(buf: &'a [u8], state: &mut ReadState) -> WkbResult<Self> {
+        let inner = WkbInner::try_new(buf, state)?;
         Ok(Self { inner })
     }
 
@@ -54,6 +87,14 @@ impl<'a> Wkb<'a> {
             MultiLineString(g) => g.dimension(),
             MultiPolygon(g) => g.dimension(),
             GeometryCollection(g) => g.dimension(),
+            CircularString(g) => g.dimension(),
+            CompoundCurve(g) => g.dimension(),
+            CurvePolygon(g) => g.dimension(),
+            MultiCurve(g) => g.dimension(),
+            MultiSurface(g) => g.dimension(),
+            PolyhedralSurface(g) => g.dimension(),
+            Tin(g) => g.dimension(),
+            Triangle(g) => g.dimension(),
         }
     }
 
@@ -68,6 +109,39 @@ impl<'a> Wkb<'a> {
             MultiLineString(_) => GeometryType::MultiLineString,
             MultiPolygon(_) => GeometryType::MultiPolygon,
             GeometryCollection(_) => GeometryType::GeometryCollection,
+            CircularString(_) => GeometryType::CircularString,
+            CompoundCurve(_) => GeometryType::CompoundCurve,
+            CurvePolygon(_) => GeometryType::CurvePolygon,
+            MultiCurve(_) => GeometryType::MultiCurve,
+            MultiSurface(_) => GeometryType::MultiSurface,
+            PolyhedralSurface(_) => GeometryType::PolyhedralSurface,
+            Tin(_) => GeometryType::Tin,
+            Triangle(_) => GeometryType::Triangle,
+        }
+    }
+
+    /// Return the SRID embedded in this geometry's EWKB header, if any.
+    ///
+    /// Only the SRID of this geometry itself is returned. Geometries nested inside a
+    /// GeometryCollection may carry their own SRID, which is available from each member.
+    pub fn srid(&self) -> Option<u32> {
+        use WkbInner::*;
+        match &self.inner {
+            Point(g) => g.srid(),
+            LineString(g) => g.srid(),
+            Polygon(g) => g.srid(),
+            MultiPoint(g) => g.srid(),
+            MultiLineString(g) => g.srid(),
+            MultiPolygon(g) => g.srid(),
+            GeometryCollection(g) => g.srid(),
+            CircularString(g) => g.srid(),
+            CompoundCurve(g) => g.srid(),
+            CurvePolygon(g) => g.srid(),
+            MultiCurve(g) => g.srid(),
+            MultiSurface(g) => g.srid(),
+            PolyhedralSurface(g) => g.srid(),
+            Tin(g) => g.srid(),
+            Triangle(g) => g.srid(),
         }
     }
 
@@ -86,10 +160,23 @@ impl<'a> Wkb<'a> {
             MultiLineString(g) => g.buf(),
             MultiPolygon(g) => g.buf(),
             GeometryCollection(g) => g.buf(),
+            CircularString(g) => g.buf(),
+            CompoundCurve(g) => g.buf(),
+            CurvePolygon(g) => g.buf(),
+            MultiCurve(g) => g.buf(),
+            MultiSurface(g) => g.buf(),
+            PolyhedralSurface(g) => g.buf(),
+            Tin(g) => g.buf(),
+            Triangle(g) => g.buf(),
         }
     }
 
-    pub(crate) fn size(&self) -> u64 {
+    /// The number of bytes in this geometry, including any header.
+    ///
+    /// This is the length of [`Wkb::buf`], and can be used to find the start of the next geometry
+    /// in a buffer of concatenated geometries.
+    #[inline]
+    pub fn size(&self) -> u64 {
         use WkbInner::*;
         match &self.inner {
             Point(g) => g.size(),
@@ -99,6 +186,83 @@ impl<'a> Wkb<'a> {
             MultiLineString(g) => g.size(),
             MultiPolygon(g) => g.size(),
             GeometryCollection(g) => g.size(),
+            CircularString(g) => g.size(),
+            CompoundCurve(g) => g.size(),
+            CurvePolygon(g) => g.size(),
+            MultiCurve(g) => g.size(),
+            MultiSurface(g) => g.size(),
+            PolyhedralSurface(g) => g.size(),
+            Tin(g) => g.size(),
+            Triangle(g) => g.size(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn inner(&self) -> &WkbInner<'a> {
+        &self.inner
+    }
+
+    /// Access this geometry as a [CircularString], if it is one.
+    pub fn as_circular_string(&self) -> Option<&CircularString<'a>> {
+        match &self.inner {
+            WkbInner::CircularString(g) => Some(g),
+            _ => None,
+        }
+    }
+
+    /// Access this geometry as a [CompoundCurve], if it is one.
+    pub fn as_compound_curve(&self) -> Option<&CompoundCurve<'a>> {
+        match &self.inner {
+            WkbInner::CompoundCurve(g) => Some(g),
+            _ => None,
+        }
+    }
+
+    /// Access this geometry as a [CurvePolygon], if it is one.
+    pub fn as_curve_polygon(&self) -> Option<&CurvePolygon<'a>> {
+        match &self.inner {
+            WkbInner::CurvePolygon(g) => Some(g),
+            _ => None,
+        }
+    }
+
+    /// Access this geometry as a [MultiCurve], if it is one.
+    pub fn as_multi_curve(&self) -> Option<&MultiCurve<'a>> {
+        match &self.inner {
+            WkbInner::MultiCurve(g) => Some(g),
+            _ => None,
+        }
+    }
+
+    /// Access this geometry as a [MultiSurface], if it is one.
+    pub fn as_multi_surface(&self) -> Option<&MultiSurface<'a>> {
+        match &self.inner {
+            WkbInner::MultiSurface(g) => Some(g),
+            _ => None,
+        }
+    }
+
+    /// Access this geometry as a [PolyhedralSurface], if it is one.
+    pub fn as_polyhedral_surface(&self) -> Option<&PolyhedralSurface<'a>> {
+        match &self.inner {
+            WkbInner::PolyhedralSurface(g) => Some(g),
+            _ => None,
+        }
+    }
+
+    /// Access this geometry as a [Tin], if it is one.
+    pub fn as_tin(&self) -> Option<&Tin<'a>> {
+        match &self.inner {
+            WkbInner::Tin(g) => Some(g),
+            _ => None,
+        }
+    }
+
+    /// Access this geometry as a [Triangle], if it is one.
+    pub fn as_triangle(&self) -> Option<&Triangle<'a>> {
+        match &self.inner {
+            WkbInner::Triangle(g) => Some(g),
+            _ => None,
         }
     }
 }
@@ -113,32 +277,75 @@ pub(crate) enum WkbInner<'a> {
     MultiLineString(MultiLineString<'a>),
     MultiPolygon(MultiPolygon<'a>),
     GeometryCollection(GeometryCollection<'a>),
+    CircularString(CircularString<'a>),
+    CompoundCurve(CompoundCurve<'a>),
+    CurvePolygon(CurvePolygon<'a>),
+    MultiCurve(MultiCurve<'a>),
+    MultiSurface(MultiSurface<'a>),
+    PolyhedralSurface(PolyhedralSurface<'a>),
+    Tin(Tin<'a>),
+    Triangle(Triangle<'a>),
 }
 
 impl<'a> WkbInner<'a> {
-    fn try_new(buf: &'a [u8]) -> WkbResult<Self> {
-        let mut reader = Cursor::new(buf);
-        let byte_order = Endianness::try_from(reader.read_u8()?)
-            .map_err(|_| WkbError::General("Invalid byte order".to_string()))?;
-        let wkb_type = WkbType::from_buffer(buf)?;
+    fn try_new(buf: &'a [u8], state: &mut ReadState) -> WkbResult<Self> {
+        let (byte_order, wkb_type) = read_header(buf)?;
+        let segment = PathSegment::Geometry(wkb_type.geometry_type());
+        state.enter().map_err(|e| e.nested(0, Some(segment)))?;
+        let result = Self::try_new_with_type(buf, byte_order, wkb_type, state);
+        state.leave();
+        result.map_err(|e| e.nested(0, Some(segment)))
+    }
 
+    fn try_new_with_type(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        wkb_type: WkbType,
+        state: &mut ReadState,
+    ) -> WkbResult<Self> {
         let out = match wkb_type {
-            WkbType::Point(dim) => Self::Point(Point::try_new(buf, byte_order, dim)?),
+            WkbType::Point(dim) => {
+                let point = Point::try_new(buf, byte_order, dim)?;
+                state.add_coords(1, 0)?;
+                Self::Point(point)
+            }
             WkbType::LineString(dim) => {
-                Self::LineString(LineString::try_new(buf, byte_order, dim)?)
+                Self::LineString(LineString::try_new(buf, byte_order, dim, state)?)
             }
-            WkbType::Polygon(dim) => Self::Polygon(Polygon::try_new(buf, byte_order, dim)?),
+            WkbType::Polygon(dim) => Self::Polygon(Polygon::try_new(buf, byte_order, dim, state)?),
             WkbType::MultiPoint(dim) => {
-                Self::MultiPoint(MultiPoint::try_new(buf, byte_order, dim)?)
+                Self::MultiPoint(MultiPoint::try_new(buf, byte_order, dim, state)?)
             }
             WkbType::MultiLineString(dim) => {
-                Self::MultiLineString(MultiLineString::try_new(buf, byte_order, dim)?)
+                Self::MultiLineString(MultiLineString::try_new(buf, byte_order, dim, state)?)
             }
             WkbType::MultiPolygon(dim) => {
-                Self::MultiPolygon(MultiPolygon::try_new(buf, byte_order, dim)?)
+                Self::MultiPolygon(MultiPolygon::try_new(buf, byte_order, dim, state)?)
             }
             WkbType::GeometryCollection(dim) => {
-                Self::GeometryCollection(GeometryCollection::try_new(buf, byte_order, dim)?)
+                Self::GeometryCollection(GeometryCollection::try_new(buf, byte_order, dim, state)?)
+            }
+            WkbType::CircularString(dim) => {
+                Self::CircularString(CircularString::try_new(buf, byte_order, dim, state)?)
+            }
+            WkbType::CompoundCurve(dim) => {
+                Self::CompoundCurve(CompoundCurve::try_new(buf, byte_order, dim, state)?)
+            }
+            WkbType::CurvePolygon(dim) => {
+                Self::CurvePolygon(CurvePolygon::try_new(buf, byte_order, dim, state)?)
+            }
+            WkbType::MultiCurve(dim) => {
+                Self::MultiCurve(MultiCurve::try_new(buf, byte_order, dim, state)?)
+            }
+            WkbType::MultiSurface(dim) => {
+                Self::MultiSurface(MultiSurface::try_new(buf, byte_order, dim, state)?)
+            }
+            WkbType::PolyhedralSurface(dim) => {
+                Self::PolyhedralSurface(PolyhedralSurface::try_new(buf, byte_order, dim, state)?)
+            }
+            WkbType::Tin(dim) => Self::Tin(Tin::try_new(buf, byte_order, dim, state)?),
+            WkbType::Triangle(dim) => {
+                Self::Triangle(Triangle::try_new(buf, byte_order, dim, state)?)
             }
         };
         Ok(out)
@@ -180,7 +387,7 @@ impl<'a> GeometryTrait for Wkb<'a> {
     where
         Self: 'b;
     type TriangleType<'b>
-        = UnimplementedTriangle<f64>
+        = Triangle<'a>
     where
         Self: 'b;
     type LineType<'b>
@@ -217,6 +424,15 @@ impl<'a> GeometryTrait for Wkb<'a> {
             A::MultiLineString(ls) => B::MultiLineString(ls),
             A::MultiPolygon(ls) => B::MultiPolygon(ls),
             A::GeometryCollection(gc) => B::GeometryCollection(gc),
+            A::CircularString(cs) => B::LineString(cs.as_line_string()),
+            A::CompoundCurve(cc) => B::GeometryCollection(cc.as_geometry_collection()),
+            A::CurvePolygon(cp) => B::GeometryCollection(cp.as_geometry_collection()),
+            A::MultiCurve(mc) => B::GeometryCollection(mc.as_geometry_collection()),
+            A::MultiSurface(ms) => B::GeometryCollection(ms.as_geometry_collection()),
+            A::PolyhedralSurface(ps) => B::MultiPolygon(ps.as_multi_polygon()),
+            A::Tin(tin) => B::MultiPolygon(tin.as_multi_polygon()),
+            A::Triangle(tri) if tri.is_empty() => B::Polygon(tri.as_polygon()),
+            A::Triangle(tri) => B::Triangle(tri),
         }
     }
 }
@@ -256,7 +472,7 @@ impl<'a> GeometryTrait for &Wkb<'a> {
     where
         Self: 'b;
     type TriangleType<'b>
-        = UnimplementedTriangle<f64>
+        = Triangle<'a>
     where
         Self: 'b;
     type LineType<'b>
@@ -293,6 +509,15 @@ impl<'a> GeometryTrait for &Wkb<'a> {
             A::MultiLineString(ls) => B::MultiLineString(ls),
             A::MultiPolygon(ls) => B::MultiPolygon(ls),
             A::GeometryCollection(gc) => B::GeometryCollection(gc),
+            A::CircularString(cs) => B::LineString(cs.as_line_string()),
+            A::CompoundCurve(cc) => B::GeometryCollection(cc.as_geometry_collection()),
+            A::CurvePolygon(cp) => B::GeometryCollection(cp.as_geometry_collection()),
+            A::MultiCurve(mc) => B::GeometryCollection(mc.as_geometry_collection()),
+            A::MultiSurface(ms) => B::GeometryCollection(ms.as_geometry_collection()),
+            A::PolyhedralSurface(ps) => B::MultiPolygon(ps.as_multi_polygon()),
+            A::Tin(tin) => B::MultiPolygon(tin.as_multi_polygon()),
+            A::Triangle(tri) if tri.is_empty() => B::Polygon(tri.as_polygon()),
+            A::Triangle(tri) => B::Triangle(tri),
         }
     }
 }
@@ -301,6 +526,11 @@ impl<'a> GeometryTrait for &Wkb<'a> {
 
 macro_rules! impl_specialization {
     ($geometry_type:ident) => {
+        impl_specialization!($geometry_type, $geometry_type, GeometryCollection);
+    };
+    // A type that is exposed as the `$variant` geometry type, with `$collection_type` as the type
+    // of any GeometryCollection
+    ($variant:ident, $geometry_type:ident, $collection_type:ident) => {
         impl<'a> GeometryTrait for $geometry_type<'a> {
             type T = f64;
             type PointType<'b>
@@ -328,7 +558,7 @@ macro_rules! impl_specialization {
             where
                 Self: 'b;
             type GeometryCollectionType<'b>
-                = GeometryCollection<'a>
+                = $collection_type<'a>
             where
                 Self: 'b;
             type RectType<'b>
@@ -363,7 +593,7 @@ macro_rules! impl_specialization {
                 Self::TriangleType<'_>,
                 Self::LineType<'_>,
             > {
-                geo_traits::GeometryType::$geometry_type(self)
+                geo_traits::GeometryType::$variant(self)
             }
         }
 
@@ -394,7 +624,7 @@ macro_rules! impl_specialization {
             where
                 Self: 'b;
             type GeometryCollectionType<'b>
-                = GeometryCollection<'a>
+                = $collection_type<'a>
             where
                 Self: 'b;
             type RectType<'b>
@@ -429,7 +659,7 @@ macro_rules! impl_specialization {
                 Self::TriangleType<'_>,
                 Self::LineType<'_>,
             > {
-                geo_traits::GeometryType::$geometry_type(self)
+                geo_traits::GeometryType::$variant(self)
             }
         }
     };
@@ -442,3 +672,8 @@ impl_specialization!(MultiPoint);
 impl_specialization!(MultiLineString);
 impl_specialization!(MultiPolygon);
 impl_specialization!(GeometryCollection);
+impl_specialization!(
+    GeometryCollection,
+    LazyGeometryCollection,
+    LazyGeometryCollection
+);
@@ -1,12 +1,16 @@
-use std::io::Cursor;
-
-use crate::common::Dimension;
-use crate::error::{WkbError, WkbResult};
-use crate::reader::util::{has_srid, ReadBytesExt};
+use crate::common::{Dimension, WkbType};
+use crate::error::{PathSegment, WkbError, WkbResult};
+use crate::reader::util::{
+    read_count_at, read_header, read_srid, unexpected_type, InlineVec, MIN_GEOMETRY_BYTES,
+};
+use crate::reader::{GeometryType, ReadOptions, ReadState};
 use crate::reader::{Wkb, HEADER_BYTES};
 use crate::Endianness;
 use geo_traits::GeometryCollectionTrait;
 
+/// The number of members a [LazyGeometryCollection] holds without allocating
+const INLINE_MEMBERS: usize = 8;
+
 /// A WKB GeometryCollection
 #[derive(Debug, Clone)]
 pub struct GeometryCollection<'a> {
@@ -14,6 +18,7 @@ pub struct GeometryCollection<'a> {
     geometries: Vec<Wkb<'a>>,
     buf: &'a [u8],
     dim: Dimension,
+    srid: Option<u32>,
 }
 
 impl<'a> GeometryCollection<'a> {
@@ -24,22 +29,15 @@ impl<'a> GeometryCollection<'a> {
         buf: &'a [u8],
         byte_order: Endianness,
         dim: Dimension,
+        state: &mut ReadState,
     ) -> WkbResult<Self> {
-        let has_srid = has_srid(buf, byte_order)?;
-        let num_geometries_offset = HEADER_BYTES + if has_srid { 4 } else { 0 };
-
-        let mut reader = Cursor::new(buf);
-        reader.set_position(num_geometries_offset);
-        let num_geometries = reader
-            .read_u32(byte_order)?
-            .try_into()
-            .map_err(|e| WkbError::General(format!("Invalid number of geometries: {}", e)))?;
-
-        let mut geometry_offset = num_geometries_offset as usize + 4;
+        let (srid, num_geometries, mut geometry_offset) =
+            read_collection_header(buf, byte_order, state)?;
 
         let mut geometries = Vec::with_capacity(num_geometries);
-        for _ in 0..num_geometries {
-            let geometry = Wkb::try_new(&buf[geometry_offset..])?;
+        for i in 0..num_geometries {
+            let geometry = Wkb::try_new_with_state(&buf[geometry_offset..], state)
+                .map_err(|e| e.nested(geometry_offset as u64, Some(PathSegment::Member(i))))?;
             geometry_offset += geometry.size() as usize;
             geometries.push(geometry);
         }
@@ -48,6 +46,7 @@ impl<'a> GeometryCollection<'a> {
             geometries,
             buf: &buf[0..geometry_offset],
             dim,
+            srid,
         })
     }
 
@@ -56,6 +55,12 @@ impl<'a> GeometryCollection<'a> {
         self.dim
     }
 
+    /// The SRID embedded in this GeometryCollection's EWKB header, if any
+    #[inline]
+    pub fn srid(&self) -> Option<u32> {
+        self.srid
+    }
+
     /// The number of bytes in this object, including any header
     #[inline]
     pub fn size(&self) -> u64 {
@@ -83,3 +88,179 @@ impl<'a> GeometryCollectionTrait for GeometryCollection<'a> {
         self.geometries.get_unchecked(i)
     }
 }
+
+/// Read the SRID and member count of a GeometryCollection, returning them along with the offset
+/// of the first member
+fn read_collection_header(
+    buf: &[u8],
+    byte_order: Endianness,
+    state: &ReadState,
+) -> WkbResult<(Option<u32>, usize, usize)> {
+    let srid = read_srid(buf, byte_order)?;
+    let num_geometries_offset = HEADER_BYTES + if srid.is_some() { 4 } else { 0 };
+
+    let num_geometries = read_count_at(buf, num_geometries_offset, byte_order, MIN_GEOMETRY_BYTES)?;
+    state.check_elements(num_geometries, num_geometries_offset)?;
+
+    Ok((srid, num_geometries, num_geometries_offset as usize + 4))
+}
+
+/// A WKB GeometryCollection whose members are parsed when they are accessed
+///
+/// A [`GeometryCollection`] keeps every member it parses, which allocates a `Vec` for every
+/// collection. A `LazyGeometryCollection` validates its members just as thoroughly, but keeps
+/// only their offsets and parses each member again when it is accessed. A collection of up to
+/// eight members is parsed and read without allocating, apart from the members of any nested
+/// GeometryCollection, which are kept as usual.
+///
+/// ```
+/// use geo_traits::{GeometryCollectionTrait, GeometryTrait};
+/// use geo_types::{point, Geometry, GeometryCollection};
+/// use wkb::reader::LazyGeometryCollection;
+/// use wkb::writer::write_geometry;
+///
+/// let collection = GeometryCollection::new_from(vec![
+///     Geometry::Point(point!(x: 1., y: 2.)),
+///     Geometry::Point(point!(x: 3., y: 4.)),
+/// ]);
+/// let mut buf = Vec::new();
+/// write_geometry(&mut buf, &collection, &Default::default()).unwrap();
+///
+/// let lazy = LazyGeometryCollection::try_new(&buf).unwrap();
+/// assert_eq!(lazy.num_geometries(), 2);
+/// assert_eq!(lazy.geometry(1).unwrap().dim(), geo_traits::Dimensions::Xy);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LazyGeometryCollection<'a> {
+    /// The offset within `buf` of each of the internal geometries
+    geometry_offsets: InlineVec<usize, INLINE_MEMBERS>,
+    buf: &'a [u8],
+    dim: Dimension,
+    srid: Option<u32>,
+    /// The options the internal geometries were validated with
+    options: ReadOptions,
+}
+
+impl<'a> LazyGeometryCollection<'a> {
+    /// Parse the GeometryCollection at the start of `buf`.
+    ///
+    /// This applies the default [`ReadOptions`].
+    pub fn try_new(buf: &'a [u8]) -> WkbResult<Self> {
+        Self::try_new_with_options(buf, &ReadOptions::default())
+    }
+
+    /// Parse the GeometryCollection at the start of `buf`, enforcing the limits in `options`.
+    ///
+    /// Returns [`WkbError::UnexpectedType`] if `buf` holds any other type of geometry, and
+    /// otherwise the same errors as [`Wkb::try_new_with_options`].
+    pub fn try_new_with_options(buf: &'a [u8], options: &ReadOptions) -> WkbResult<Self> {
+        let (byte_order, wkb_type) = read_header(buf)?;
+        let WkbType::GeometryCollection(dim) = wkb_type else {
+            return Err(unexpected_type(
+                "a GeometryCollection",
+                wkb_type.geometry_type(),
+                0,
+            ));
+        };
+
+        let segment = Some(PathSegment::Geometry(GeometryType::GeometryCollection));
+        let mut state = ReadState::new(options);
+        state.enter().map_err(|e| e.nested(0, segment))?;
+        let collection =
+            Self::parse(buf, byte_order, dim, &mut state).map_err(|e| e.nested(0, segment))?;
+
+        if options.reject_trailing_bytes && collection.size() < buf.len() as u64 {
+            return Err(WkbError::TrailingBytes {
+                offset: collection.size(),
+                count: buf.len() as u64 - collection.size(),
+            });
+        }
+        Ok(collection)
+    }
+
+    fn parse(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        dim: Dimension,
+        state: &mut ReadState,
+    ) -> WkbResult<Self> {
+        let (srid, num_geometries, mut geometry_offset) =
+            read_collection_header(buf, byte_order, state)?;
+
+        let mut geometry_offsets = InlineVec::with_capacity(num_geometries, 0);
+        for i in 0..num_geometries {
+            let geometry = Wkb::try_new_with_state(&buf[geometry_offset..], state)
+                .map_err(|e| e.nested(geometry_offset as u64, Some(PathSegment::Member(i))))?;
+            geometry_offsets.push(geometry_offset);
+            geometry_offset += geometry.size() as usize;
+        }
+
+        Ok(Self {
+            geometry_offsets,
+            buf: &buf[0..geometry_offset],
+            dim,
+            srid,
+            options: state.options().clone(),
+        })
+    }
+
+    /// The dimension of this GeometryCollection
+    pub fn dimension(&self) -> Dimension {
+        self.dim
+    }
+
+    /// The SRID embedded in this GeometryCollection's EWKB header, if any
+    #[inline]
+    pub fn srid(&self) -> Option<u32> {
+        self.srid
+    }
+
+    /// The number of bytes in this object, including any header
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.buf.len() as u64
+    }
+
+    /// Return the underlying buffer of this GeometryCollection.
+    #[inline]
+    pub fn buf(&self) -> &'a [u8] {
+        self.buf
+    }
+
+    /// Parse the already validated geometry that starts at `offset`
+    fn geometry_at(&self, offset: usize) -> Wkb<'a> {
+        // A member within the limits as part of this collection is within them on its own
+        Wkb::try_new_with_state(&self.buf[offset..], &mut ReadState::new(&self.options))
+            .expect("a validated geometry parses again with the same options")
+    }
+}
+
+impl<'a> GeometryCollectionTrait for LazyGeometryCollection<'a> {
+    type GeometryType<'b>
+        = Wkb<'a>
+    where
+        Self: 'b;
+
+    fn num_geometries(&self) -> usize {
+        self.geometry_offsets.len()
+    }
+
+    unsafe fn geometry_unchecked(&self, i: usize) -> Self::GeometryType<'_> {
+        self.geometry_at(*self.geometry_offsets.get_unchecked(i))
+    }
+}
+
+impl<'a> GeometryCollectionTrait for &LazyGeometryCollection<'a> {
+    type GeometryType<'b>
+        = Wkb<'a>
+    where
+        Self: 'b;
+
+    fn num_geometries(&self) -> usize {
+        self.geometry_offsets.len()
+    }
+
+    unsafe fn geometry_unchecked(&self, i: usize) -> Self::GeometryType<'_> {
+        self.geometry_at(*self.geometry_offsets.get_unchecked(i))
+    }
+}
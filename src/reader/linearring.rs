@@ -1,5 +1,3 @@
-use std::io::Cursor;
-
 use geo_traits::{
     GeometryTrait, LineStringTrait, UnimplementedGeometryCollection, UnimplementedLine,
     UnimplementedMultiLineString, UnimplementedMultiPoint, UnimplementedMultiPolygon,
@@ -7,9 +5,10 @@ use geo_traits::{
 };
 
 use crate::common::Dimension;
-use crate::error::{WkbError, WkbResult};
+use crate::error::{PathSegment, WkbResult};
 use crate::reader::coord::Coord;
-use crate::reader::util::ReadBytesExt;
+use crate::reader::util::{check_elements_len, read_f64s, read_u32_at, read_validated_u32_at};
+use crate::reader::ReadState;
 use crate::Endianness;
 
 /// A linear ring in a WKB buffer.
@@ -39,12 +38,13 @@ impl<'a> LinearRing<'a> {
         buf: &'a [u8],
         byte_order: Endianness,
         dim: Dimension,
+        state: &mut ReadState,
     ) -> WkbResult<Self> {
-        let mut reader = Cursor::new(buf);
-        let num_points = reader
-            .read_u32(byte_order)?
-            .try_into()
-            .map_err(|e| WkbError::General(format!("Invalid number of points: {}", e)))?;
+        let num_points = read_u32_at(buf, 0, byte_order)?;
+        let num_points = check_elements_len(buf, 4, num_points, dim.size() as u64 * 8, |i| {
+            PathSegment::Field("coords", i)
+        })?;
+        state.add_coords(num_points, 0)?;
 
         let mut ring = Self {
             buf,
@@ -54,21 +54,24 @@ impl<'a> LinearRing<'a> {
         };
 
         let expected_end_abs = ring.coord_offset(num_points as u64);
-        if expected_end_abs > buf.len() as u64 {
-            return Self::handle_invalid_buffer_length(expected_end_abs, buf.len());
-        }
-
         ring.buf = &ring.buf[0..expected_end_abs as usize];
 
         Ok(ring)
     }
 
-    #[cold]
-    fn handle_invalid_buffer_length(expected_end_abs: u64, buf_len: usize) -> WkbResult<Self> {
-        Err(WkbError::General(format!(
-            "Invalid buffer length for LinearRing: data would end at byte {}, but buffer length is {}.",
-            expected_end_abs, buf_len
-        )))
+    /// Construct a LinearRing from a WKB buffer that [`LinearRing::try_new`] has already
+    /// validated.
+    #[inline]
+    pub(crate) fn from_validated(buf: &'a [u8], byte_order: Endianness, dim: Dimension) -> Self {
+        let num_points = read_validated_u32_at(buf, 0, byte_order) as usize;
+        let mut ring = Self {
+            buf,
+            byte_order,
+            num_points,
+            dim,
+        };
+        ring.buf = &buf[0..ring.coord_offset(num_points as u64) as usize];
+        ring
     }
 
     /// The number of bytes in this object, including any header
@@ -103,6 +106,29 @@ impl<'a> LinearRing<'a> {
     pub fn byte_order(&self) -> Endianness {
         self.byte_order
     }
+
+    /// Decode all of the coordinates of this LinearRing into the start of `out`, returning the number
+    /// of values written.
+    ///
+    /// The ordinates of each coordinate are written one after another, as in
+    /// `[x0, y0, x1, y1, ...]` for a 2D LinearRing, so `out` needs room for
+    /// [`num_coords`][LineStringTrait::num_coords] times 2, 3 or 4 values per coordinate,
+    /// depending on the dimension. Coordinates in the platform's native byte order are copied
+    /// without swapping any bytes.
+    ///
+    /// ### Panics
+    ///
+    /// If `out` is too short to hold every ordinate.
+    pub fn decode_coords(&self, out: &mut [f64]) -> usize {
+        let len = self.num_points * self.dim.size();
+        assert!(
+            out.len() >= len,
+            "buffer of length {} is too short for {len} ordinates",
+            out.len()
+        );
+        read_f64s(self.coords_slice(), self.byte_order, &mut out[..len]);
+        len
+    }
 }
 
 impl<'a> LineStringTrait for LinearRing<'a> {
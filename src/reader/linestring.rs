@@ -1,9 +1,8 @@
-use std::io::Cursor;
-
 use crate::common::Dimension;
-use crate::error::{WkbError, WkbResult};
+use crate::error::{PathSegment, WkbResult};
 use crate::reader::coord::Coord;
-use crate::reader::util::{has_srid, ReadBytesExt};
+use crate::reader::util::{check_elements_len, read_f64s, read_srid, read_u32_at};
+use crate::reader::ReadState;
 use crate::reader::HEADER_BYTES;
 use crate::Endianness;
 use geo_traits::LineStringTrait;
@@ -22,6 +21,7 @@ pub struct LineString<'a> {
     /// The offset into the buffer where the first coord is located
     coord_offset: u64,
     dim: Dimension,
+    srid: Option<u32>,
 }
 
 impl<'a> LineString<'a> {
@@ -32,18 +32,19 @@ impl<'a> LineString<'a> {
         buf: &'a [u8],
         byte_order: Endianness,
         dim: Dimension,
+        state: &mut ReadState,
     ) -> WkbResult<Self> {
-        let has_srid = has_srid(buf, byte_order)?;
+        let srid = read_srid(buf, byte_order)?;
 
-        let num_points_offset = HEADER_BYTES + if has_srid { 4 } else { 0 };
-        let mut reader = Cursor::new(buf);
-        reader.set_position(num_points_offset);
-        let num_points = reader
-            .read_u32(byte_order)?
-            .try_into()
-            .map_err(|e| WkbError::General(format!("Invalid number of points: {}", e)))?;
+        let num_points_offset = HEADER_BYTES + if srid.is_some() { 4 } else { 0 };
+        let num_points = read_u32_at(buf, num_points_offset, byte_order)?;
 
         let coord_offset = num_points_offset + 4; // Skip the 4-byte num_points field
+        let num_points =
+            check_elements_len(buf, coord_offset, num_points, dim.size() as u64 * 8, |i| {
+                PathSegment::Field("coords", i)
+            })?;
+        state.add_coords(num_points, num_points_offset)?;
 
         let mut linestring = Self {
             buf,
@@ -51,26 +52,15 @@ impl<'a> LineString<'a> {
             num_points,
             coord_offset,
             dim,
+            srid,
         };
 
         let expected_end_abs = linestring.coord_offset(num_points as u64);
-        if expected_end_abs > buf.len() as u64 {
-            return Self::handle_invalid_buffer_length(expected_end_abs, buf.len());
-        }
-
         linestring.buf = &linestring.buf[0..expected_end_abs as usize];
 
         Ok(linestring)
     }
 
-    #[cold]
-    fn handle_invalid_buffer_length(expected_end_abs: u64, buf_len: usize) -> WkbResult<Self> {
-        Err(WkbError::General(format!(
-            "Invalid buffer length for LineString: geometry would end at byte {}, but buffer length is {}.",
-            expected_end_abs, buf_len
-        )))
-    }
-
     /// The number of bytes in this object, including any header
     #[inline]
     pub fn size(&self) -> u64 {
@@ -88,6 +78,12 @@ impl<'a> LineString<'a> {
         self.dim
     }
 
+    /// The SRID embedded in this LineString's EWKB header, if any
+    #[inline]
+    pub fn srid(&self) -> Option<u32> {
+        self.srid
+    }
+
     /// The slice of bytes containing the coordinates of this LineString. The byte order
     /// of LineString can be obtained by calling [LineString::byte_order].
     #[inline]
@@ -103,6 +99,29 @@ impl<'a> LineString<'a> {
         self.byte_order
     }
 
+    /// Decode all of the coordinates of this LineString into the start of `out`, returning the number
+    /// of values written.
+    ///
+    /// The ordinates of each coordinate are written one after another, as in
+    /// `[x0, y0, x1, y1, ...]` for a 2D LineString, so `out` needs room for
+    /// [`num_coords`][LineStringTrait::num_coords] times 2, 3 or 4 values per coordinate,
+    /// depending on the dimension. Coordinates in the platform's native byte order are copied
+    /// without swapping any bytes.
+    ///
+    /// ### Panics
+    ///
+    /// If `out` is too short to hold every ordinate.
+    pub fn decode_coords(&self, out: &mut [f64]) -> usize {
+        let len = self.num_points * self.dim.size();
+        assert!(
+            out.len() >= len,
+            "buffer of length {} is too short for {len} ordinates",
+            out.len()
+        );
+        read_f64s(self.coords_slice(), self.byte_order, &mut out[..len]);
+        len
+    }
+
     /// Get the underlying buffer of this LineString
     #[inline]
     pub fn buf(&self) -> &'a [u8] {
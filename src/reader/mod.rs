@@ -1,31 +1,71 @@
 //! Parse buffers containing WKB-encoded geometries.
+//!
+//! Geometries in other formats, such as [`read_wkt`][crate::wkt::read_wkt],
+//! [`read_geojson`][crate::geojson::read_geojson],
+//! [`read_spatialite`][crate::spatialite::read_spatialite], and
+//! [`read_twkb`][crate::twkb::read_twkb], are read by converting them to WKB in a
+//! caller-provided `Vec<u8>`, whose previous contents are replaced. The converted geometry is then
+//! accessed through the same [`Wkb`] type as any other WKB, and a single buffer can be reused to
+//! read many geometries without allocating.
 
 // Each of the data structures in this module is intended to mirror the [WKB
 // spec](https://portal.ogc.org/files/?artifact_id=25355).
 
+mod buf;
+mod circularstring;
+mod compoundcurve;
 mod coord;
+mod curvepolygon;
+mod export;
 mod geometry;
 mod geometry_collection;
 mod linearring;
 mod linestring;
+mod multicurve;
 mod multilinestring;
 mod multipoint;
 mod multipolygon;
+mod multisurface;
+mod options;
 mod point;
 mod polygon;
-mod util;
+mod polyhedralsurface;
+#[cfg(feature = "bytes")]
+mod shared;
+mod stream;
+mod tin;
+mod triangle;
+pub(crate) mod util;
 
 pub use crate::common::Dimension;
+pub use buf::{WkbBuf, WkbBytes};
+pub use circularstring::CircularString;
+pub use compoundcurve::CompoundCurve;
 pub use coord::Coord;
+pub use curvepolygon::CurvePolygon;
 pub use geometry::Wkb;
-pub use geometry_collection::GeometryCollection;
+pub(crate) use geometry::WkbInner;
+pub use geometry_collection::{GeometryCollection, LazyGeometryCollection};
 pub use linearring::LinearRing;
 pub use linestring::LineString;
+pub use multicurve::MultiCurve;
 pub use multilinestring::MultiLineString;
 pub use multipoint::MultiPoint;
 pub use multipolygon::MultiPolygon;
+pub use multisurface::MultiSurface;
+pub(crate) use options::ReadState;
+pub use options::{ReadLimit, ReadOptions};
 pub use point::Point;
 pub use polygon::Polygon;
+pub use polyhedralsurface::PolyhedralSurface;
+#[cfg(feature = "bytes")]
+pub use shared::{
+    BytesGeometryCollection, BytesLineString, BytesMultiLineString, BytesMultiPoint,
+    BytesMultiPolygon, BytesPoint, BytesPolygon, BytesWkb,
+};
+pub use stream::WkbStreamReader;
+pub use tin::Tin;
+pub use triangle::Triangle;
 
 use crate::error::WkbResult;
 
@@ -36,10 +76,64 @@ pub fn read_wkb(buf: &[u8]) -> WkbResult<Wkb<'_>> {
     Wkb::try_new(buf)
 }
 
+/// Parse a WKB byte slice into a geometry, requiring the geometry to span the entire slice.
+///
+/// Returns [`WkbError::TrailingBytes`][crate::error::WkbError::TrailingBytes] with the number of
+/// bytes left over if the slice continues after the end of the geometry.
+pub fn read_wkb_exact(buf: &[u8]) -> WkbResult<Wkb<'_>> {
+    let options = ReadOptions {
+        reject_trailing_bytes: true,
+        ..Default::default()
+    };
+    Wkb::try_new_with_options(buf, &options)
+}
+
+/// Iterate over a byte slice holding WKB geometries back to back.
+///
+/// Each geometry is parsed with [`Wkb::try_new`] and its [size][Wkb::size] is used to find the
+/// start of the next one. Iteration stops after the first error, whose offset is relative to the
+/// start of `buf`.
+///
+/// ```
+/// use wkb::reader::iter_wkb;
+///
+/// // Two little-endian 2D points
+/// let mut buf = Vec::new();
+/// for (x, y) in [(1.0f64, 2.0f64), (3.0, 4.0)] {
+///     buf.push(1);
+///     buf.extend_from_slice(&1u32.to_le_bytes());
+///     buf.extend_from_slice(&x.to_le_bytes());
+///     buf.extend_from_slice(&y.to_le_bytes());
+/// }
+///
+/// let geometries = iter_wkb(&buf).collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(geometries.len(), 2);
+/// assert_eq!(geometries[1].size(), 21);
+/// ```
+pub fn iter_wkb(buf: &[u8]) -> impl Iterator<Item = WkbResult<Wkb<'_>>> {
+    let mut offset = 0;
+    let mut failed = false;
+    std::iter::from_fn(move || {
+        if failed || offset >= buf.len() {
+            return None;
+        }
+        match Wkb::try_new(&buf[offset..]) {
+            Ok(wkb) => {
+                offset += wkb.size() as usize;
+                Some(Ok(wkb))
+            }
+            Err(e) => {
+                failed = true;
+                Some(Err(e.nested(offset as u64, None)))
+            }
+        }
+    })
+}
+
 /// The geometry type of the WKB object.
 ///
-/// This is marked as non exhaustive because we do not currently support extended WKB types, such
-/// as curves.
+/// This is marked as non exhaustive so that further geometry types can be supported without a
+/// breaking change.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub enum GeometryType {
@@ -57,6 +151,22 @@ pub enum GeometryType {
     MultiPolygon,
     /// A WKB GeometryCollection
     GeometryCollection,
+    /// A WKB CircularString
+    CircularString,
+    /// A WKB CompoundCurve
+    CompoundCurve,
+    /// A WKB CurvePolygon
+    CurvePolygon,
+    /// A WKB MultiCurve
+    MultiCurve,
+    /// A WKB MultiSurface
+    MultiSurface,
+    /// A WKB PolyhedralSurface
+    PolyhedralSurface,
+    /// A WKB TIN
+    Tin,
+    /// A WKB Triangle
+    Triangle,
 }
 
 /// skip endianness and wkb type
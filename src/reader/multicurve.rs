@@ -0,0 +1,92 @@
+use crate::common::Dimension;
+use crate::error::{PathSegment, WkbResult};
+use crate::reader::util::{offset_within, unexpected_type};
+use crate::reader::ReadState;
+use crate::reader::{GeometryCollection, GeometryType, Wkb};
+use crate::Endianness;
+use geo_traits::GeometryCollectionTrait;
+
+/// A WKB MultiCurve
+///
+/// A MultiCurve is a collection of LineStrings, CircularStrings, and CompoundCurves. It has the
+/// same binary layout as a GeometryCollection.
+#[derive(Debug, Clone)]
+pub struct MultiCurve<'a> {
+    /// The curves in this MultiCurve
+    curves: GeometryCollection<'a>,
+}
+
+impl<'a> MultiCurve<'a> {
+    /// Construct a new MultiCurve from a WKB buffer.
+    ///
+    /// This will parse the WKB header and extract all contained curves.
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        dim: Dimension,
+        state: &mut ReadState,
+    ) -> WkbResult<Self> {
+        let curves = GeometryCollection::try_new(buf, byte_order, dim, state)?;
+        for (i, curve) in curves.geometries().enumerate() {
+            match curve.geometry_type() {
+                GeometryType::LineString
+                | GeometryType::CircularString
+                | GeometryType::CompoundCurve => (),
+                other => {
+                    let offset = offset_within(buf, curve.buf());
+                    return Err(unexpected_type(
+                        "a LineString, CircularString or CompoundCurve",
+                        other,
+                        offset,
+                    )
+                    .nested(0, Some(PathSegment::Member(i))));
+                }
+            }
+        }
+        Ok(Self { curves })
+    }
+
+    /// The number of bytes in this object, including any header
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.curves.size()
+    }
+
+    /// The dimension of this MultiCurve
+    pub fn dimension(&self) -> Dimension {
+        self.curves.dimension()
+    }
+
+    /// The SRID embedded in this MultiCurve's EWKB header, if any
+    #[inline]
+    pub fn srid(&self) -> Option<u32> {
+        self.curves.srid()
+    }
+
+    /// The number of curves in this MultiCurve
+    pub fn num_curves(&self) -> usize {
+        self.curves.num_geometries()
+    }
+
+    /// Access the curve at index `i`
+    pub fn curve(&self, i: usize) -> Option<&Wkb<'a>> {
+        self.curves.geometry(i)
+    }
+
+    /// An iterator over the curves in this MultiCurve
+    pub fn curves(&self) -> impl Iterator<Item = &Wkb<'a>> {
+        self.curves.geometries()
+    }
+
+    /// Get the underlying buffer of this MultiCurve
+    #[inline]
+    pub fn buf(&self) -> &'a [u8] {
+        self.curves.buf()
+    }
+
+    /// View the curves of this MultiCurve as a [GeometryCollection].
+    #[inline]
+    pub fn as_geometry_collection(&self) -> &GeometryCollection<'a> {
+        &self.curves
+    }
+}
@@ -1,9 +1,8 @@
-use std::io::Cursor;
-
 use crate::common::Dimension;
-use crate::error::{WkbError, WkbResult};
+use crate::error::{PathSegment, WkbResult};
 use crate::reader::linestring::LineString;
-use crate::reader::util::{has_srid, ReadBytesExt};
+use crate::reader::util::{read_count_at, read_srid, MIN_GEOMETRY_BYTES};
+use crate::reader::ReadState;
 use crate::reader::HEADER_BYTES;
 use crate::Endianness;
 use geo_traits::MultiLineStringTrait;
@@ -17,6 +16,7 @@ pub struct MultiLineString<'a> {
     wkb_line_strings: Vec<LineString<'a>>,
     buf: &'a [u8],
     dim: Dimension,
+    srid: Option<u32>,
 }
 
 impl<'a> MultiLineString<'a> {
@@ -24,30 +24,39 @@ impl<'a> MultiLineString<'a> {
         buf: &'a [u8],
         byte_order: Endianness,
         dim: Dimension,
+        state: &mut ReadState,
     ) -> WkbResult<Self> {
-        let has_srid = has_srid(buf, byte_order)?;
-        let num_line_strings_offset = HEADER_BYTES + if has_srid { 4 } else { 0 };
+        let srid = read_srid(buf, byte_order)?;
+        let num_line_strings_offset = HEADER_BYTES + if srid.is_some() { 4 } else { 0 };
 
-        let mut reader = Cursor::new(buf);
-        reader.set_position(num_line_strings_offset);
-        let num_line_strings = reader
-            .read_u32(byte_order)?
-            .try_into()
-            .map_err(|e| WkbError::General(format!("Invalid number of line strings: {}", e)))?;
+        let num_line_strings =
+            read_count_at(buf, num_line_strings_offset, byte_order, MIN_GEOMETRY_BYTES)?;
+        state.check_elements(num_line_strings, num_line_strings_offset)?;
 
         let mut line_string_offset = num_line_strings_offset + 4;
 
         let mut wkb_line_strings = Vec::with_capacity(num_line_strings);
-        for _ in 0..num_line_strings {
-            let ls = LineString::try_new(&buf[line_string_offset as usize..], byte_order, dim)?;
+        if num_line_strings > 0 {
+            state
+                .enter()
+                .map_err(|e| e.nested(line_string_offset, Some(PathSegment::Member(0))))?;
+        }
+        for i in 0..num_line_strings {
+            let ls =
+                LineString::try_new(&buf[line_string_offset as usize..], byte_order, dim, state)
+                    .map_err(|e| e.nested(line_string_offset, Some(PathSegment::Member(i))))?;
             line_string_offset += ls.size();
             wkb_line_strings.push(ls);
         }
+        if num_line_strings > 0 {
+            state.leave();
+        }
 
         Ok(Self {
             wkb_line_strings,
             buf: &buf[0..line_string_offset as usize],
             dim,
+            srid,
         })
     }
 
@@ -62,6 +71,12 @@ impl<'a> MultiLineString<'a> {
         self.dim
     }
 
+    /// The SRID embedded in this MultiLineString's EWKB header, if any
+    #[inline]
+    pub fn srid(&self) -> Option<u32> {
+        self.srid
+    }
+
     /// Get the underlying buffer of this MultiLineString
     #[inline]
     pub fn buf(&self) -> &'a [u8] {
@@ -1,9 +1,8 @@
-use std::io::Cursor;
-
 use crate::common::Dimension;
-use crate::error::{WkbError, WkbResult};
+use crate::error::{PathSegment, WkbResult};
 use crate::reader::point::Point;
-use crate::reader::util::{has_srid, ReadBytesExt};
+use crate::reader::util::{check_elements_len, read_srid, read_u32_at};
+use crate::reader::ReadState;
 use crate::reader::HEADER_BYTES;
 use crate::Endianness;
 use geo_traits::MultiPointTrait;
@@ -23,6 +22,7 @@ pub struct MultiPoint<'a> {
     points_offset: u64,
 
     dim: Dimension,
+    srid: Option<u32>,
 }
 
 impl<'a> MultiPoint<'a> {
@@ -30,45 +30,44 @@ impl<'a> MultiPoint<'a> {
         buf: &'a [u8],
         byte_order: Endianness,
         dim: Dimension,
+        state: &mut ReadState,
     ) -> WkbResult<Self> {
-        let has_srid = has_srid(buf, byte_order)?;
-        let num_points_offset = HEADER_BYTES + if has_srid { 4 } else { 0 };
+        let srid = read_srid(buf, byte_order)?;
+        let num_points_offset = HEADER_BYTES + if srid.is_some() { 4 } else { 0 };
 
-        let mut reader = Cursor::new(buf);
-        // Set reader to after 1-byte byteOrder and 4-byte wkbType
-        reader.set_position(num_points_offset);
-        let num_points = reader
-            .read_u32(byte_order)?
-            .try_into()
-            .map_err(|e| WkbError::General(format!("Invalid number of points: {}", e)))?;
+        let num_points = read_u32_at(buf, num_points_offset, byte_order)?;
 
         let points_offset = num_points_offset + 4;
+        let num_points = check_elements_len(
+            buf,
+            points_offset,
+            num_points,
+            HEADER_BYTES + dim.size() as u64 * 8,
+            PathSegment::Member,
+        )?;
+        state.add_coords(num_points, num_points_offset)?;
+        if num_points > 0 {
+            // Members are not parsed here, but still count towards the nesting depth
+            state
+                .enter()
+                .map_err(|e| e.nested(points_offset, Some(PathSegment::Member(0))))?;
+            state.leave();
+        }
         let mut multipoint = Self {
             buf,
             byte_order,
             num_points,
             points_offset,
             dim,
+            srid,
         };
 
         let end_offset = multipoint.point_offset(num_points as u64);
-        if end_offset > buf.len() as u64 {
-            return Self::handle_invalid_buffer_length(end_offset, buf.len());
-        }
-
         multipoint.buf = &buf[0..end_offset as usize];
 
         Ok(multipoint)
     }
 
-    #[cold]
-    fn handle_invalid_buffer_length(expected_end_abs: u64, buf_len: usize) -> WkbResult<Self> {
-        Err(WkbError::General(format!(
-            "Invalid buffer length for MultiPoint: geometry would end at byte {}, but buffer length is {}.",
-            expected_end_abs, buf_len
-        )))
-    }
-
     /// The number of bytes in this object, including any header
     #[inline]
     pub fn size(&self) -> u64 {
@@ -85,6 +84,12 @@ impl<'a> MultiPoint<'a> {
         self.dim
     }
 
+    /// The SRID embedded in this MultiPoint's EWKB header, if any
+    #[inline]
+    pub fn srid(&self) -> Option<u32> {
+        self.srid
+    }
+
     /// Get the underlying buffer of this MultiPoint
     pub fn buf(&self) -> &'a [u8] {
         self.buf
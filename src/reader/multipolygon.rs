@@ -1,20 +1,28 @@
-use std::io::Cursor;
-
 use crate::common::Dimension;
-use crate::error::{WkbError, WkbResult};
+use crate::error::{PathSegment, WkbResult};
 use crate::reader::polygon::Polygon;
-use crate::reader::util::{has_srid, ReadBytesExt};
+use crate::reader::util::{read_count_at, read_srid, InlineVec, MIN_GEOMETRY_BYTES};
+use crate::reader::ReadState;
 use crate::reader::HEADER_BYTES;
 use crate::Endianness;
 use geo_traits::MultiPolygonTrait;
 
+/// The number of polygons a [MultiPolygon] holds without allocating
+const INLINE_POLYGONS: usize = 8;
+
 /// A WKB MultiPolygon
+///
+/// The polygons are validated when the MultiPolygon is parsed, but only their offsets are kept,
+/// and each [Polygon] is built again when it is accessed. A MultiPolygon with up to eight
+/// polygons, each with up to four rings, is parsed and read without allocating.
 #[derive(Debug, Clone)]
 pub struct MultiPolygon<'a> {
-    /// A Polygon object for each of the internal line strings
-    wkb_polygons: Vec<Polygon<'a>>,
+    /// The offset within `buf` of each of the internal polygons
+    polygon_offsets: InlineVec<usize, INLINE_POLYGONS>,
     buf: &'a [u8],
+    byte_order: Endianness,
     dim: Dimension,
+    srid: Option<u32>,
 }
 
 impl<'a> MultiPolygon<'a> {
@@ -22,30 +30,38 @@ impl<'a> MultiPolygon<'a> {
         buf: &'a [u8],
         byte_order: Endianness,
         dim: Dimension,
+        state: &mut ReadState,
     ) -> WkbResult<Self> {
-        let has_srid = has_srid(buf, byte_order)?;
-        let num_polygons_offset = HEADER_BYTES + if has_srid { 4 } else { 0 };
+        let srid = read_srid(buf, byte_order)?;
+        let num_polygons_offset = HEADER_BYTES + if srid.is_some() { 4 } else { 0 };
 
-        let mut reader = Cursor::new(buf);
-        reader.set_position(num_polygons_offset);
-        let num_polygons = reader
-            .read_u32(byte_order)?
-            .try_into()
-            .map_err(|e| WkbError::General(format!("Invalid number of polygons: {}", e)))?;
+        let num_polygons = read_count_at(buf, num_polygons_offset, byte_order, MIN_GEOMETRY_BYTES)?;
+        state.check_elements(num_polygons, num_polygons_offset)?;
 
         let mut polygon_offset = num_polygons_offset + 4;
 
-        let mut wkb_polygons = Vec::with_capacity(num_polygons);
-        for _ in 0..num_polygons {
-            let polygon = Polygon::try_new(&buf[polygon_offset as usize..], byte_order, dim)?;
+        let mut polygon_offsets = InlineVec::with_capacity(num_polygons, 0);
+        if num_polygons > 0 {
+            state
+                .enter()
+                .map_err(|e| e.nested(polygon_offset, Some(PathSegment::Member(0))))?;
+        }
+        for i in 0..num_polygons {
+            let polygon = Polygon::try_new(&buf[polygon_offset as usize..], byte_order, dim, state)
+                .map_err(|e| e.nested(polygon_offset, Some(PathSegment::Member(i))))?;
+            polygon_offsets.push(polygon_offset as usize);
             polygon_offset += polygon.size();
-            wkb_polygons.push(polygon);
+        }
+        if num_polygons > 0 {
+            state.leave();
         }
 
         Ok(Self {
-            wkb_polygons,
+            polygon_offsets,
             buf: &buf[0..polygon_offset as usize],
+            byte_order,
             dim,
+            srid,
         })
     }
 
@@ -60,38 +76,49 @@ impl<'a> MultiPolygon<'a> {
         self.dim
     }
 
+    /// The SRID embedded in this MultiPolygon's EWKB header, if any
+    #[inline]
+    pub fn srid(&self) -> Option<u32> {
+        self.srid
+    }
+
     /// Get the underlying buffer of this MultiPolygon
     pub fn buf(&self) -> &'a [u8] {
         self.buf
     }
+
+    /// Build the already validated polygon that starts at `offset`
+    fn polygon_at(&self, offset: usize) -> Polygon<'a> {
+        Polygon::from_validated(&self.buf[offset..], self.byte_order, self.dim)
+    }
 }
 
 impl<'a> MultiPolygonTrait for MultiPolygon<'a> {
     type InnerPolygonType<'b>
-        = &'b Polygon<'a>
+        = Polygon<'a>
     where
         Self: 'b;
 
     fn num_polygons(&self) -> usize {
-        self.wkb_polygons.len()
+        self.polygon_offsets.len()
     }
 
     unsafe fn polygon_unchecked(&self, i: usize) -> Self::InnerPolygonType<'_> {
-        self.wkb_polygons.get_unchecked(i)
+        self.polygon_at(*self.polygon_offsets.get_unchecked(i))
     }
 }
 
-impl<'a, 'b> MultiPolygonTrait for &'b MultiPolygon<'a> {
+impl<'a> MultiPolygonTrait for &MultiPolygon<'a> {
     type InnerPolygonType<'c>
-        = &'b Polygon<'a>
+        = Polygon<'a>
     where
         Self: 'c;
 
     fn num_polygons(&self) -> usize {
-        self.wkb_polygons.len()
+        self.polygon_offsets.len()
     }
 
     unsafe fn polygon_unchecked(&self, i: usize) -> Self::InnerPolygonType<'_> {
-        self.wkb_polygons.get_unchecked(i)
+        self.polygon_at(*self.polygon_offsets.get_unchecked(i))
     }
 }
@@ -0,0 +1,94 @@
+use crate::common::Dimension;
+use crate::error::{PathSegment, WkbResult};
+use crate::reader::util::{offset_within, unexpected_type};
+use crate::reader::ReadState;
+use crate::reader::{GeometryCollection, GeometryType, Wkb};
+use crate::Endianness;
+use geo_traits::GeometryCollectionTrait;
+
+/// A WKB MultiSurface
+///
+/// A MultiSurface is a collection of surfaces, such as Polygons and CurvePolygons. It has the same
+/// binary layout as a GeometryCollection.
+#[derive(Debug, Clone)]
+pub struct MultiSurface<'a> {
+    /// The surfaces in this MultiSurface
+    surfaces: GeometryCollection<'a>,
+}
+
+impl<'a> MultiSurface<'a> {
+    /// Construct a new MultiSurface from a WKB buffer.
+    ///
+    /// This will parse the WKB header and extract all contained surfaces.
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        dim: Dimension,
+        state: &mut ReadState,
+    ) -> WkbResult<Self> {
+        let surfaces = GeometryCollection::try_new(buf, byte_order, dim, state)?;
+        for (i, surface) in surfaces.geometries().enumerate() {
+            match surface.geometry_type() {
+                GeometryType::Polygon
+                | GeometryType::CurvePolygon
+                | GeometryType::PolyhedralSurface
+                | GeometryType::Tin
+                | GeometryType::Triangle => (),
+                other => {
+                    let offset = offset_within(buf, surface.buf());
+                    return Err(unexpected_type(
+                        "a Polygon, CurvePolygon, PolyhedralSurface, TIN or Triangle",
+                        other,
+                        offset,
+                    )
+                    .nested(0, Some(PathSegment::Member(i))));
+                }
+            }
+        }
+        Ok(Self { surfaces })
+    }
+
+    /// The number of bytes in this object, including any header
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.surfaces.size()
+    }
+
+    /// The dimension of this MultiSurface
+    pub fn dimension(&self) -> Dimension {
+        self.surfaces.dimension()
+    }
+
+    /// The SRID embedded in this MultiSurface's EWKB header, if any
+    #[inline]
+    pub fn srid(&self) -> Option<u32> {
+        self.surfaces.srid()
+    }
+
+    /// The number of surfaces in this MultiSurface
+    pub fn num_surfaces(&self) -> usize {
+        self.surfaces.num_geometries()
+    }
+
+    /// Access the surface at index `i`
+    pub fn surface(&self, i: usize) -> Option<&Wkb<'a>> {
+        self.surfaces.geometry(i)
+    }
+
+    /// An iterator over the surfaces in this MultiSurface
+    pub fn surfaces(&self) -> impl Iterator<Item = &Wkb<'a>> {
+        self.surfaces.geometries()
+    }
+
+    /// Get the underlying buffer of this MultiSurface
+    #[inline]
+    pub fn buf(&self) -> &'a [u8] {
+        self.surfaces.buf()
+    }
+
+    /// View the surfaces of this MultiSurface as a [GeometryCollection].
+    #[inline]
+    pub fn as_geometry_collection(&self) -> &GeometryCollection<'a> {
+        &self.surfaces
+    }
+}
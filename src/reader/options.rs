@@ -0,0 +1,140 @@
+use std::fmt;
+
+use crate::error::{WkbError, WkbPath, WkbResult};
+
+/// Options for reading geometries from WKB
+///
+/// The limits guard against untrusted input that declares huge element counts or deeply nested
+/// collections. Element counts are always validated against the remaining buffer length before
+/// any allocation, so these limits only need to be tightened to bound the work done for buffers
+/// that are themselves large.
+#[derive(Debug, Clone)]
+pub struct ReadOptions {
+    /// The maximum nesting depth of geometries.
+    ///
+    /// The outermost geometry has a depth of 1, and each member of a multi geometry or geometry
+    /// collection is one level deeper than its parent. Defaults to 128.
+    pub max_depth: usize,
+
+    /// The maximum value of any single element count, such as the number of points in a
+    /// LineString, rings in a Polygon, or members in a GeometryCollection. Defaults to no limit.
+    pub max_elements: usize,
+
+    /// The maximum total number of coordinates across the whole geometry. Defaults to no limit.
+    pub max_coords: usize,
+
+    /// Whether to return [`WkbError::TrailingBytes`] when the geometry does not consume the entire
+    /// buffer.
+    ///
+    /// By default, any bytes after the end of the geometry are ignored and excluded from
+    /// [`Wkb::buf`][crate::reader::Wkb::buf].
+    pub reject_trailing_bytes: bool,
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: 128,
+            max_elements: usize::MAX,
+            max_coords: usize::MAX,
+            reject_trailing_bytes: false,
+        }
+    }
+}
+
+/// A limit from [`ReadOptions`] that can be exceeded while reading
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ReadLimit {
+    /// [`ReadOptions::max_depth`]
+    Depth,
+    /// [`ReadOptions::max_elements`]
+    Elements,
+    /// [`ReadOptions::max_coords`]
+    Coords,
+}
+
+impl fmt::Display for ReadLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Depth => f.write_str("nesting depth"),
+            Self::Elements => f.write_str("element count"),
+            Self::Coords => f.write_str("total coordinate count"),
+        }
+    }
+}
+
+/// The limits being enforced during a single parse, along with the running totals they apply to
+#[derive(Debug)]
+pub(crate) struct ReadState<'o> {
+    options: &'o ReadOptions,
+    depth: usize,
+    num_coords: usize,
+}
+
+impl<'o> ReadState<'o> {
+    pub(crate) fn new(options: &'o ReadOptions) -> Self {
+        Self {
+            options,
+            depth: 0,
+            num_coords: 0,
+        }
+    }
+
+    /// The options being enforced
+    pub(crate) fn options(&self) -> &'o ReadOptions {
+        self.options
+    }
+
+    /// Descend into a geometry, failing if it would be nested too deeply
+    pub(crate) fn enter(&mut self) -> WkbResult<()> {
+        if self.depth >= self.options.max_depth {
+            return Err(limit_exceeded(ReadLimit::Depth, self.options.max_depth, 0));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    /// Return from a geometry entered with [`ReadState::enter`]
+    pub(crate) fn leave(&mut self) {
+        self.depth -= 1;
+    }
+
+    /// Check an element count read at `offset`
+    pub(crate) fn check_elements(&self, count: usize, offset: u64) -> WkbResult<()> {
+        if count > self.options.max_elements {
+            return Err(limit_exceeded(
+                ReadLimit::Elements,
+                self.options.max_elements,
+                offset,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Account for `count` coordinates whose count was read at `offset`
+    pub(crate) fn add_coords(&mut self, count: usize, offset: u64) -> WkbResult<()> {
+        self.check_elements(count, offset)?;
+        match self.num_coords.checked_add(count) {
+            Some(total) if total <= self.options.max_coords => {
+                self.num_coords = total;
+                Ok(())
+            }
+            _ => Err(limit_exceeded(
+                ReadLimit::Coords,
+                self.options.max_coords,
+                offset,
+            )),
+        }
+    }
+}
+
+#[cold]
+fn limit_exceeded(limit: ReadLimit, max: usize, offset: u64) -> WkbError {
+    WkbError::LimitExceeded {
+        limit,
+        max,
+        offset,
+        path: WkbPath::default(),
+    }
+}
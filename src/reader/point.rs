@@ -1,7 +1,7 @@
 use crate::common::Dimension;
-use crate::error::{WkbError, WkbResult};
+use crate::error::WkbResult;
 use crate::reader::coord::Coord;
-use crate::reader::util::has_srid;
+use crate::reader::util::{check_len, read_srid};
 use crate::Endianness;
 use geo_traits::{CoordTrait, PointTrait};
 
@@ -16,6 +16,7 @@ pub struct Point<'a> {
     coord: Coord<'a>,
     buf: &'a [u8],
     dim: Dimension,
+    srid: Option<u32>,
     is_empty: bool,
 }
 
@@ -29,19 +30,17 @@ impl<'a> Point<'a> {
         byte_order: Endianness,
         dim: Dimension,
     ) -> WkbResult<Self> {
-        let has_srid = has_srid(buf, byte_order)?;
+        let srid = read_srid(buf, byte_order)?;
 
         // The space of the byte order + geometry type
         let mut offset = 5;
-        if has_srid {
+        if srid.is_some() {
             // Skip SRID bytes if they exist
             offset += 4;
         }
 
+        check_len(buf, offset, dim.size() as u64 * 8)?;
         let expected_end = offset as usize + dim.size() * 8;
-        if buf.len() < expected_end {
-            return Self::handle_invalid_buffer_length(expected_end, buf.len());
-        }
 
         let coord = Coord::new(&buf[offset as usize..expected_end], byte_order, dim);
         let is_empty = (0..coord.dim().size()).all(|coord_dim| {
@@ -57,18 +56,11 @@ impl<'a> Point<'a> {
             coord,
             buf: &buf[0..expected_end],
             dim,
+            srid,
             is_empty,
         })
     }
 
-    #[cold]
-    fn handle_invalid_buffer_length(expected_end: usize, buf_len: usize) -> WkbResult<Self> {
-        Err(WkbError::General(format!(
-            "Invalid buffer length for Point: geometry would end at byte {}, but buffer length is {}.",
-            expected_end, buf_len
-        )))
-    }
-
     /// The number of bytes in this object, including any header
     #[inline]
     pub fn size(&self) -> u64 {
@@ -81,6 +73,12 @@ impl<'a> Point<'a> {
         self.dim
     }
 
+    /// The SRID embedded in this Point's EWKB header, if any
+    #[inline]
+    pub fn srid(&self) -> Option<u32> {
+        self.srid
+    }
+
     /// Whether this Point is empty
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -1,58 +1,90 @@
-use std::io::Cursor;
-
-use crate::common::Dimension;
-use crate::error::{WkbError, WkbResult};
+use crate::common::{Dimension, WkbGeometryCode};
+use crate::error::{PathSegment, WkbResult};
 use crate::reader::linearring::LinearRing;
-use crate::reader::util::{has_srid, ReadBytesExt};
+use crate::reader::util::{read_count_at, read_srid, read_validated_u32_at, InlineVec};
+use crate::reader::ReadState;
 use crate::reader::HEADER_BYTES;
 use crate::Endianness;
 use geo_traits::PolygonTrait;
 
+/// The number of rings a [Polygon] holds without allocating
+const INLINE_RINGS: usize = 4;
+
 /// A WKB Polygon
 ///
-/// This has been preprocessed, so access to any internal coordinate is `O(1)`.
+/// This has been preprocessed, so access to any internal coordinate is `O(1)`. Only the offset
+/// of each ring is kept, so a Polygon with up to four rings is parsed without allocating.
 #[derive(Debug, Clone)]
 pub struct Polygon<'a> {
-    wkb_linear_rings: Vec<LinearRing<'a>>,
+    /// The offset within `buf` of each of the linear rings
+    ring_offsets: InlineVec<usize, INLINE_RINGS>,
     buf: &'a [u8],
+    byte_order: Endianness,
     dim: Dimension,
+    srid: Option<u32>,
 }
 
 impl<'a> Polygon<'a> {
     /// Construct a new Polygon from a WKB buffer.
     ///
-    /// This will parse the WKB header and extract all linear rings.
+    /// This will parse the WKB header and validate all linear rings.
     pub(crate) fn try_new(
         buf: &'a [u8],
         byte_order: Endianness,
         dim: Dimension,
+        state: &mut ReadState,
     ) -> WkbResult<Self> {
-        let has_srid = has_srid(buf, byte_order)?;
-        let num_rings_offset = HEADER_BYTES + if has_srid { 4 } else { 0 };
-
-        let mut reader = Cursor::new(buf);
-        reader.set_position(num_rings_offset);
+        let srid = read_srid(buf, byte_order)?;
+        let num_rings_offset = HEADER_BYTES + if srid.is_some() { 4 } else { 0 };
 
-        let num_rings = reader
-            .read_u32(byte_order)?
-            .try_into()
-            .map_err(|e| WkbError::General(format!("Invalid number of rings: {}", e)))?;
+        // Each ring holds at least its 4-byte number of points
+        let num_rings = read_count_at(buf, num_rings_offset, byte_order, 4)?;
+        state.check_elements(num_rings, num_rings_offset)?;
 
         let mut ring_offset = num_rings_offset + 4;
-        let mut wkb_linear_rings = Vec::with_capacity(num_rings);
-        for _ in 0..num_rings {
-            let ring = LinearRing::try_new(&buf[ring_offset as usize..], byte_order, dim)?;
+        let mut ring_offsets = InlineVec::with_capacity(num_rings, 0);
+        for i in 0..num_rings {
+            let ring = LinearRing::try_new(&buf[ring_offset as usize..], byte_order, dim, state)
+                .map_err(|e| e.nested(ring_offset, Some(PathSegment::Field("rings", i))))?;
+            ring_offsets.push(ring_offset as usize);
             ring_offset += ring.size();
-            wkb_linear_rings.push(ring);
         }
 
         Ok(Self {
-            wkb_linear_rings,
+            ring_offsets,
             buf: &buf[0..ring_offset as usize],
+            byte_order,
             dim,
+            srid,
         })
     }
 
+    /// Construct a Polygon from a WKB buffer that [`Polygon::try_new`] has already validated.
+    pub(crate) fn from_validated(buf: &'a [u8], byte_order: Endianness, dim: Dimension) -> Self {
+        let geometry_code = WkbGeometryCode::new(read_validated_u32_at(buf, 1, byte_order));
+        let srid = geometry_code
+            .has_srid()
+            .then(|| read_validated_u32_at(buf, HEADER_BYTES as usize, byte_order));
+        let num_rings_offset = HEADER_BYTES as usize + if srid.is_some() { 4 } else { 0 };
+        let num_rings = read_validated_u32_at(buf, num_rings_offset, byte_order) as usize;
+
+        let mut ring_offset = num_rings_offset + 4;
+        let mut ring_offsets = InlineVec::with_capacity(num_rings, 0);
+        for _ in 0..num_rings {
+            ring_offsets.push(ring_offset);
+            ring_offset +=
+                LinearRing::from_validated(&buf[ring_offset..], byte_order, dim).size() as usize;
+        }
+
+        Self {
+            ring_offsets,
+            buf: &buf[0..ring_offset],
+            byte_order,
+            dim,
+            srid,
+        }
+    }
+
     /// The number of bytes in this object, including any header
     #[inline]
     pub fn size(&self) -> u64 {
@@ -64,65 +96,62 @@ impl<'a> Polygon<'a> {
         self.dim
     }
 
+    /// The SRID embedded in this Polygon's EWKB header, if any
+    #[inline]
+    pub fn srid(&self) -> Option<u32> {
+        self.srid
+    }
+
     /// Get the underlying buffer of this Polygon
     #[inline]
     pub fn buf(&self) -> &'a [u8] {
         self.buf
     }
+
+    /// The ring that starts at `offset`
+    #[inline]
+    fn ring_at(&self, offset: usize) -> LinearRing<'a> {
+        LinearRing::from_validated(&self.buf[offset..], self.byte_order, self.dim)
+    }
 }
 
 impl<'a> PolygonTrait for Polygon<'a> {
     type RingType<'b>
-        = &'b LinearRing<'a>
+        = LinearRing<'a>
     where
         Self: 'b;
 
     fn num_interiors(&self) -> usize {
         // Support an empty polygon with no rings
-        if self.wkb_linear_rings.is_empty() {
-            0
-        } else {
-            self.wkb_linear_rings.len() - 1
-        }
+        self.ring_offsets.len().saturating_sub(1)
     }
 
     fn exterior(&self) -> Option<Self::RingType<'_>> {
-        if self.wkb_linear_rings.is_empty() {
-            None
-        } else {
-            Some(&self.wkb_linear_rings[0])
-        }
+        self.ring_offsets
+            .first()
+            .map(|&offset| self.ring_at(offset))
     }
 
     unsafe fn interior_unchecked(&self, i: usize) -> Self::RingType<'_> {
-        self.wkb_linear_rings.get_unchecked(i + 1)
+        self.ring_at(*self.ring_offsets.get_unchecked(i + 1))
     }
 }
 
-impl<'a, 'b> PolygonTrait for &'b Polygon<'a> {
+impl<'a> PolygonTrait for &Polygon<'a> {
     type RingType<'c>
-        = &'b LinearRing<'a>
+        = LinearRing<'a>
     where
         Self: 'c;
 
     fn num_interiors(&self) -> usize {
-        // Support an empty polygon with no rings
-        if self.wkb_linear_rings.is_empty() {
-            0
-        } else {
-            self.wkb_linear_rings.len() - 1
-        }
+        (*self).num_interiors()
     }
 
     fn exterior(&self) -> Option<Self::RingType<'_>> {
-        if self.wkb_linear_rings.is_empty() {
-            None
-        } else {
-            Some(&self.wkb_linear_rings[0])
-        }
+        (*self).exterior()
     }
 
     unsafe fn interior_unchecked(&self, i: usize) -> Self::RingType<'_> {
-        self.wkb_linear_rings.get_unchecked(i + 1)
+        (*self).interior_unchecked(i)
     }
 }
@@ -0,0 +1,85 @@
+use crate::common::{Dimension, WkbType};
+use crate::error::{PathSegment, WkbResult};
+use crate::reader::util::{check_patch_header, offset_within};
+use crate::reader::ReadState;
+use crate::reader::{MultiPolygon, Polygon};
+use crate::Endianness;
+use geo_traits::MultiPolygonTrait;
+
+/// A WKB PolyhedralSurface
+///
+/// A PolyhedralSurface is a contiguous collection of Polygon patches that share common boundary
+/// segments. It has the same binary layout as a MultiPolygon.
+#[derive(Debug, Clone)]
+pub struct PolyhedralSurface<'a> {
+    /// The patches of this PolyhedralSurface
+    patches: MultiPolygon<'a>,
+}
+
+impl<'a> PolyhedralSurface<'a> {
+    /// Construct a new PolyhedralSurface from a WKB buffer.
+    ///
+    /// This will parse the WKB header and extract all contained patches.
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        dim: Dimension,
+        state: &mut ReadState,
+    ) -> WkbResult<Self> {
+        let patches = MultiPolygon::try_new(buf, byte_order, dim, state)?;
+        for (i, patch) in patches.polygons().enumerate() {
+            check_patch_header(patch.buf(), WkbType::Polygon(dim), byte_order, "a Polygon")
+                .map_err(|e| {
+                    e.nested(
+                        offset_within(buf, patch.buf()),
+                        Some(PathSegment::Member(i)),
+                    )
+                })?;
+        }
+        Ok(Self { patches })
+    }
+
+    /// The number of bytes in this object, including any header
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.patches.size()
+    }
+
+    /// The dimension of this PolyhedralSurface
+    pub fn dimension(&self) -> Dimension {
+        self.patches.dimension()
+    }
+
+    /// The SRID embedded in this PolyhedralSurface's EWKB header, if any
+    #[inline]
+    pub fn srid(&self) -> Option<u32> {
+        self.patches.srid()
+    }
+
+    /// The number of patches in this PolyhedralSurface
+    pub fn num_patches(&self) -> usize {
+        self.patches.num_polygons()
+    }
+
+    /// Access the patch at index `i`
+    pub fn patch(&self, i: usize) -> Option<Polygon<'a>> {
+        self.patches.polygon(i)
+    }
+
+    /// An iterator over the patches of this PolyhedralSurface
+    pub fn patches(&self) -> impl Iterator<Item = Polygon<'a>> + '_ {
+        self.patches.polygons()
+    }
+
+    /// Get the underlying buffer of this PolyhedralSurface
+    #[inline]
+    pub fn buf(&self) -> &'a [u8] {
+        self.patches.buf()
+    }
+
+    /// View the patches of this PolyhedralSurface as a [MultiPolygon].
+    #[inline]
+    pub fn as_multi_polygon(&self) -> &MultiPolygon<'a> {
+        &self.patches
+    }
+}
@@ -0,0 +1,303 @@
+use bytes::Bytes;
+use geo_traits::{
+    Dimensions, GeometryCollectionTrait, GeometryTrait, LineStringTrait, MultiLineStringTrait,
+    MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, UnimplementedLine,
+    UnimplementedRect,
+};
+
+use crate::reader::{
+    Coord, GeometryCollection, LineString, LinearRing, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon, Wkb, WkbBuf, WkbInner,
+};
+
+/// A parsed WKB geometry that shares ownership of a [`Bytes`] buffer.
+///
+/// This is a [`WkbBuf`] backed by [`Bytes`], so it is `'static`, `Send`, and `Sync`, and can be
+/// moved across tasks or stored without a lifetime. Its `to_*` methods, such as
+/// [`WkbBuf::to_polygon`], return the geometry as one of the typed `Bytes*` geometries, which
+/// share the same buffer instead of copying it.
+///
+/// ```
+/// use bytes::Bytes;
+/// use geo_traits::{LineStringTrait, PolygonTrait};
+/// use geo_types::polygon;
+/// use wkb::reader::BytesWkb;
+/// use wkb::writer::write_geometry;
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)];
+/// let mut buf = Vec::new();
+/// write_geometry(&mut buf, &polygon, &Default::default()).unwrap();
+/// let payload = Bytes::from(buf);
+///
+/// let geometry = BytesWkb::try_new(payload).unwrap();
+/// let polygon = geometry.to_polygon().unwrap();
+/// let handle = std::thread::spawn(move || polygon.exterior().unwrap().num_coords());
+/// assert_eq!(handle.join().unwrap(), 4);
+/// ```
+pub type BytesWkb = WkbBuf<Bytes>;
+
+macro_rules! bytes_geometry {
+    (
+        $(#[$attr:meta])*
+        $name:ident($geometry_type:ident), $as_fn:ident, $to_fn:ident
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            /// Borrows from `bytes`. Its `'static` lifetime is never exposed, and is shortened to
+            /// the lifetime of `&self` whenever it is borrowed.
+            geometry: $geometry_type<'static>,
+            bytes: Bytes,
+        }
+
+        impl $name {
+            /// Borrow the parsed geometry.
+            pub fn $as_fn(&self) -> &$geometry_type<'_> {
+                &self.geometry
+            }
+
+            /// The buffer this geometry was parsed from, which may also hold an enclosing
+            /// geometry.
+            pub fn bytes(&self) -> &Bytes {
+                &self.bytes
+            }
+        }
+
+        impl BytesWkb {
+            #[doc = concat!("Return this geometry as a [`", stringify!($name), "`] sharing the same buffer, or `None` if it is not a ", stringify!($geometry_type), ".")]
+            pub fn $to_fn(&self) -> Option<$name> {
+                match self.wkb.inner() {
+                    WkbInner::$geometry_type(geometry) => Some($name {
+                        geometry: geometry.clone(),
+                        bytes: self.bytes.clone(),
+                    }),
+                    _ => None,
+                }
+            }
+        }
+
+        impl GeometryTrait for $name {
+            type T = f64;
+            type PointType<'b>
+                = Point<'b>
+            where
+                Self: 'b;
+            type LineStringType<'b>
+                = LineString<'b>
+            where
+                Self: 'b;
+            type PolygonType<'b>
+                = Polygon<'b>
+            where
+                Self: 'b;
+            type MultiPointType<'b>
+                = MultiPoint<'b>
+            where
+                Self: 'b;
+            type MultiLineStringType<'b>
+                = MultiLineString<'b>
+            where
+                Self: 'b;
+            type MultiPolygonType<'b>
+                = MultiPolygon<'b>
+            where
+                Self: 'b;
+            type GeometryCollectionType<'b>
+                = GeometryCollection<'b>
+            where
+                Self: 'b;
+            type RectType<'b>
+                = UnimplementedRect<f64>
+            where
+                Self: 'b;
+            type LineType<'b>
+                = UnimplementedLine<f64>
+            where
+                Self: 'b;
+            type TriangleType<'b>
+                = geo_traits::UnimplementedTriangle<f64>
+            where
+                Self: 'b;
+
+            fn dim(&self) -> Dimensions {
+                self.$as_fn().dimension().into()
+            }
+
+            fn as_type(
+                &self,
+            ) -> geo_traits::GeometryType<
+                '_,
+                Self::PointType<'_>,
+                Self::LineStringType<'_>,
+                Self::PolygonType<'_>,
+                Self::MultiPointType<'_>,
+                Self::MultiLineStringType<'_>,
+                Self::MultiPolygonType<'_>,
+                Self::GeometryCollectionType<'_>,
+                Self::RectType<'_>,
+                Self::TriangleType<'_>,
+                Self::LineType<'_>,
+            > {
+                geo_traits::GeometryType::$geometry_type(self.$as_fn())
+            }
+        }
+    };
+}
+
+bytes_geometry!(
+    /// A [`Point`] that shares ownership of the [`Bytes`] buffer it was parsed from.
+    BytesPoint(Point), as_point, to_point
+);
+bytes_geometry!(
+    /// A [`LineString`] that shares ownership of the [`Bytes`] buffer it was parsed from.
+    BytesLineString(LineString), as_line_string, to_line_string
+);
+bytes_geometry!(
+    /// A [`Polygon`] that shares ownership of the [`Bytes`] buffer it was parsed from.
+    BytesPolygon(Polygon), as_polygon, to_polygon
+);
+bytes_geometry!(
+    /// A [`MultiPoint`] that shares ownership of the [`Bytes`] buffer it was parsed from.
+    BytesMultiPoint(MultiPoint), as_multi_point, to_multi_point
+);
+bytes_geometry!(
+    /// A [`MultiLineString`] that shares ownership of the [`Bytes`] buffer it was parsed from.
+    BytesMultiLineString(MultiLineString), as_multi_line_string, to_multi_line_string
+);
+bytes_geometry!(
+    /// A [`MultiPolygon`] that shares ownership of the [`Bytes`] buffer it was parsed from.
+    BytesMultiPolygon(MultiPolygon), as_multi_polygon, to_multi_polygon
+);
+bytes_geometry!(
+    /// A [`GeometryCollection`] that shares ownership of the [`Bytes`] buffer it was parsed from.
+    BytesGeometryCollection(GeometryCollection), as_geometry_collection, to_geometry_collection
+);
+
+impl BytesMultiPolygon {
+    /// Iterate over the polygons, each of which shares this geometry's buffer.
+    pub fn owned_polygons(&self) -> impl ExactSizeIterator<Item = BytesPolygon> + '_ {
+        self.geometry.polygons().map(|polygon| BytesPolygon {
+            geometry: polygon,
+            bytes: self.bytes.clone(),
+        })
+    }
+}
+
+impl BytesGeometryCollection {
+    /// Iterate over the member geometries, each of which shares this geometry's buffer.
+    ///
+    /// The bytes of each member are sliced to that member, so [`WkbBuf::as_bytes`] and
+    /// [`WkbBuf::into_inner`] return only the member's WKB.
+    pub fn owned_geometries(&self) -> impl ExactSizeIterator<Item = BytesWkb> + '_ {
+        self.geometry.geometries().map(|geometry| {
+            let wkb = geometry.clone();
+            let bytes = self.bytes.slice_ref(wkb.buf());
+            WkbBuf { wkb, bytes }
+        })
+    }
+}
+
+impl PointTrait for BytesPoint {
+    type CoordType<'b>
+        = Coord<'b>
+    where
+        Self: 'b;
+
+    fn coord(&self) -> Option<Self::CoordType<'_>> {
+        self.as_point().coord()
+    }
+}
+
+impl LineStringTrait for BytesLineString {
+    type CoordType<'b>
+        = Coord<'b>
+    where
+        Self: 'b;
+
+    fn num_coords(&self) -> usize {
+        self.as_line_string().num_coords()
+    }
+
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        self.as_line_string().coord_unchecked(i)
+    }
+}
+
+impl PolygonTrait for BytesPolygon {
+    type RingType<'b>
+        = LinearRing<'b>
+    where
+        Self: 'b;
+
+    fn num_interiors(&self) -> usize {
+        self.as_polygon().num_interiors()
+    }
+
+    fn exterior(&self) -> Option<Self::RingType<'_>> {
+        self.as_polygon().exterior()
+    }
+
+    unsafe fn interior_unchecked(&self, i: usize) -> Self::RingType<'_> {
+        self.as_polygon().interior_unchecked(i)
+    }
+}
+
+impl MultiPointTrait for BytesMultiPoint {
+    type InnerPointType<'b>
+        = Point<'b>
+    where
+        Self: 'b;
+
+    fn num_points(&self) -> usize {
+        self.as_multi_point().num_points()
+    }
+
+    unsafe fn point_unchecked(&self, i: usize) -> Self::InnerPointType<'_> {
+        self.as_multi_point().point_unchecked(i)
+    }
+}
+
+impl MultiLineStringTrait for BytesMultiLineString {
+    type InnerLineStringType<'b>
+        = &'b LineString<'b>
+    where
+        Self: 'b;
+
+    fn num_line_strings(&self) -> usize {
+        self.as_multi_line_string().num_line_strings()
+    }
+
+    unsafe fn line_string_unchecked(&self, i: usize) -> Self::InnerLineStringType<'_> {
+        self.as_multi_line_string().line_string_unchecked(i)
+    }
+}
+
+impl MultiPolygonTrait for BytesMultiPolygon {
+    type InnerPolygonType<'b>
+        = Polygon<'b>
+    where
+        Self: 'b;
+
+    fn num_polygons(&self) -> usize {
+        self.as_multi_polygon().num_polygons()
+    }
+
+    unsafe fn polygon_unchecked(&self, i: usize) -> Self::InnerPolygonType<'_> {
+        self.as_multi_polygon().polygon_unchecked(i)
+    }
+}
+
+impl GeometryCollectionTrait for BytesGeometryCollection {
+    type GeometryType<'b>
+        = &'b Wkb<'b>
+    where
+        Self: 'b;
+
+    fn num_geometries(&self) -> usize {
+        self.as_geometry_collection().num_geometries()
+    }
+
+    unsafe fn geometry_unchecked(&self, i: usize) -> Self::GeometryType<'_> {
+        self.as_geometry_collection().geometry_unchecked(i)
+    }
+}
@@ -0,0 +1,261 @@
+use std::io::{ErrorKind, Read};
+
+use crate::common::{Dimension, WkbGeometryCode, WkbType};
+use crate::error::{PathSegment, WkbError, WkbPath, WkbResult};
+use crate::reader::util::{read_u32_at, unexpected_eof};
+use crate::reader::{ReadOptions, ReadState, Wkb};
+use crate::Endianness;
+
+/// Read concatenated WKB geometries from a [`Read`] source, one geometry at a time.
+///
+/// The length of each geometry is determined incrementally from its headers and element counts,
+/// so only the bytes of the current geometry are held in memory. Bytes are only buffered once
+/// they have been read from the source, so a corrupt element count cannot cause a large
+/// allocation. The limits in [`ReadOptions`] are enforced while reading.
+///
+/// Each item is the complete WKB buffer of one geometry, which has been validated and can be
+/// parsed with [`Wkb::try_new`]. Iteration stops after the first error, whose offset is relative
+/// to the start of the stream.
+///
+/// ```
+/// use std::io::Cursor;
+/// use wkb::reader::{Wkb, WkbStreamReader};
+///
+/// // Two little-endian 2D points
+/// let mut data = Vec::new();
+/// for (x, y) in [(1.0f64, 2.0f64), (3.0, 4.0)] {
+///     data.push(1);
+///     data.extend_from_slice(&1u32.to_le_bytes());
+///     data.extend_from_slice(&x.to_le_bytes());
+///     data.extend_from_slice(&y.to_le_bytes());
+/// }
+///
+/// let reader = WkbStreamReader::new(Cursor::new(data));
+/// let geometries = reader.collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(geometries.len(), 2);
+/// assert!(Wkb::try_new(&geometries[1]).is_ok());
+/// ```
+#[derive(Debug)]
+pub struct WkbStreamReader<R> {
+    reader: R,
+    options: ReadOptions,
+    /// The bytes of the geometry currently being read
+    buf: Vec<u8>,
+    /// The offset in the stream of the geometry currently being read
+    position: u64,
+    /// Whether the end of the stream or an error has been reached
+    done: bool,
+}
+
+impl<R: Read> WkbStreamReader<R> {
+    /// Create a new stream reader with the default [`ReadOptions`]
+    pub fn new(reader: R) -> Self {
+        Self::with_options(reader, ReadOptions::default())
+    }
+
+    /// Create a new stream reader that enforces the limits in `options`
+    pub fn with_options(reader: R, options: ReadOptions) -> Self {
+        Self {
+            reader,
+            options,
+            buf: Vec::new(),
+            position: 0,
+            done: false,
+        }
+    }
+
+    /// The offset in the stream at which the next geometry starts
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Consume this stream reader, returning the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Read the next geometry, returning `None` at the end of the stream.
+    pub fn read_next(&mut self) -> WkbResult<Option<Vec<u8>>> {
+        if self.done {
+            return Ok(None);
+        }
+        let result = self.read_next_inner();
+        if !matches!(result, Ok(Some(_))) {
+            self.done = true;
+        }
+        result.map_err(|e| e.nested(self.position, None))
+    }
+
+    fn read_next_inner(&mut self) -> WkbResult<Option<Vec<u8>>> {
+        // A stream that ends cleanly between two geometries is not an error
+        let mut byte_order = [0u8; 1];
+        match self.reader.read_exact(&mut byte_order) {
+            Ok(()) => (),
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e.into()),
+        }
+
+        self.buf.clear();
+        let mut state = ReadState::new(&self.options);
+        let mut reader = byte_order.as_slice().chain(&mut self.reader);
+        read_geometry(&mut reader, &mut self.buf, &mut state, true)?;
+
+        // Validate the geometry with the same checks as a slice, such as the member types allowed
+        // in curves and surfaces
+        Wkb::try_new_with_options(&self.buf, &self.options)?;
+
+        let geometry = std::mem::take(&mut self.buf);
+        self.position += geometry.len() as u64;
+        Ok(Some(geometry))
+    }
+}
+
+impl<R: Read> Iterator for WkbStreamReader<R> {
+    type Item = WkbResult<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_next().transpose()
+    }
+}
+
+/// Append exactly `n` bytes from `reader` to `buf`
+fn fill(reader: &mut impl Read, buf: &mut Vec<u8>, n: u64) -> WkbResult<()> {
+    let start = buf.len() as u64;
+    let read = reader.take(n).read_to_end(buf)? as u64;
+    if read < n {
+        return Err(unexpected_eof(start, n, (start + read) as usize));
+    }
+    Ok(())
+}
+
+/// Read one complete geometry from `reader` into `buf`, using its headers to find its length.
+///
+/// Members of multi geometries whose member type is fixed are not named in error paths, matching
+/// the paths reported when parsing a slice.
+fn read_geometry(
+    reader: &mut impl Read,
+    buf: &mut Vec<u8>,
+    state: &mut ReadState,
+    named: bool,
+) -> WkbResult<()> {
+    let start = buf.len() as u64;
+    fill(reader, buf, 1)?;
+    let byte = buf[start as usize];
+    let byte_order = Endianness::try_from(byte).map_err(|_| WkbError::InvalidByteOrder {
+        byte,
+        offset: start,
+        path: WkbPath::default(),
+    })?;
+
+    fill(reader, buf, 4)?;
+    let geometry_code = WkbGeometryCode::new(read_u32_at(buf, start + 1, byte_order)?);
+    let wkb_type = geometry_code
+        .get_type()
+        .map_err(|e| e.nested(start + 1, None))?;
+    if geometry_code.has_srid() {
+        fill(reader, buf, 4)?;
+    }
+
+    let segment = named.then(|| PathSegment::Geometry(wkb_type.geometry_type()));
+    state.enter().map_err(|e| e.nested(start, segment))?;
+    read_geometry_body(reader, buf, state, byte_order, wkb_type)
+        .map_err(|e| e.nested(0, segment))?;
+    state.leave();
+    Ok(())
+}
+
+/// Read the part of a geometry that follows its header
+fn read_geometry_body(
+    reader: &mut impl Read,
+    buf: &mut Vec<u8>,
+    state: &mut ReadState,
+    byte_order: Endianness,
+    wkb_type: WkbType,
+) -> WkbResult<()> {
+    use WkbType::*;
+    match wkb_type {
+        Point(dim) => {
+            state.add_coords(1, buf.len() as u64)?;
+            fill(reader, buf, coord_size(dim))
+        }
+        LineString(dim) | CircularString(dim) => read_coords(reader, buf, state, byte_order, dim),
+        Polygon(dim) | Triangle(dim) => {
+            let num_rings = read_count(reader, buf, state, byte_order)?;
+            for i in 0..num_rings {
+                read_coords(reader, buf, state, byte_order, dim)
+                    .map_err(|e| e.nested(0, Some(PathSegment::Field("rings", i))))?;
+            }
+            Ok(())
+        }
+        MultiPoint(_) | MultiLineString(_) | MultiPolygon(_) | PolyhedralSurface(_) | Tin(_) => {
+            read_members(reader, buf, state, byte_order, false)
+        }
+        GeometryCollection(_)
+        | CompoundCurve(_)
+        | CurvePolygon(_)
+        | MultiCurve(_)
+        | MultiSurface(_) => read_members(reader, buf, state, byte_order, true),
+    }
+}
+
+/// Read the member geometries of a multi geometry or collection, preceded by their count
+fn read_members(
+    reader: &mut impl Read,
+    buf: &mut Vec<u8>,
+    state: &mut ReadState,
+    byte_order: Endianness,
+    named: bool,
+) -> WkbResult<()> {
+    let num_geometries = read_count(reader, buf, state, byte_order)?;
+    for i in 0..num_geometries {
+        read_geometry(reader, buf, state, named)
+            .map_err(|e| e.nested(0, Some(PathSegment::Member(i))))?;
+    }
+    Ok(())
+}
+
+/// Read a sequence of coordinates preceded by their count
+fn read_coords(
+    reader: &mut impl Read,
+    buf: &mut Vec<u8>,
+    state: &mut ReadState,
+    byte_order: Endianness,
+    dim: Dimension,
+) -> WkbResult<()> {
+    let count_offset = buf.len() as u64;
+    let num_coords = read_count(reader, buf, state, byte_order)?;
+    state.add_coords(num_coords, count_offset)?;
+
+    let start = buf.len() as u64;
+    let coord_size = coord_size(dim);
+    fill(reader, buf, num_coords as u64 * coord_size).map_err(|e| match e {
+        // Report the first incomplete coordinate
+        WkbError::UnexpectedEof { available, .. } => {
+            let first_incomplete = available / coord_size;
+            let offset = start + first_incomplete * coord_size;
+            unexpected_eof(offset, coord_size, (start + available) as usize).nested(
+                0,
+                Some(PathSegment::Field("coords", first_incomplete as usize)),
+            )
+        }
+        e => e,
+    })
+}
+
+/// Read an element count, checking it against the configured limits
+fn read_count(
+    reader: &mut impl Read,
+    buf: &mut Vec<u8>,
+    state: &mut ReadState,
+    byte_order: Endianness,
+) -> WkbResult<usize> {
+    let offset = buf.len() as u64;
+    fill(reader, buf, 4)?;
+    let count = read_u32_at(buf, offset, byte_order)? as usize;
+    state.check_elements(count, offset)?;
+    Ok(count)
+}
+
+fn coord_size(dim: Dimension) -> u64 {
+    dim.size() as u64 * 8
+}
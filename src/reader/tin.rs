@@ -0,0 +1,97 @@
+use crate::common::{Dimension, WkbType};
+use crate::error::{PathSegment, WkbResult};
+use crate::reader::util::{check_patch_header, invalid_geometry, offset_within};
+use crate::reader::ReadState;
+use crate::reader::{MultiPolygon, Triangle};
+use crate::Endianness;
+use geo_traits::{MultiPolygonTrait, PolygonTrait};
+
+/// A WKB TIN (triangulated irregular network)
+///
+/// A TIN is a PolyhedralSurface whose patches are all Triangles. It has the same binary layout as
+/// a MultiPolygon.
+#[derive(Debug, Clone)]
+pub struct Tin<'a> {
+    /// The triangles of this TIN
+    triangles: MultiPolygon<'a>,
+}
+
+impl<'a> Tin<'a> {
+    /// Construct a new TIN from a WKB buffer.
+    ///
+    /// This will parse the WKB header and extract all contained triangles.
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        dim: Dimension,
+        state: &mut ReadState,
+    ) -> WkbResult<Self> {
+        let triangles = MultiPolygon::try_new(buf, byte_order, dim, state)?;
+        for (i, triangle) in triangles.polygons().enumerate() {
+            let offset = offset_within(buf, triangle.buf());
+            check_patch_header(
+                triangle.buf(),
+                WkbType::Triangle(dim),
+                byte_order,
+                "a Triangle",
+            )
+            .map_err(|e| e.nested(offset, Some(PathSegment::Member(i))))?;
+            // An empty Triangle is valid on its own, but not as a patch
+            if triangle.exterior().is_none() {
+                return Err(invalid_geometry("a TIN patch must not be empty")
+                    .nested(offset, Some(PathSegment::Member(i))));
+            }
+            Triangle::try_from_polygon(triangle)
+                .map_err(|e| e.nested(offset, Some(PathSegment::Member(i))))?;
+        }
+        Ok(Self { triangles })
+    }
+
+    /// The number of bytes in this object, including any header
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.triangles.size()
+    }
+
+    /// The dimension of this TIN
+    pub fn dimension(&self) -> Dimension {
+        self.triangles.dimension()
+    }
+
+    /// The SRID embedded in this TIN's EWKB header, if any
+    #[inline]
+    pub fn srid(&self) -> Option<u32> {
+        self.triangles.srid()
+    }
+
+    /// The number of triangles in this TIN
+    pub fn num_triangles(&self) -> usize {
+        self.triangles.num_polygons()
+    }
+
+    /// Access the triangle at index `i`
+    pub fn triangle(&self, i: usize) -> Option<Triangle<'a>> {
+        self.triangles
+            .polygon(i)
+            .map(Triangle::from_polygon_unchecked)
+    }
+
+    /// An iterator over the triangles of this TIN
+    pub fn triangles(&self) -> impl Iterator<Item = Triangle<'a>> + '_ {
+        self.triangles
+            .polygons()
+            .map(Triangle::from_polygon_unchecked)
+    }
+
+    /// Get the underlying buffer of this TIN
+    #[inline]
+    pub fn buf(&self) -> &'a [u8] {
+        self.triangles.buf()
+    }
+
+    /// View the triangles of this TIN as a [MultiPolygon].
+    #[inline]
+    pub fn as_multi_polygon(&self) -> &MultiPolygon<'a> {
+        &self.triangles
+    }
+}
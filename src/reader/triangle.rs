@@ -0,0 +1,276 @@
+use crate::common::Dimension;
+use crate::error::WkbResult;
+use crate::reader::coord::Coord;
+use crate::reader::polygon::Polygon;
+use crate::reader::util::invalid_geometry;
+use crate::reader::ReadState;
+use crate::Endianness;
+use geo_traits::{
+    GeometryTrait, LineStringTrait, PolygonTrait, TriangleTrait, UnimplementedGeometryCollection,
+    UnimplementedLine, UnimplementedLineString, UnimplementedMultiLineString,
+    UnimplementedMultiPoint, UnimplementedMultiPolygon, UnimplementedPoint, UnimplementedPolygon,
+    UnimplementedRect,
+};
+
+/// A WKB Triangle
+///
+/// A Triangle has the same binary layout as a Polygon with a single closed ring of four points.
+/// It may also be empty, in which case it has no rings.
+#[derive(Debug, Clone)]
+pub struct Triangle<'a> {
+    /// The Polygon representation of this Triangle
+    polygon: Polygon<'a>,
+}
+
+impl<'a> Triangle<'a> {
+    /// Construct a new Triangle from a WKB buffer.
+    ///
+    /// This will parse the WKB header and validate the number of rings and points.
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        dim: Dimension,
+        state: &mut ReadState,
+    ) -> WkbResult<Self> {
+        let polygon = Polygon::try_new(buf, byte_order, dim, state)?;
+        Self::try_from_polygon(polygon)
+    }
+
+    /// Construct a Triangle from a Polygon that has already been parsed
+    pub(crate) fn try_from_polygon(polygon: Polygon<'a>) -> WkbResult<Self> {
+        let num_rings = polygon.exterior().map_or(0, |_| 1) + polygon.num_interiors();
+        let valid = match polygon.exterior() {
+            None => true,
+            Some(exterior) => num_rings == 1 && exterior.num_coords() == 4,
+        };
+        if !valid {
+            return Err(invalid_geometry(
+                "a Triangle must have a single ring with 4 points",
+            ));
+        }
+        Ok(Self { polygon })
+    }
+
+    /// Construct a Triangle from a Polygon that has already been validated as a Triangle
+    pub(crate) fn from_polygon_unchecked(polygon: Polygon<'a>) -> Self {
+        Self { polygon }
+    }
+
+    /// The number of bytes in this object, including any header
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.polygon.size()
+    }
+
+    /// The dimension of this Triangle
+    pub fn dimension(&self) -> Dimension {
+        self.polygon.dimension()
+    }
+
+    /// The SRID embedded in this Triangle's EWKB header, if any
+    #[inline]
+    pub fn srid(&self) -> Option<u32> {
+        self.polygon.srid()
+    }
+
+    /// Whether this Triangle is empty
+    ///
+    /// The [TriangleTrait] accessors panic on an empty Triangle.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.polygon.exterior().is_none()
+    }
+
+    /// Get the underlying buffer of this Triangle
+    #[inline]
+    pub fn buf(&self) -> &'a [u8] {
+        self.polygon.buf()
+    }
+
+    /// View this Triangle as a [Polygon].
+    #[inline]
+    pub fn as_polygon(&self) -> &Polygon<'a> {
+        &self.polygon
+    }
+
+    #[inline]
+    fn nth_coord(&self, n: usize) -> Coord<'a> {
+        let exterior = self
+            .polygon
+            .exterior()
+            .expect("an empty Triangle has no coordinates");
+        // Safety:
+        // A non-empty Triangle was validated to have 4 coordinates
+        unsafe { exterior.coord_unchecked(n) }
+    }
+}
+
+impl<'a> TriangleTrait for Triangle<'a> {
+    type CoordType<'b>
+        = Coord<'a>
+    where
+        Self: 'b;
+
+    fn first(&self) -> Self::CoordType<'_> {
+        self.nth_coord(0)
+    }
+
+    fn second(&self) -> Self::CoordType<'_> {
+        self.nth_coord(1)
+    }
+
+    fn third(&self) -> Self::CoordType<'_> {
+        self.nth_coord(2)
+    }
+}
+
+impl<'a> TriangleTrait for &Triangle<'a> {
+    type CoordType<'b>
+        = Coord<'a>
+    where
+        Self: 'b;
+
+    fn first(&self) -> Self::CoordType<'_> {
+        self.nth_coord(0)
+    }
+
+    fn second(&self) -> Self::CoordType<'_> {
+        self.nth_coord(1)
+    }
+
+    fn third(&self) -> Self::CoordType<'_> {
+        self.nth_coord(2)
+    }
+}
+
+impl<'a> GeometryTrait for Triangle<'a> {
+    type T = f64;
+    type PointType<'b>
+        = UnimplementedPoint<f64>
+    where
+        Self: 'b;
+    type LineStringType<'b>
+        = UnimplementedLineString<f64>
+    where
+        Self: 'b;
+    type PolygonType<'b>
+        = UnimplementedPolygon<f64>
+    where
+        Self: 'b;
+    type MultiPointType<'b>
+        = UnimplementedMultiPoint<f64>
+    where
+        Self: 'b;
+    type MultiLineStringType<'b>
+        = UnimplementedMultiLineString<f64>
+    where
+        Self: 'b;
+    type MultiPolygonType<'b>
+        = UnimplementedMultiPolygon<f64>
+    where
+        Self: 'b;
+    type GeometryCollectionType<'b>
+        = UnimplementedGeometryCollection<f64>
+    where
+        Self: 'b;
+    type RectType<'b>
+        = UnimplementedRect<f64>
+    where
+        Self: 'b;
+    type LineType<'b>
+        = UnimplementedLine<f64>
+    where
+        Self: 'b;
+    type TriangleType<'b>
+        = Triangle<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.dimension().into()
+    }
+
+    fn as_type(
+        &self,
+    ) -> geo_traits::GeometryType<
+        '_,
+        Self::PointType<'_>,
+        Self::LineStringType<'_>,
+        Self::PolygonType<'_>,
+        Self::MultiPointType<'_>,
+        Self::MultiLineStringType<'_>,
+        Self::MultiPolygonType<'_>,
+        Self::GeometryCollectionType<'_>,
+        Self::RectType<'_>,
+        Self::TriangleType<'_>,
+        Self::LineType<'_>,
+    > {
+        geo_traits::GeometryType::Triangle(self)
+    }
+}
+
+impl<'a> GeometryTrait for &Triangle<'a> {
+    type T = f64;
+    type PointType<'b>
+        = UnimplementedPoint<f64>
+    where
+        Self: 'b;
+    type LineStringType<'b>
+        = UnimplementedLineString<f64>
+    where
+        Self: 'b;
+    type PolygonType<'b>
+        = UnimplementedPolygon<f64>
+    where
+        Self: 'b;
+    type MultiPointType<'b>
+        = UnimplementedMultiPoint<f64>
+    where
+        Self: 'b;
+    type MultiLineStringType<'b>
+        = UnimplementedMultiLineString<f64>
+    where
+        Self: 'b;
+    type MultiPolygonType<'b>
+        = UnimplementedMultiPolygon<f64>
+    where
+        Self: 'b;
+    type GeometryCollectionType<'b>
+        = UnimplementedGeometryCollection<f64>
+    where
+        Self: 'b;
+    type RectType<'b>
+        = UnimplementedRect<f64>
+    where
+        Self: 'b;
+    type LineType<'b>
+        = UnimplementedLine<f64>
+    where
+        Self: 'b;
+    type TriangleType<'b>
+        = Triangle<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.dimension().into()
+    }
+
+    fn as_type(
+        &self,
+    ) -> geo_traits::GeometryType<
+        '_,
+        Self::PointType<'_>,
+        Self::LineStringType<'_>,
+        Self::PolygonType<'_>,
+        Self::MultiPointType<'_>,
+        Self::MultiLineStringType<'_>,
+        Self::MultiPolygonType<'_>,
+        Self::GeometryCollectionType<'_>,
+        Self::RectType<'_>,
+        Self::TriangleType<'_>,
+        Self::LineType<'_>,
+    > {
+        geo_traits::GeometryType::Triangle(self)
+    }
+}
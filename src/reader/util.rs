@@ -1,36 +1,276 @@
-use byteorder::{BigEndian, LittleEndian};
+use crate::common::{WkbGeometryCode, WkbType};
+use crate::error::{PathSegment, WkbError, WkbPath, WkbResult};
+use crate::reader::{GeometryType, HEADER_BYTES};
+use crate::Endianness;
 
-use crate::{common::WkbGeometryCode, error::WkbError, Endianness};
-use std::io::{Cursor, Error};
+/// The byte order of the target platform, whose values can be decoded without swapping bytes
+pub(crate) const NATIVE_ENDIANNESS: Endianness = if cfg!(target_endian = "big") {
+    Endianness::BigEndian
+} else {
+    Endianness::LittleEndian
+};
 
-pub(crate) trait ReadBytesExt: byteorder::ReadBytesExt {
-    fn read_u32(&mut self, byte_order: Endianness) -> Result<u32, Error> {
-        match byte_order {
-            Endianness::BigEndian => byteorder::ReadBytesExt::read_u32::<BigEndian>(self),
-            Endianness::LittleEndian => byteorder::ReadBytesExt::read_u32::<LittleEndian>(self),
-        }
+/// Copy `N` bytes at `offset` into an array, panicking if `buf` is too short
+#[inline]
+fn bytes_at<const N: usize>(buf: &[u8], offset: usize) -> [u8; N] {
+    let mut bytes = [0; N];
+    bytes.copy_from_slice(&buf[offset..offset + N]);
+    bytes
+}
+
+/// Decode the f64 at `offset` bytes into `buf`, panicking if `buf` is too short
+#[inline]
+pub(crate) fn read_f64_at(buf: &[u8], offset: usize, byte_order: Endianness) -> f64 {
+    let bytes = bytes_at(buf, offset);
+    match byte_order {
+        Endianness::LittleEndian => f64::from_le_bytes(bytes),
+        Endianness::BigEndian => f64::from_be_bytes(bytes),
     }
+}
 
-    fn read_f64(&mut self, byte_order: Endianness) -> Result<f64, Error> {
-        match byte_order {
-            Endianness::BigEndian => byteorder::ReadBytesExt::read_f64::<BigEndian>(self),
-            Endianness::LittleEndian => byteorder::ReadBytesExt::read_f64::<LittleEndian>(self),
+/// Decode the back-to-back f64s in `src` into `out`, which holds `src.len() / 8` values
+#[inline]
+pub(crate) fn read_f64s(src: &[u8], byte_order: Endianness, out: &mut [f64]) {
+    debug_assert_eq!(src.len(), out.len() * 8);
+    let values = src.chunks_exact(8).zip(out);
+    if byte_order == NATIVE_ENDIANNESS {
+        // Without any byte swapping, this compiles down to a copy
+        for (bytes, value) in values {
+            *value = f64::from_ne_bytes(bytes_at(bytes, 0));
+        }
+    } else {
+        for (bytes, value) in values {
+            *value = f64::from_bits(u64::from_ne_bytes(bytes_at(bytes, 0)).swap_bytes());
         }
     }
 }
 
-/// All types that implement `Read` get methods defined in `ReadBytesExt`
-/// for free.
-impl<R: std::io::Read + ?Sized> ReadBytesExt for R {}
+/// The smallest possible size of a geometry: a header followed by a 4-byte count of zero elements
+pub(crate) const MIN_GEOMETRY_BYTES: u64 = HEADER_BYTES + 4;
+
+/// Read the byte order and geometry type from the header at the start of `buf`
+pub(crate) fn read_header(buf: &[u8]) -> WkbResult<(Endianness, WkbType)> {
+    let byte = *buf.first().ok_or_else(|| unexpected_eof(0, 1, buf.len()))?;
+    let byte_order = Endianness::try_from(byte).map_err(|_| invalid_byte_order(byte))?;
+    let code = read_u32_at(buf, 1, byte_order)?;
+    let wkb_type = WkbGeometryCode::new(code)
+        .get_type()
+        .map_err(|e| e.nested(1, None))?;
+    Ok((byte_order, wkb_type))
+}
 
-/// Return `true` if this WKB item is EWKB and has an embedded SRID
-pub(crate) fn has_srid(buf: &[u8], byte_order: Endianness) -> Result<bool, WkbError> {
-    // Read geometry code to see if an SRID exists.
-    let mut reader = Cursor::new(buf);
+/// Check the header of a PolyhedralSurface or TIN patch, which its surface parses with the
+/// surface's own byte order and dimension
+pub(crate) fn check_patch_header(
+    patch: &[u8],
+    expected: WkbType,
+    byte_order: Endianness,
+    description: &'static str,
+) -> WkbResult<()> {
+    let (patch_byte_order, wkb_type) = read_header(patch)?;
+    if wkb_type.geometry_type() != expected.geometry_type() {
+        return Err(unexpected_type(description, wkb_type.geometry_type(), 0));
+    }
+    if wkb_type != expected {
+        return Err(invalid_geometry(
+            "a patch must have the dimension of its surface",
+        ));
+    }
+    if patch_byte_order != byte_order {
+        return Err(invalid_geometry(
+            "a patch must have the byte order of its surface",
+        ));
+    }
+    Ok(())
+}
 
+/// Return the embedded SRID if this WKB item is EWKB and has the SRID flag set
+pub(crate) fn read_srid(buf: &[u8], byte_order: Endianness) -> WkbResult<Option<u32>> {
     // Skip 1-byte byte order that we already know
-    reader.set_position(1);
+    let geometry_code = WkbGeometryCode::new(read_u32_at(buf, 1, byte_order)?);
+    if geometry_code.has_srid() {
+        // The SRID immediately follows the geometry code
+        Ok(Some(read_u32_at(buf, HEADER_BYTES, byte_order)?))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Read a u32 at `offset` bytes into `buf`
+pub(crate) fn read_u32_at(buf: &[u8], offset: u64, byte_order: Endianness) -> WkbResult<u32> {
+    check_len(buf, offset, 4)?;
+    let bytes = bytes_at(buf, offset as usize);
+    Ok(match byte_order {
+        Endianness::LittleEndian => u32::from_le_bytes(bytes),
+        Endianness::BigEndian => u32::from_be_bytes(bytes),
+    })
+}
+
+/// Read a u32 at `offset` bytes into a buffer that has already been validated, panicking if
+/// `buf` is too short
+#[inline]
+pub(crate) fn read_validated_u32_at(buf: &[u8], offset: usize, byte_order: Endianness) -> u32 {
+    let bytes = bytes_at(buf, offset);
+    match byte_order {
+        Endianness::LittleEndian => u32::from_le_bytes(bytes),
+        Endianness::BigEndian => u32::from_be_bytes(bytes),
+    }
+}
+
+/// Read an element count at `offset` bytes into `buf`, where every element takes up at least
+/// `min_element_size` bytes after the count.
+///
+/// The count is checked against the remaining buffer so that a corrupt count cannot cause a huge
+/// allocation.
+pub(crate) fn read_count_at(
+    buf: &[u8],
+    offset: u64,
+    byte_order: Endianness,
+    min_element_size: u64,
+) -> WkbResult<usize> {
+    let count = read_u32_at(buf, offset, byte_order)?;
+    let remaining = (buf.len() as u64).saturating_sub(offset + 4);
+    let max = remaining / min_element_size;
+    if count as u64 > max {
+        return Err(count_overflow(count, max, offset));
+    }
+    count
+        .try_into()
+        .map_err(|_| count_overflow(count, usize::MAX as u64, offset))
+}
+
+/// Check that `buf` holds `needed` bytes from `offset`
+#[inline]
+pub(crate) fn check_len(buf: &[u8], offset: u64, needed: u64) -> WkbResult<()> {
+    if offset + needed > buf.len() as u64 {
+        Err(unexpected_eof(offset, needed, buf.len()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Check that `buf` holds `count` elements of `element_size` bytes each from `offset`, reporting
+/// the first incomplete element otherwise
+#[inline]
+pub(crate) fn check_elements_len(
+    buf: &[u8],
+    offset: u64,
+    count: u32,
+    element_size: u64,
+    segment: fn(usize) -> PathSegment,
+) -> WkbResult<usize> {
+    if offset + count as u64 * element_size > buf.len() as u64 {
+        let first_incomplete = (buf.len() as u64).saturating_sub(offset) / element_size;
+        let element_offset = offset + first_incomplete * element_size;
+        return Err(unexpected_eof(element_offset, element_size, buf.len())
+            .nested(0, Some(segment(first_incomplete as usize))));
+    }
+    // The elements fit in the buffer, so their count fits in a usize
+    Ok(count as usize)
+}
+
+/// The offset of `inner` within `outer`, where `inner` is a subslice of `outer`
+pub(crate) fn offset_within(outer: &[u8], inner: &[u8]) -> u64 {
+    debug_assert!(outer.as_ptr_range().contains(&inner.as_ptr()) || inner.is_empty());
+    (inner.as_ptr() as usize - outer.as_ptr() as usize) as u64
+}
+
+/// A vector that keeps up to `N` items inline, and only allocates when it holds more
+#[derive(Debug, Clone)]
+pub(crate) enum InlineVec<T, const N: usize> {
+    Inline { len: usize, items: [T; N] },
+    Heap(Vec<T>),
+}
+
+impl<T: Copy, const N: usize> InlineVec<T, N> {
+    /// An empty vector with room for `capacity` items, whose unused inline slots hold `filler`
+    pub(crate) fn with_capacity(capacity: usize, filler: T) -> Self {
+        if capacity <= N {
+            Self::Inline {
+                len: 0,
+                items: [filler; N],
+            }
+        } else {
+            Self::Heap(Vec::with_capacity(capacity))
+        }
+    }
+
+    pub(crate) fn push(&mut self, item: T) {
+        match self {
+            Self::Inline { len, items } if *len < N => {
+                items[*len] = item;
+                *len += 1;
+            }
+            Self::Inline { items, .. } => {
+                let mut heap = Vec::with_capacity(N * 2);
+                heap.extend_from_slice(items);
+                heap.push(item);
+                *self = Self::Heap(heap);
+            }
+            Self::Heap(heap) => heap.push(item),
+        }
+    }
+}
+
+impl<T, const N: usize> std::ops::Deref for InlineVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        match self {
+            Self::Inline { len, items } => &items[..*len],
+            Self::Heap(heap) => heap,
+        }
+    }
+}
+
+#[cold]
+pub(crate) fn unexpected_eof(offset: u64, needed: u64, buf_len: usize) -> WkbError {
+    WkbError::UnexpectedEof {
+        offset,
+        needed,
+        available: (buf_len as u64).saturating_sub(offset),
+        path: WkbPath::default(),
+    }
+}
+
+#[cold]
+fn invalid_byte_order(byte: u8) -> WkbError {
+    WkbError::InvalidByteOrder {
+        byte,
+        offset: 0,
+        path: WkbPath::default(),
+    }
+}
 
-    let geometry_code = WkbGeometryCode::new(reader.read_u32(byte_order)?);
-    Ok(geometry_code.has_srid())
+#[cold]
+fn count_overflow(count: u32, max: u64, offset: u64) -> WkbError {
+    WkbError::CountOverflow {
+        count,
+        max,
+        offset,
+        path: WkbPath::default(),
+    }
+}
+
+#[cold]
+pub(crate) fn unexpected_type(
+    expected: &'static str,
+    found: GeometryType,
+    offset: u64,
+) -> WkbError {
+    WkbError::UnexpectedType {
+        expected,
+        found,
+        offset,
+        path: WkbPath::default(),
+    }
+}
+
+#[cold]
+pub(crate) fn invalid_geometry(reason: &'static str) -> WkbError {
+    WkbError::InvalidGeometry {
+        reason,
+        offset: 0,
+        path: WkbPath::default(),
+    }
 }
@@ -0,0 +1,674 @@
+//! Read and write geometries in the SpatiaLite BLOB format.
+//!
+//! SpatiaLite stores geometries in its own binary format, which is close to WKB but not
+//! compatible with it. A blob starts with a header holding the byte order, SRID, and minimum
+//! bounding rectangle (MBR), followed by the geometry class and body and a final end marker.
+//! Members of collections are prefixed by an entity marker instead of a WKB header, and the
+//! compressed LineString and Polygon classes store intermediate vertices as `f32` offsets from
+//! the previous vertex. See
+//! [the SpatiaLite documentation](https://www.gaia-gis.it/gaia-sins/BLOB-Geometry.html).
+//!
+//! Blobs are read by converting them to WKB in a caller-provided buffer, so that the result can
+//! be accessed through the same [`Wkb`] type and `geo_traits` implementations as any other WKB.
+//!
+//! ```
+//! use geo_types::line_string;
+//! use wkb::spatialite::{read_spatialite, write_spatialite, SpatialiteWriteOptions};
+//!
+//! let line_string = line_string![(x: 0., y: 1.), (x: 2., y: 3.)];
+//! let mut blob = Vec::new();
+//! write_spatialite(&mut blob, &line_string, 4326, &SpatialiteWriteOptions::default()).unwrap();
+//!
+//! let mut buf = Vec::new();
+//! let geometry = read_spatialite(&blob, &mut buf).unwrap();
+//! assert_eq!(geometry.srid(), 4326);
+//! assert_eq!(geometry.mbr().max_y, 3.);
+//! ```
+
+use std::io::Write;
+use std::marker::PhantomData;
+
+use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
+};
+
+use crate::bounds::Bounds;
+use crate::common::{Dimension, WkbGeometryCode, WkbType};
+use crate::error::{PathSegment, WkbError, WkbPath, WkbResult};
+use crate::reader::util::{check_elements_len, check_len, read_count_at};
+use crate::reader::Wkb;
+use crate::Endianness;
+
+const START: u8 = 0x00;
+const MBR_END: u8 = 0x7C;
+const ENTITY: u8 = 0x69;
+const END: u8 = 0xFE;
+
+/// The size of the header before the geometry class: start marker, byte order, SRID, MBR, and
+/// MBR end marker
+const HEADER_BYTES: usize = 39;
+
+/// The offset added to the LineString and Polygon classes for their compressed variants
+const COMPRESSED: u32 = 1_000_000;
+
+/// The minimum bounding rectangle stored in a SpatiaLite blob header
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpatialiteMbr {
+    /// The minimum x value
+    pub min_x: f64,
+    /// The minimum y value
+    pub min_y: f64,
+    /// The maximum x value
+    pub max_x: f64,
+    /// The maximum y value
+    pub max_y: f64,
+}
+
+/// A SpatiaLite geometry that has been converted to WKB
+#[derive(Debug, Clone)]
+pub struct SpatialiteGeometry<'a> {
+    byte_order: Endianness,
+    srid: i32,
+    mbr: SpatialiteMbr,
+    wkb: Wkb<'a>,
+}
+
+impl<'a> SpatialiteGeometry<'a> {
+    /// The byte order of the blob, which is also used for the converted WKB
+    pub fn byte_order(&self) -> Endianness {
+        self.byte_order
+    }
+
+    /// The SRID stored in the blob header
+    pub fn srid(&self) -> i32 {
+        self.srid
+    }
+
+    /// The minimum bounding rectangle stored in the blob header
+    pub fn mbr(&self) -> &SpatialiteMbr {
+        &self.mbr
+    }
+
+    /// The geometry, converted to WKB
+    pub fn wkb(&self) -> &Wkb<'a> {
+        &self.wkb
+    }
+
+    /// Consume this geometry, returning the geometry converted to WKB
+    pub fn into_wkb(self) -> Wkb<'a> {
+        self.wkb
+    }
+}
+
+/// Read a SpatiaLite blob by converting it to WKB in `buf`.
+///
+/// The previous contents of `buf` are replaced. The converted WKB uses ISO geometry codes and the
+/// byte order of the blob. Errors are reported with offsets into `blob`.
+pub fn read_spatialite<'a>(blob: &[u8], buf: &'a mut Vec<u8>) -> WkbResult<SpatialiteGeometry<'a>> {
+    check_len(blob, 0, HEADER_BYTES as u64)?;
+    if blob[0] != START {
+        return Err(invalid_header("missing start marker", 0));
+    }
+    let byte_order = match blob[1] {
+        0x00 => Endianness::BigEndian,
+        0x01 => Endianness::LittleEndian,
+        _ => return Err(invalid_header("invalid byte order", 1)),
+    };
+    if blob[HEADER_BYTES - 1] != MBR_END {
+        return Err(invalid_header(
+            "missing MBR end marker",
+            HEADER_BYTES as u64 - 1,
+        ));
+    }
+
+    buf.clear();
+    let (srid, mbr) = match byte_order {
+        Endianness::BigEndian => Transcoder::<BigEndian>::new(blob, buf, byte_order).run()?,
+        Endianness::LittleEndian => Transcoder::<LittleEndian>::new(blob, buf, byte_order).run()?,
+    };
+
+    Ok(SpatialiteGeometry {
+        byte_order,
+        srid,
+        mbr,
+        wkb: Wkb::try_new(buf)?,
+    })
+}
+
+/// Converts the body of a SpatiaLite blob to WKB
+struct Transcoder<'b, B> {
+    blob: &'b [u8],
+    out: &'b mut Vec<u8>,
+    byte_order: Endianness,
+    /// The offset of the next unread byte of `blob`
+    pos: usize,
+    _byte_order: PhantomData<B>,
+}
+
+impl<'b, B: ByteOrder> Transcoder<'b, B> {
+    fn new(blob: &'b [u8], out: &'b mut Vec<u8>, byte_order: Endianness) -> Self {
+        Self {
+            blob,
+            out,
+            byte_order,
+            pos: HEADER_BYTES,
+            _byte_order: PhantomData,
+        }
+    }
+
+    /// Convert the whole blob, returning the SRID and MBR from its header
+    fn run(mut self) -> WkbResult<(i32, SpatialiteMbr)> {
+        let srid = B::read_i32(&self.blob[2..6]);
+        let value = |i: usize| B::read_f64(&self.blob[6 + i * 8..]);
+        let mbr = SpatialiteMbr {
+            min_x: value(0),
+            min_y: value(1),
+            max_x: value(2),
+            max_y: value(3),
+        };
+
+        self.geometry(None)?;
+
+        let end = self.take(1)?[0];
+        if end != END {
+            return Err(invalid_header("missing end marker", self.pos as u64 - 1));
+        }
+        if self.pos < self.blob.len() {
+            return Err(WkbError::TrailingBytes {
+                offset: self.pos as u64,
+                count: (self.blob.len() - self.pos) as u64,
+            });
+        }
+        Ok((srid, mbr))
+    }
+
+    fn take(&mut self, len: usize) -> WkbResult<&'b [u8]> {
+        check_len(self.blob, self.pos as u64, len as u64)?;
+        let bytes = &self.blob[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn read_f32(&mut self) -> WkbResult<f32> {
+        Ok(B::read_f32(self.take(4)?))
+    }
+
+    fn read_f64(&mut self) -> WkbResult<f64> {
+        Ok(B::read_f64(self.take(8)?))
+    }
+
+    /// Read an element count, copying it to the output
+    fn count(&mut self, min_element_size: u64) -> WkbResult<usize> {
+        let count = read_count_at(
+            self.blob,
+            self.pos as u64,
+            self.byte_order,
+            min_element_size,
+        )?;
+        let bytes = self.take(4)?;
+        self.out.extend_from_slice(bytes);
+        Ok(count)
+    }
+
+    /// Convert a geometry starting at its class, which must have the type and dimension of
+    /// `parent`'s members when it is a collection member
+    fn geometry(&mut self, parent: Option<WkbType>) -> WkbResult<()> {
+        let offset = self.pos;
+        let class = B::read_u32(self.take(4)?);
+        let (wkb_type, compressed) = decode_class(class, offset)?;
+        if let Some(parent) = parent {
+            if !is_member_type(parent, wkb_type) {
+                return Err(invalid_geometry("unexpected entity class", offset));
+            }
+        }
+
+        self.out.push(self.byte_order.into());
+        self.out
+            .write_u32::<B>(wkb_type.as_geometry_code().into())?;
+        // As in WKB, members of typed multi geometries are not named in error paths
+        let segment = match parent {
+            Some(WkbType::GeometryCollection(_)) | None => {
+                Some(PathSegment::Geometry(wkb_type.geometry_type()))
+            }
+            Some(_) => None,
+        };
+        self.body(wkb_type, compressed)
+            .map_err(|e| e.nested(0, segment))
+    }
+
+    fn body(&mut self, wkb_type: WkbType, compressed: bool) -> WkbResult<()> {
+        let dim = wkb_type.dimension();
+        match wkb_type {
+            WkbType::Point(_) => self.coords(1, dim, false),
+            WkbType::LineString(_) => {
+                let count = self.count(min_coord_size(dim, compressed))?;
+                self.coords(count, dim, compressed)
+            }
+            WkbType::Polygon(_) => {
+                let num_rings = self.count(4)?;
+                for i in 0..num_rings {
+                    self.ring(dim, compressed)
+                        .map_err(|e| e.nested(0, Some(PathSegment::Field("rings", i))))?;
+                }
+                Ok(())
+            }
+            _ => {
+                // An entity marker, class, and at least the count of an empty LineString or
+                // Polygon
+                let num_entities = self.count(1 + 4 + 4)?;
+                for i in 0..num_entities {
+                    self.entity(wkb_type)
+                        .map_err(|e| e.nested(0, Some(PathSegment::Member(i))))?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn ring(&mut self, dim: Dimension, compressed: bool) -> WkbResult<()> {
+        let count = self.count(min_coord_size(dim, compressed))?;
+        self.coords(count, dim, compressed)
+    }
+
+    fn entity(&mut self, parent: WkbType) -> WkbResult<()> {
+        if self.take(1)?[0] != ENTITY {
+            return Err(invalid_header("missing entity marker", self.pos as u64 - 1));
+        }
+        self.geometry(Some(parent))
+    }
+
+    fn coords(&mut self, count: usize, dim: Dimension, compressed: bool) -> WkbResult<()> {
+        let coord_size = dim.size() * 8;
+        if !compressed {
+            check_elements_len(
+                self.blob,
+                self.pos as u64,
+                count as u32,
+                coord_size as u64,
+                |i| PathSegment::Field("coords", i),
+            )?;
+            let coords = self.take(count * coord_size)?;
+            self.out.extend_from_slice(coords);
+            return Ok(());
+        }
+
+        // The first and last vertices are stored in full. Intermediate vertices store x, y,
+        // and z as f32 offsets from the previous vertex, and m in full.
+        let has_m = dim.has_m();
+        let num_offsets = if has_m { dim.size() - 1 } else { dim.size() };
+        let mut last = [0.; 4];
+        self.out.reserve(count * coord_size);
+        for i in 0..count {
+            if i == 0 || i == count - 1 {
+                for value in &mut last[..dim.size()] {
+                    *value = self.read_f64()?;
+                }
+            } else {
+                for value in &mut last[..num_offsets] {
+                    *value += self.read_f32()? as f64;
+                }
+                if has_m {
+                    last[dim.size() - 1] = self.read_f64()?;
+                }
+            }
+            for value in &last[..dim.size()] {
+                self.out.write_f64::<B>(*value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Decode a geometry class into its WKB type and whether it is compressed
+fn decode_class(class: u32, offset: usize) -> WkbResult<(WkbType, bool)> {
+    let (code, compressed) = if class > COMPRESSED {
+        (class - COMPRESSED, true)
+    } else {
+        (class, false)
+    };
+    let unknown = || WkbError::UnknownTypeCode {
+        code: class,
+        offset: offset as u64,
+        path: WkbPath::default(),
+    };
+
+    // Only the seven basic geometry types in the four ISO dimensions are valid classes
+    if code >= 4000 || !(1..=7).contains(&(code % 1000)) {
+        return Err(unknown());
+    }
+    let wkb_type = WkbGeometryCode::new(code)
+        .get_type()
+        .map_err(|_| unknown())?;
+    if compressed && !matches!(wkb_type, WkbType::LineString(_) | WkbType::Polygon(_)) {
+        return Err(unknown());
+    }
+    Ok((wkb_type, compressed))
+}
+
+/// Whether `member` may appear as an entity of the collection `parent`
+fn is_member_type(parent: WkbType, member: WkbType) -> bool {
+    if parent.dimension() != member.dimension() {
+        return false;
+    }
+    matches!(
+        (parent, member),
+        (WkbType::MultiPoint(_), WkbType::Point(_))
+            | (WkbType::MultiLineString(_), WkbType::LineString(_))
+            | (WkbType::MultiPolygon(_), WkbType::Polygon(_))
+            | (
+                WkbType::GeometryCollection(_),
+                WkbType::Point(_) | WkbType::LineString(_) | WkbType::Polygon(_)
+            )
+    )
+}
+
+/// The smallest number of bytes a coordinate can take up
+fn min_coord_size(dim: Dimension, compressed: bool) -> u64 {
+    if !compressed {
+        return dim.size() as u64 * 8;
+    }
+    match dim {
+        Dimension::Xy => 8,
+        Dimension::Xyz => 12,
+        Dimension::Xym => 16,
+        Dimension::Xyzm => 20,
+    }
+}
+
+/// Options for writing geometries as SpatiaLite blobs
+#[derive(Debug, Clone, Default)]
+pub struct SpatialiteWriteOptions {
+    /// The byte order to use when writing the blob
+    pub endianness: Endianness,
+
+    /// Whether to write LineStrings and Polygons with the compressed geometry classes.
+    ///
+    /// Compressed geometries store intermediate vertices as `f32` offsets from the previous
+    /// vertex, which loses precision.
+    pub compressed: bool,
+}
+
+/// Write a geometry as a SpatiaLite blob.
+///
+/// SpatiaLite collections may only contain Points, LineStrings, and Polygons, so multi
+/// geometries and nested collections inside a GeometryCollection are flattened into their
+/// members. Lines, Rects, and Triangles are written as LineStrings and Polygons. Empty Points are
+/// written with NaN coordinates, and the MBR of a geometry without coordinates is all zeros.
+pub fn write_spatialite(
+    writer: &mut impl Write,
+    geom: &impl GeometryTrait<T = f64>,
+    srid: i32,
+    options: &SpatialiteWriteOptions,
+) -> WkbResult<()> {
+    let dim = geom.dim().try_into()?;
+    writer.write_u8(START)?;
+    writer.write_u8(options.endianness.into())?;
+    match options.endianness {
+        Endianness::BigEndian => {
+            BlobWriter::<_, BigEndian>::new(writer, dim, options.compressed).write(geom, srid)
+        }
+        Endianness::LittleEndian => {
+            BlobWriter::<_, LittleEndian>::new(writer, dim, options.compressed).write(geom, srid)
+        }
+    }
+}
+
+struct BlobWriter<'w, W, B> {
+    writer: &'w mut W,
+    dim: Dimension,
+    compressed: bool,
+    _byte_order: PhantomData<B>,
+}
+
+impl<'w, W: Write, B: ByteOrder> BlobWriter<'w, W, B> {
+    fn new(writer: &'w mut W, dim: Dimension, compressed: bool) -> Self {
+        Self {
+            writer,
+            dim,
+            compressed,
+            _byte_order: PhantomData,
+        }
+    }
+
+    fn write(&mut self, geom: &impl GeometryTrait<T = f64>, srid: i32) -> WkbResult<()> {
+        self.writer.write_i32::<B>(srid)?;
+        let bounds = Bounds::of_geometry(geom);
+        let ((min_x, max_x), (min_y, max_y)) = if bounds.is_empty() {
+            ((0., 0.), (0., 0.))
+        } else {
+            (bounds.x(), bounds.y())
+        };
+        for value in [min_x, min_y, max_x, max_y] {
+            self.writer.write_f64::<B>(value)?;
+        }
+        self.writer.write_u8(MBR_END)?;
+
+        self.geometry(geom)?;
+        self.writer.write_u8(END)?;
+        Ok(())
+    }
+
+    fn class(&mut self, wkb_type: WkbType) -> WkbResult<()> {
+        let mut class = u32::from(wkb_type);
+        if self.compressed && matches!(wkb_type, WkbType::LineString(_) | WkbType::Polygon(_)) {
+            class += COMPRESSED;
+        }
+        self.writer.write_u32::<B>(class)?;
+        Ok(())
+    }
+
+    fn geometry(&mut self, geom: &impl GeometryTrait<T = f64>) -> WkbResult<()> {
+        use GeometryType::*;
+        let dim = self.dim;
+        match geom.as_type() {
+            MultiPoint(mp) => {
+                self.class(WkbType::MultiPoint(dim))?;
+                self.writer.write_u32::<B>(mp.num_points().try_into()?)?;
+                for point in mp.points() {
+                    self.writer.write_u8(ENTITY)?;
+                    self.point(&point)?;
+                }
+                Ok(())
+            }
+            MultiLineString(ml) => {
+                self.class(WkbType::MultiLineString(dim))?;
+                self.writer
+                    .write_u32::<B>(ml.num_line_strings().try_into()?)?;
+                for line_string in ml.line_strings() {
+                    self.writer.write_u8(ENTITY)?;
+                    self.line_string(&line_string)?;
+                }
+                Ok(())
+            }
+            MultiPolygon(mp) => {
+                self.class(WkbType::MultiPolygon(dim))?;
+                self.writer.write_u32::<B>(mp.num_polygons().try_into()?)?;
+                for polygon in mp.polygons() {
+                    self.writer.write_u8(ENTITY)?;
+                    self.polygon(&polygon)?;
+                }
+                Ok(())
+            }
+            GeometryCollection(gc) => {
+                self.class(WkbType::GeometryCollection(dim))?;
+                let num_entities = gc.geometries().map(|g| count_entities(&g)).sum::<usize>();
+                self.writer.write_u32::<B>(num_entities.try_into()?)?;
+                gc.geometries().try_for_each(|g| self.entities(&g))
+            }
+            _ => self.simple(geom),
+        }
+    }
+
+    /// Write the members of a geometry as entities of a GeometryCollection
+    fn entities(&mut self, geom: &impl GeometryTrait<T = f64>) -> WkbResult<()> {
+        use GeometryType::*;
+        match geom.as_type() {
+            MultiPoint(mp) => mp.points().try_for_each(|point| {
+                self.writer.write_u8(ENTITY)?;
+                self.point(&point)
+            }),
+            MultiLineString(ml) => ml.line_strings().try_for_each(|line_string| {
+                self.writer.write_u8(ENTITY)?;
+                self.line_string(&line_string)
+            }),
+            MultiPolygon(mp) => mp.polygons().try_for_each(|polygon| {
+                self.writer.write_u8(ENTITY)?;
+                self.polygon(&polygon)
+            }),
+            GeometryCollection(gc) => gc.geometries().try_for_each(|g| self.entities(&g)),
+            _ => {
+                self.writer.write_u8(ENTITY)?;
+                self.simple(geom)
+            }
+        }
+    }
+
+    /// Write a geometry that maps to a Point, LineString, or Polygon
+    fn simple(&mut self, geom: &impl GeometryTrait<T = f64>) -> WkbResult<()> {
+        use GeometryType::*;
+        let dim = self.dim;
+        match geom.as_type() {
+            Point(p) => self.point(p),
+            LineString(ls) => self.line_string(ls),
+            Polygon(p) => self.polygon(p),
+            Line(line) => {
+                self.class(WkbType::LineString(dim))?;
+                self.coords(2, line.coords().into_iter())
+            }
+            Rect(rect) => {
+                self.class(WkbType::Polygon(dim))?;
+                self.writer.write_u32::<B>(1)?;
+                let (min, max) = (rect.min(), rect.max());
+                let ring = [
+                    (min.x(), min.y()),
+                    (min.x(), max.y()),
+                    (max.x(), max.y()),
+                    (max.x(), min.y()),
+                    (min.x(), min.y()),
+                ];
+                self.coords(ring.len(), ring.into_iter())
+            }
+            Triangle(tri) => {
+                self.class(WkbType::Polygon(dim))?;
+                self.writer.write_u32::<B>(1)?;
+                let ring = tri.coords().into_iter().chain(std::iter::once(tri.first()));
+                self.coords(4, ring)
+            }
+            MultiPoint(_) | MultiLineString(_) | MultiPolygon(_) | GeometryCollection(_) => {
+                unreachable!("collections are handled by the caller")
+            }
+        }
+    }
+
+    fn point(&mut self, point: &impl PointTrait<T = f64>) -> WkbResult<()> {
+        self.class(WkbType::Point(self.dim))?;
+        match point.coord() {
+            Some(coord) => self.coord(&coord),
+            None => {
+                for _ in 0..self.dim.size() {
+                    self.writer.write_f64::<B>(f64::NAN)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn line_string(&mut self, line_string: &impl LineStringTrait<T = f64>) -> WkbResult<()> {
+        self.class(WkbType::LineString(self.dim))?;
+        self.coords(line_string.num_coords(), line_string.coords())
+    }
+
+    fn polygon(&mut self, polygon: &impl PolygonTrait<T = f64>) -> WkbResult<()> {
+        self.class(WkbType::Polygon(self.dim))?;
+        let num_rings = polygon
+            .exterior()
+            .map_or(0, |_| 1 + polygon.num_interiors());
+        self.writer.write_u32::<B>(num_rings.try_into()?)?;
+        if let Some(exterior) = polygon.exterior() {
+            self.coords(exterior.num_coords(), exterior.coords())?;
+        }
+        for interior in polygon.interiors() {
+            self.coords(interior.num_coords(), interior.coords())?;
+        }
+        Ok(())
+    }
+
+    fn coord(&mut self, coord: &impl CoordTrait<T = f64>) -> WkbResult<()> {
+        for i in 0..self.dim.size() {
+            self.writer
+                .write_f64::<B>(coord.nth(i).unwrap_or(f64::NAN))?;
+        }
+        Ok(())
+    }
+
+    /// Write a count followed by `count` coordinates
+    fn coords<C: CoordTrait<T = f64>>(
+        &mut self,
+        count: usize,
+        coords: impl Iterator<Item = C>,
+    ) -> WkbResult<()> {
+        self.writer.write_u32::<B>(count.try_into()?)?;
+        if !self.compressed {
+            return coords.into_iter().try_for_each(|coord| self.coord(&coord));
+        }
+
+        // Offsets are taken from the previous vertex as written by SpatiaLite itself
+        let size = self.dim.size();
+        let has_m = self.dim.has_m();
+        let num_offsets = if has_m { size - 1 } else { size };
+        let mut last = [0.; 4];
+        for (i, coord) in coords.enumerate() {
+            let mut values = [0.; 4];
+            for (j, value) in values[..size].iter_mut().enumerate() {
+                *value = coord.nth(j).unwrap_or(f64::NAN);
+            }
+            if i == 0 || i == count - 1 {
+                for value in &values[..size] {
+                    self.writer.write_f64::<B>(*value)?;
+                }
+            } else {
+                for j in 0..num_offsets {
+                    self.writer.write_f32::<B>((values[j] - last[j]) as f32)?;
+                }
+                if has_m {
+                    self.writer.write_f64::<B>(values[size - 1])?;
+                }
+            }
+            last = values;
+        }
+        Ok(())
+    }
+}
+
+/// The number of entities a geometry is flattened into inside a GeometryCollection
+fn count_entities(geom: &impl GeometryTrait<T = f64>) -> usize {
+    use GeometryType::*;
+    match geom.as_type() {
+        MultiPoint(mp) => mp.num_points(),
+        MultiLineString(ml) => ml.num_line_strings(),
+        MultiPolygon(mp) => mp.num_polygons(),
+        GeometryCollection(gc) => gc.geometries().map(|g| count_entities(&g)).sum(),
+        _ => 1,
+    }
+}
+
+#[cold]
+fn invalid_header(reason: &'static str, offset: u64) -> WkbError {
+    WkbError::InvalidHeader {
+        format: "SpatiaLite",
+        reason,
+        offset,
+    }
+}
+
+#[cold]
+fn invalid_geometry(reason: &'static str, offset: usize) -> WkbError {
+    WkbError::InvalidGeometry {
+        reason,
+        offset: offset as u64,
+        path: WkbPath::default(),
+    }
+}
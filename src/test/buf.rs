@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::{mpsc, Arc};
+
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_types::Geometry;
+
+use crate::error::WkbError;
+use crate::reader::{GeometryType, ReadLimit, ReadOptions, WkbBuf};
+use crate::writer::write_geometry;
+
+use super::data::*;
+
+fn to_wkb(geom: &Geometry) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_geometry(&mut buf, geom, &Default::default()).unwrap();
+    buf
+}
+
+#[test]
+fn containers() {
+    let geom = Geometry::Polygon(polygon_2d_with_interior());
+    let bytes = to_wkb(&geom);
+
+    let from_vec = WkbBuf::try_new(bytes.clone()).unwrap();
+    assert_eq!(from_vec.to_geometry(), geom);
+    assert_eq!(from_vec.as_bytes(), bytes.as_slice());
+
+    let from_box = WkbBuf::try_new(bytes.clone().into_boxed_slice()).unwrap();
+    assert_eq!(from_box.to_geometry(), geom);
+    assert_eq!(from_box.into_inner(), bytes.clone().into_boxed_slice());
+
+    let from_arc = WkbBuf::try_new(Arc::<[u8]>::from(bytes.clone())).unwrap();
+    let mut by_reference = Vec::new();
+    write_geometry(&mut by_reference, &&from_arc, &Default::default()).unwrap();
+    assert_eq!(by_reference, bytes);
+
+    let from_rc = WkbBuf::try_new(Rc::<[u8]>::from(bytes.clone())).unwrap();
+    assert_eq!(from_rc.as_wkb().geometry_type(), GeometryType::Polygon);
+
+    #[cfg(feature = "bytes")]
+    {
+        let from_bytes = WkbBuf::try_new(bytes::Bytes::from(bytes)).unwrap();
+        assert_eq!(from_bytes.to_geometry(), geom);
+    }
+}
+
+#[test]
+fn stored_and_sent() {
+    let geometries = [
+        Geometry::Point(point_2d()),
+        Geometry::MultiLineString(multi_line_string_2d()),
+        Geometry::GeometryCollection(geometry_collection_2d()),
+    ];
+
+    let (sender, receiver) = mpsc::channel();
+    let expected = geometries.clone();
+    std::thread::spawn(move || {
+        for geom in &expected {
+            sender.send(WkbBuf::try_new(to_wkb(geom)).unwrap()).unwrap();
+        }
+    });
+
+    let cache: HashMap<usize, WkbBuf> = receiver.iter().enumerate().collect();
+    for (i, geom) in geometries.iter().enumerate() {
+        assert_eq!(&cache[&i].to_geometry(), geom);
+    }
+
+    // A clone parses its own copy of the bytes
+    let clone = cache[&2].clone();
+    drop(cache);
+    assert_eq!(clone.to_geometry(), geometries[2]);
+}
+
+#[test]
+fn invalid_bytes() {
+    let mut bytes = to_wkb(&Geometry::LineString(linestring_2d()));
+    bytes.truncate(bytes.len() - 1);
+    assert!(matches!(
+        WkbBuf::try_new(bytes).unwrap_err(),
+        WkbError::UnexpectedEof { .. }
+    ));
+
+    let bytes = to_wkb(&Geometry::GeometryCollection(geometry_collection_2d()));
+    let options = ReadOptions {
+        max_depth: 1,
+        ..Default::default()
+    };
+    assert!(matches!(
+        WkbBuf::try_new_with_options(bytes, &options).unwrap_err(),
+        WkbError::LimitExceeded {
+            limit: ReadLimit::Depth,
+            ..
+        }
+    ));
+}
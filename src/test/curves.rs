@@ -0,0 +1,155 @@
+use geo_traits::{CoordTrait, GeometryCollectionTrait, GeometryTrait, LineStringTrait};
+
+use crate::reader::{read_wkb, Dimension, GeometryType};
+use crate::writer::{wkb_size, write_circular_string, write_wkb, WriteOptions};
+use crate::Endianness;
+
+use super::data::*;
+
+fn circular_string() -> Vec<u8> {
+    wkb_point_sequence(8, 2, &[0., 0., 1., 1., 2., 0.])
+}
+
+fn compound_curve() -> Vec<u8> {
+    wkb_container(
+        9,
+        &[
+            circular_string(),
+            wkb_point_sequence(2, 2, &[2., 0., 1., -1., 0., 0.]),
+        ],
+    )
+}
+
+fn curve_polygon() -> Vec<u8> {
+    let interior = wkb_point_sequence(8, 2, &[0.5, 0., 1., 0.5, 1.5, 0., 1., -0.5, 0.5, 0.]);
+    wkb_container(10, &[compound_curve(), interior])
+}
+
+#[test]
+fn read_circular_string() {
+    let buf = circular_string();
+    let wkb = read_wkb(&buf).unwrap();
+    assert_eq!(wkb.geometry_type(), GeometryType::CircularString);
+    assert_eq!(wkb.dimension(), Dimension::Xy);
+    assert_eq!(wkb.buf(), buf.as_slice());
+
+    let cs = wkb.as_circular_string().unwrap();
+    assert_eq!(cs.num_coords(), 3);
+    assert_eq!(cs.coords_slice().len(), 3 * 16);
+    assert_eq!(cs.coord(1).unwrap().x(), 1.);
+    assert_eq!(cs.coord(1).unwrap().y(), 1.);
+
+    // Exposed through geo-traits as a LineString of control points
+    let geo_traits::GeometryType::LineString(ls) = wkb.as_type() else {
+        panic!("expected a LineString");
+    };
+    assert_eq!(ls.num_coords(), 3);
+}
+
+#[test]
+fn read_circular_string_z() {
+    // ISO and EWKB encodings of a CircularString Z
+    for code in [1008, 8 | 0x80000000] {
+        let mut buf = wkb_header(code);
+        buf.extend_from_slice(&1u32.to_le_bytes());
+        for val in [1.0f64, 2.0, 3.0] {
+            buf.extend_from_slice(&val.to_le_bytes());
+        }
+        let wkb = read_wkb(&buf).unwrap();
+        assert_eq!(wkb.geometry_type(), GeometryType::CircularString);
+        assert_eq!(wkb.dimension(), Dimension::Xyz);
+    }
+}
+
+#[test]
+fn read_compound_curve() {
+    let buf = compound_curve();
+    let wkb = read_wkb(&buf).unwrap();
+    assert_eq!(wkb.geometry_type(), GeometryType::CompoundCurve);
+    assert_eq!(wkb.buf().len(), buf.len());
+
+    let cc = wkb.as_compound_curve().unwrap();
+    assert_eq!(cc.num_segments(), 2);
+    let segment_types = cc.segments().map(|s| s.geometry_type()).collect::<Vec<_>>();
+    assert_eq!(
+        segment_types,
+        vec![GeometryType::CircularString, GeometryType::LineString]
+    );
+    assert!(cc.segment(2).is_none());
+
+    let geo_traits::GeometryType::GeometryCollection(gc) = wkb.as_type() else {
+        panic!("expected a GeometryCollection");
+    };
+    assert_eq!(gc.num_geometries(), 2);
+}
+
+#[test]
+fn read_curve_polygon() {
+    let buf = curve_polygon();
+    let wkb = read_wkb(&buf).unwrap();
+    assert_eq!(wkb.geometry_type(), GeometryType::CurvePolygon);
+
+    let cp = wkb.as_curve_polygon().unwrap();
+    assert_eq!(cp.num_rings(), 2);
+    assert_eq!(cp.num_interiors(), 1);
+    assert_eq!(
+        cp.exterior().unwrap().geometry_type(),
+        GeometryType::CompoundCurve
+    );
+    assert_eq!(
+        cp.interior(0).unwrap().geometry_type(),
+        GeometryType::CircularString
+    );
+    assert!(cp.interior(1).is_none());
+}
+
+#[test]
+fn read_compound_curve_invalid_segment() {
+    let mut point = wkb_header(1);
+    point.extend_from_slice(&1.0f64.to_le_bytes());
+    point.extend_from_slice(&2.0f64.to_le_bytes());
+    let buf = wkb_container(9, &[point]);
+    assert!(read_wkb(&buf).is_err());
+}
+
+#[test]
+fn read_curve_polygon_invalid_ring() {
+    let buf = wkb_container(10, &[wkb_container(10, &[])]);
+    assert!(read_wkb(&buf).is_err());
+}
+
+#[test]
+fn write_circular_string_matches_input() {
+    let buf = circular_string();
+    let wkb = read_wkb(&buf).unwrap();
+    let cs = wkb.as_circular_string().unwrap();
+
+    let mut out = Vec::new();
+    write_circular_string(&mut out, cs, &WriteOptions::default()).unwrap();
+    assert_eq!(out, buf);
+}
+
+#[test]
+fn round_trip_curves() {
+    let nested = wkb_container(7, &[circular_string(), curve_polygon()]);
+    for buf in [circular_string(), compound_curve(), curve_polygon(), nested] {
+        let wkb = read_wkb(&buf).unwrap();
+        assert_eq!(wkb_size(&wkb, &WriteOptions::default()), buf.len());
+
+        let mut big_endian = Vec::new();
+        let options = WriteOptions {
+            endianness: Endianness::BigEndian,
+            ..Default::default()
+        };
+        write_wkb(&mut big_endian, &wkb, &options).unwrap();
+        assert_eq!(big_endian.len(), buf.len());
+        assert_eq!(big_endian[0], 0x00);
+
+        let retour = read_wkb(&big_endian).unwrap();
+        assert_eq!(retour.geometry_type(), wkb.geometry_type());
+
+        let mut little_endian = Vec::new();
+        write_wkb(&mut little_endian, &retour, &WriteOptions::default()).unwrap();
+        assert_eq!(little_endian, buf);
+    }
+}
@@ -110,3 +110,58 @@ pub(super) fn geometry_collection_2d() -> GeometryCollection {
         Geometry::MultiPolygon(multi_polygon_2d()),
     ])
 }
+
+/// One geometry of each basic type
+pub(super) fn geometries_2d() -> Vec<Geometry> {
+    vec![
+        Geometry::Point(point_2d()),
+        Geometry::LineString(linestring_2d()),
+        Geometry::Polygon(polygon_2d_with_interior()),
+        Geometry::MultiPoint(multi_point_2d()),
+        Geometry::MultiLineString(multi_line_string_2d()),
+        Geometry::MultiPolygon(multi_polygon_2d()),
+        Geometry::GeometryCollection(geometry_collection_2d()),
+    ]
+}
+
+/// A little-endian WKB header with the geometry code `code`
+pub(super) fn wkb_header(code: u32) -> Vec<u8> {
+    let mut buf = vec![0x01];
+    buf.extend_from_slice(&code.to_le_bytes());
+    buf
+}
+
+/// A little-endian WKB Point, or any other geometry that is a single coordinate
+pub(super) fn wkb_point(code: u32, coords: &[f64]) -> Vec<u8> {
+    let mut buf = wkb_header(code);
+    for val in coords {
+        buf.extend_from_slice(&val.to_le_bytes());
+    }
+    buf
+}
+
+/// A little-endian WKB LineString, or any other geometry that is a sequence of coordinates with
+/// `dim_size` values each
+pub(super) fn wkb_point_sequence(code: u32, dim_size: usize, coords: &[f64]) -> Vec<u8> {
+    let mut buf = wkb_header(code);
+    buf.extend_from_slice(&((coords.len() / dim_size) as u32).to_le_bytes());
+    for val in coords {
+        buf.extend_from_slice(&val.to_le_bytes());
+    }
+    buf
+}
+
+/// A little-endian WKB multi geometry, collection, or other geometry made up of WKB members
+pub(super) fn wkb_container(code: u32, members: &[Vec<u8>]) -> Vec<u8> {
+    let mut buf = wkb_header(code);
+    buf.extend_from_slice(&(members.len() as u32).to_le_bytes());
+    for member in members {
+        buf.extend_from_slice(member);
+    }
+    buf
+}
+
+/// A little-endian ISO WKB LineString with XYZM coordinates
+pub(super) fn line_string_zm_wkb(coords: &[[f64; 4]]) -> Vec<u8> {
+    wkb_point_sequence(3002, 4, coords.as_flattened())
+}
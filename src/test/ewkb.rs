@@ -1,8 +1,11 @@
 use geo_traits::to_geo::ToGeoGeometry;
+use geo_traits::{GeometryCollectionTrait, GeometryTrait};
 use geo_types::Geometry;
 use geos::WKBWriter;
 
-use crate::reader::read_wkb;
+use crate::reader::{read_wkb, GeometryType};
+use crate::writer::{geometry_wkb_size, write_geometry, write_wkb, WkbFlavor, WriteOptions};
+use crate::Endianness;
 
 use super::data::*;
 
@@ -17,6 +20,7 @@ fn read_point_srid() {
     let buf: Vec<u8> = wkb_writer.write_wkb(&geos_geom).unwrap().into();
 
     let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(1));
     assert_eq!(Geometry::Point(orig), retour.to_geometry());
 }
 
@@ -32,6 +36,7 @@ fn read_line_string_srid() {
     let buf: Vec<u8> = wkb_writer.write_wkb(&geos_geom).unwrap().into();
 
     let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(1));
     assert_eq!(Geometry::LineString(orig.clone()), retour.to_geometry());
 }
 
@@ -47,6 +52,7 @@ fn read_polygon() {
     let buf: Vec<u8> = wkb_writer.write_wkb(&geos_geom).unwrap().into();
 
     let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(1));
     assert_eq!(Geometry::Polygon(orig.clone()), retour.to_geometry());
 }
 
@@ -62,6 +68,7 @@ fn read_polygon_with_interior() {
     let buf: Vec<u8> = wkb_writer.write_wkb(&geos_geom).unwrap().into();
 
     let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(1));
     assert_eq!(Geometry::Polygon(orig.clone()), retour.to_geometry());
 }
 
@@ -77,6 +84,7 @@ fn read_multi_point() {
     let buf: Vec<u8> = wkb_writer.write_wkb(&geos_geom).unwrap().into();
 
     let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(1));
     assert_eq!(Geometry::MultiPoint(orig.clone()), retour.to_geometry());
 }
 
@@ -92,6 +100,7 @@ fn read_multi_line_string() {
     let buf: Vec<u8> = wkb_writer.write_wkb(&geos_geom).unwrap().into();
 
     let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(1));
     assert_eq!(
         Geometry::MultiLineString(orig.clone()),
         retour.to_geometry()
@@ -110,6 +119,7 @@ fn read_multi_polygon() {
     let buf: Vec<u8> = wkb_writer.write_wkb(&geos_geom).unwrap().into();
 
     let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(1));
     assert_eq!(Geometry::MultiPolygon(orig.clone()), retour.to_geometry());
 }
 
@@ -144,6 +154,7 @@ fn read_geometry_collection() {
     let buf: Vec<u8> = wkb_writer.write_wkb(&geos_geom).unwrap().into();
 
     let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(1));
     assert_eq!(
         Geometry::GeometryCollection(orig.clone()),
         retour.to_geometry()
@@ -208,3 +219,148 @@ fn ewkb_multi_polygon_buf_with_trailing_data() {
 fn ewkb_geometry_collection_buf_with_trailing_data() {
     test_ewkb_buf_with_trailing_data(&Geometry::GeometryCollection(geometry_collection_2d()));
 }
+
+#[test]
+fn read_nested_srid() {
+    // A GeometryCollection with SRID 4326 containing a Point with SRID 3857 and a Point without
+    // an SRID.
+    let mut buf = vec![0x01];
+    buf.extend_from_slice(&(7u32 | 0x20000000).to_le_bytes());
+    buf.extend_from_slice(&4326u32.to_le_bytes());
+    buf.extend_from_slice(&2u32.to_le_bytes());
+
+    buf.push(0x01);
+    buf.extend_from_slice(&(1u32 | 0x20000000).to_le_bytes());
+    buf.extend_from_slice(&3857u32.to_le_bytes());
+    buf.extend_from_slice(&1.0f64.to_le_bytes());
+    buf.extend_from_slice(&2.0f64.to_le_bytes());
+
+    buf.push(0x01);
+    buf.extend_from_slice(&1u32.to_le_bytes());
+    buf.extend_from_slice(&3.0f64.to_le_bytes());
+    buf.extend_from_slice(&4.0f64.to_le_bytes());
+
+    let wkb = read_wkb(&buf).unwrap();
+    assert_eq!(wkb.geometry_type(), GeometryType::GeometryCollection);
+    assert_eq!(wkb.srid(), Some(4326));
+
+    let geo_traits::GeometryType::GeometryCollection(gc) = wkb.as_type() else {
+        panic!("expected a GeometryCollection");
+    };
+    let srids = gc.geometries().map(|g| g.srid()).collect::<Vec<_>>();
+    assert_eq!(srids, vec![Some(3857), None]);
+}
+
+fn ewkb_options(endianness: Endianness, srid: Option<u32>) -> WriteOptions {
+    WriteOptions {
+        endianness,
+        flavor: WkbFlavor::Ewkb,
+        srid,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn write_polygon_srid_matches_geos() {
+    let orig = polygon_2d_with_interior();
+
+    let mut geos_geom: geos::Geometry = (&orig).try_into().unwrap();
+    geos_geom.set_srid(4326);
+
+    let mut wkb_writer = WKBWriter::new().unwrap();
+    wkb_writer.set_include_SRID(true);
+    let expected: Vec<u8> = wkb_writer.write_wkb(&geos_geom).unwrap().into();
+
+    let mut buf = Vec::new();
+    write_geometry(
+        &mut buf,
+        &Geometry::Polygon(orig),
+        &ewkb_options(Endianness::LittleEndian, Some(4326)),
+    )
+    .unwrap();
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn round_trip_geometry_collection_srid() {
+    let orig = Geometry::GeometryCollection(geometry_collection_2d());
+
+    for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+        let options = ewkb_options(endianness, Some(4326));
+        let mut buf = Vec::new();
+        write_geometry(&mut buf, &orig, &options).unwrap();
+        assert_eq!(geometry_wkb_size(&orig, &options), buf.len());
+
+        let retour = read_wkb(&buf).unwrap();
+        assert_eq!(retour.srid(), Some(4326));
+        assert_eq!(orig, retour.to_geometry());
+
+        // The SRID is only written on the outer geometry
+        let geo_traits::GeometryType::GeometryCollection(gc) = retour.as_type() else {
+            panic!("expected a GeometryCollection");
+        };
+        assert!(gc.geometries().all(|g| g.srid().is_none()));
+    }
+}
+
+#[test]
+fn write_ewkb_without_srid() {
+    let orig = Geometry::Point(point_2d());
+    let mut buf = Vec::new();
+    write_geometry(
+        &mut buf,
+        &orig,
+        &ewkb_options(Endianness::LittleEndian, None),
+    )
+    .unwrap();
+    assert_eq!(buf.len(), 21);
+    assert_eq!(buf[1..5], 1u32.to_le_bytes());
+
+    let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), None);
+    assert_eq!(orig, retour.to_geometry());
+}
+
+#[test]
+fn write_ewkb_dimension_flags() {
+    let iso = line_string_zm_wkb(&[[1., 2., 3., 4.]]);
+    let wkb = read_wkb(&iso).unwrap();
+
+    let mut buf = Vec::new();
+    write_geometry(
+        &mut buf,
+        &wkb,
+        &ewkb_options(Endianness::BigEndian, Some(3857)),
+    )
+    .unwrap();
+    assert_eq!(buf[0], 0x00);
+    assert_eq!(
+        buf[1..5],
+        (2u32 | 0x80000000 | 0x40000000 | 0x20000000).to_be_bytes()
+    );
+    assert_eq!(buf[5..9], 3857u32.to_be_bytes());
+
+    let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(3857));
+    assert_eq!(retour.dimension(), crate::reader::Dimension::Xyzm);
+    assert_eq!(retour.buf().len(), iso.len() + 4);
+}
+
+#[test]
+fn write_iso_with_srid_errors() {
+    let options = WriteOptions {
+        srid: Some(4326),
+        ..Default::default()
+    };
+    // Nothing is written, not even the byte order
+    for geom in geometries_2d() {
+        let mut buf = Vec::new();
+        assert!(write_geometry(&mut buf, &geom, &options).is_err());
+        assert!(buf.is_empty());
+    }
+
+    let wkb = wkb_container(7, &[wkb_point(1, &[1., 2.])]);
+    let mut buf = Vec::new();
+    assert!(write_wkb(&mut buf, &read_wkb(&wkb).unwrap(), &options).is_err());
+    assert!(buf.is_empty());
+}
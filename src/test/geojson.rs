@@ -0,0 +1,381 @@
+use geo_traits::GeometryTrait;
+use geo_types::{coord, Geometry, GeometryCollection, Line, Rect, Triangle};
+
+use crate::error::WkbError;
+use crate::geojson::{geojson_to_wkb, read_geojson, write_geojson, GeoJsonWriteOptions};
+use crate::reader::{read_wkb, ReadLimit};
+use crate::wkt::read_wkt;
+use crate::writer::{write_geometry, write_wkb, WriteOptions};
+use crate::Endianness;
+
+use super::data::*;
+
+fn to_geojson(geom: &impl GeometryTrait<T = f64>, options: &GeoJsonWriteOptions) -> String {
+    let mut json = Vec::new();
+    write_geojson(&mut json, geom, options).unwrap();
+    String::from_utf8(json).unwrap()
+}
+
+fn wkt_to_geojson(wkt: &str, options: &GeoJsonWriteOptions) -> String {
+    let mut buf = Vec::new();
+    let geometry = read_wkt(wkt, &mut buf).unwrap();
+    to_geojson(&geometry, options)
+}
+
+#[test]
+fn basic_geometries() {
+    let options = GeoJsonWriteOptions::default();
+    assert_eq!(
+        to_geojson(&point_2d(), &options),
+        r#"{"type":"Point","coordinates":[0,1]}"#
+    );
+    assert_eq!(
+        to_geojson(&linestring_2d(), &options),
+        r#"{"type":"LineString","coordinates":[[0,1],[1,2]]}"#
+    );
+    assert_eq!(
+        to_geojson(&polygon_2d_with_interior(), &options),
+        concat!(
+            r#"{"type":"Polygon","coordinates":["#,
+            "[[-111,45],[-111,41],[-104,41],[-104,45],[-111,45]],",
+            "[[-110,44],[-110,42],[-105,42],[-105,44],[-110,44]]]}"
+        )
+    );
+    assert_eq!(
+        to_geojson(&multi_point_2d(), &options),
+        r#"{"type":"MultiPoint","coordinates":[[0,1],[1,2]]}"#
+    );
+    assert_eq!(
+        to_geojson(&multi_line_string_2d(), &options),
+        concat!(
+            r#"{"type":"MultiLineString","coordinates":["#,
+            "[[-111,45],[-111,41],[-104,41],[-104,45]],",
+            "[[-110,44],[-110,42],[-105,42],[-105,44]]]}"
+        )
+    );
+}
+
+#[test]
+fn parsed_wkb_matches_geo_types() {
+    let options = GeoJsonWriteOptions {
+        include_bbox: true,
+        ..Default::default()
+    };
+    let geometries = [
+        Geometry::Point(point_2d()),
+        Geometry::LineString(linestring_2d()),
+        Geometry::Polygon(polygon_2d_with_interior()),
+        Geometry::MultiPoint(multi_point_2d()),
+        Geometry::MultiLineString(multi_line_string_2d()),
+        Geometry::MultiPolygon(multi_polygon_2d()),
+        Geometry::GeometryCollection(geometry_collection_2d()),
+    ];
+    for geom in geometries {
+        let mut buf = Vec::new();
+        write_geometry(&mut buf, &geom, &Default::default()).unwrap();
+        let wkb = read_wkb(&buf).unwrap();
+        assert_eq!(to_geojson(&wkb, &options), to_geojson(&geom, &options));
+    }
+}
+
+#[test]
+fn geometry_collections() {
+    let collection = GeometryCollection::new_from(vec![
+        Geometry::Point(point_2d()),
+        Geometry::GeometryCollection(GeometryCollection::new_from(vec![Geometry::LineString(
+            linestring_2d(),
+        )])),
+    ]);
+    let options = GeoJsonWriteOptions {
+        include_bbox: true,
+        ..Default::default()
+    };
+    // Only the outermost geometry has a bbox
+    assert_eq!(
+        to_geojson(&collection, &options),
+        concat!(
+            r#"{"type":"GeometryCollection","bbox":[0,1,1,2],"geometries":["#,
+            r#"{"type":"Point","coordinates":[0,1]},"#,
+            r#"{"type":"GeometryCollection","geometries":["#,
+            r#"{"type":"LineString","coordinates":[[0,1],[1,2]]}]}]}"#
+        )
+    );
+}
+
+#[test]
+fn z_and_m() {
+    let options = GeoJsonWriteOptions {
+        include_bbox: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        wkt_to_geojson("LINESTRING ZM (1 2 3 4,5 6 7 8)", &options),
+        r#"{"type":"LineString","bbox":[1,2,3,5,6,7],"coordinates":[[1,2,3],[5,6,7]]}"#
+    );
+    assert_eq!(
+        wkt_to_geojson("POINT M (1 2 3)", &options),
+        r#"{"type":"Point","bbox":[1,2,1,2],"coordinates":[1,2]}"#
+    );
+
+    let options = GeoJsonWriteOptions {
+        include_z: false,
+        include_bbox: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        wkt_to_geojson("POINT Z (1 2 3)", &options),
+        r#"{"type":"Point","bbox":[1,2,1,2],"coordinates":[1,2]}"#
+    );
+}
+
+#[test]
+fn precision() {
+    let options = GeoJsonWriteOptions {
+        precision: Some(3),
+        ..Default::default()
+    };
+    assert_eq!(
+        wkt_to_geojson("POINT(1.23456 -0.0001)", &options),
+        r#"{"type":"Point","coordinates":[1.235,0]}"#
+    );
+    assert_eq!(
+        wkt_to_geojson("POINT(1e-7 3e20)", &Default::default()),
+        r#"{"type":"Point","coordinates":[0.0000001,300000000000000000000]}"#
+    );
+}
+
+#[test]
+fn empty_geometries() {
+    let options = GeoJsonWriteOptions {
+        include_bbox: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        wkt_to_geojson("POINT EMPTY", &options),
+        r#"{"type":"Point","coordinates":[]}"#
+    );
+    assert_eq!(
+        wkt_to_geojson("POLYGON EMPTY", &options),
+        r#"{"type":"Polygon","coordinates":[]}"#
+    );
+    assert_eq!(
+        wkt_to_geojson("MULTIPOINT(EMPTY,(1 2))", &options),
+        r#"{"type":"MultiPoint","bbox":[1,2,1,2],"coordinates":[[1,2]]}"#
+    );
+    assert_eq!(
+        wkt_to_geojson("GEOMETRYCOLLECTION EMPTY", &options),
+        r#"{"type":"GeometryCollection","geometries":[]}"#
+    );
+}
+
+#[test]
+fn other_geometry_types() {
+    let options = GeoJsonWriteOptions::default();
+    let line = Line::new(coord! { x: 0., y: 1. }, coord! { x: 2., y: 3. });
+    assert_eq!(
+        to_geojson(&line, &options),
+        r#"{"type":"LineString","coordinates":[[0,1],[2,3]]}"#
+    );
+
+    // Rects are wound counterclockwise
+    let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 2., y: 1. });
+    assert_eq!(
+        to_geojson(&rect, &options),
+        r#"{"type":"Polygon","coordinates":[[[0,0],[2,0],[2,1],[0,1],[0,0]]]}"#
+    );
+
+    let triangle = Triangle::new(
+        coord! { x: 0., y: 0. },
+        coord! { x: 1., y: 0. },
+        coord! { x: 0., y: 1. },
+    );
+    assert_eq!(
+        to_geojson(&triangle, &options),
+        r#"{"type":"Polygon","coordinates":[[[0,0],[1,0],[0,1],[0,0]]]}"#
+    );
+
+    assert_eq!(
+        wkt_to_geojson("TIN Z (((0 0 1,1 0 1,0 1 1,0 0 1)))", &options),
+        r#"{"type":"MultiPolygon","coordinates":[[[[0,0,1],[1,0,1],[0,1,1],[0,0,1]]]]}"#
+    );
+}
+
+#[test]
+fn non_finite_coordinates() {
+    let buf = wkb_point_sequence(2, 2, &[0., 0., 1., f64::NAN]);
+    let geometry = read_wkb(&buf).unwrap();
+    let mut json = Vec::new();
+    let err = write_geojson(&mut json, &geometry, &Default::default()).unwrap_err();
+    assert!(matches!(err, WkbError::General(_)));
+}
+
+fn geojson_to_wkt(json: &str) -> String {
+    let mut buf = Vec::new();
+    read_geojson(json, &mut buf).unwrap().to_string()
+}
+
+fn parse_error(json: &str) -> (u64, &'static str) {
+    let mut buf = Vec::new();
+    match read_geojson(json, &mut buf).unwrap_err() {
+        WkbError::InvalidGeoJson { offset, reason } => (offset, reason),
+        err => panic!("unexpected error: {err}"),
+    }
+}
+
+#[test]
+fn parse_round_trip() {
+    let options = GeoJsonWriteOptions::default();
+    let geometries = [
+        Geometry::Point(point_2d()),
+        Geometry::LineString(linestring_2d()),
+        Geometry::Polygon(polygon_2d_with_interior()),
+        Geometry::MultiPoint(multi_point_2d()),
+        Geometry::MultiLineString(multi_line_string_2d()),
+        Geometry::MultiPolygon(multi_polygon_2d()),
+        Geometry::GeometryCollection(geometry_collection_2d()),
+    ];
+    for geom in geometries {
+        let json = to_geojson(&geom, &options);
+        let mut buf = Vec::new();
+        let wkb = read_geojson(&json, &mut buf).unwrap();
+        assert_eq!(to_geojson(&wkb, &options), json);
+
+        // The output matches the writer byte for byte
+        let mut expected = Vec::new();
+        write_geometry(&mut expected, &geom, &Default::default()).unwrap();
+        assert_eq!(buf, expected);
+    }
+}
+
+#[test]
+fn parse_member_order_and_foreign_members() {
+    let expected = "POLYGON((0 0,1 0,0 1,0 0))";
+    assert_eq!(
+        geojson_to_wkt(r#"{"type":"Polygon","coordinates":[[[0,0],[1,0],[0,1],[0,0]]]}"#),
+        expected
+    );
+    assert_eq!(
+        geojson_to_wkt(
+            r#" { "coordinates" : [ [ [0, 0], [1, 0], [0, 1], [0, 0] ] ],
+                  "bbox": [0, 0, 1, 1], "id": "a\"b", "properties": {"x": [null, true]},
+                  "type": "Polygon" } "#
+        ),
+        expected
+    );
+    assert_eq!(
+        geojson_to_wkt(
+            r#"{"geometries":[{"coordinates":[1,2],"type":"Point"}],"type":"GeometryCollection"}"#
+        ),
+        "GEOMETRYCOLLECTION(POINT(1 2))"
+    );
+    assert_eq!(
+        geojson_to_wkt(r#"{"type":"\u0050oint","coordinates":[1e2,-2.5E-1]}"#),
+        "POINT(100 -0.25)"
+    );
+}
+
+#[test]
+fn parse_3d_and_nested_collections() {
+    assert_eq!(
+        geojson_to_wkt(r#"{"type":"MultiPoint","coordinates":[[1,2,3],[4,5,6]]}"#),
+        "MULTIPOINT Z ((1 2 3),(4 5 6))"
+    );
+    // Elements after the third are ignored
+    assert_eq!(
+        geojson_to_wkt(r#"{"type":"LineString","coordinates":[[1,2,3,4],[5,6,7,8]]}"#),
+        "LINESTRING Z (1 2 3,5 6 7)"
+    );
+    assert_eq!(
+        geojson_to_wkt(concat!(
+            r#"{"type":"GeometryCollection","geometries":["#,
+            r#"{"type":"GeometryCollection","geometries":["#,
+            r#"{"type":"Point","coordinates":[1,2,3]}]},"#,
+            r#"{"type":"LineString","coordinates":[[0,0,0],[1,1,1]]}]}"#
+        )),
+        "GEOMETRYCOLLECTION Z (GEOMETRYCOLLECTION Z (POINT Z (1 2 3)),LINESTRING Z (0 0 0,1 1 1))"
+    );
+    assert_eq!(
+        geojson_to_wkt(r#"{"type":"GeometryCollection","geometries":[]}"#),
+        "GEOMETRYCOLLECTION EMPTY"
+    );
+    assert_eq!(
+        geojson_to_wkt(r#"{"type":"Point","coordinates":[]}"#),
+        "POINT EMPTY"
+    );
+}
+
+#[test]
+fn parse_options() {
+    let json =
+        r#"{"type":"GeometryCollection","geometries":[{"type":"Point","coordinates":[1,2,3]}]}"#;
+    let options = WriteOptions {
+        endianness: Endianness::BigEndian,
+        ..Default::default()
+    };
+    let mut buf = Vec::new();
+    geojson_to_wkb(json, &mut buf, &options).unwrap();
+
+    let mut expected = Vec::new();
+    let wkb = read_wkt("GEOMETRYCOLLECTION Z (POINT Z (1 2 3))", &mut expected).unwrap();
+    let mut big_endian = Vec::new();
+    write_wkb(&mut big_endian, &wkb, &options).unwrap();
+    assert_eq!(buf, big_endian);
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(
+        parse_error(r#"{"type":"Circle","coordinates":[]}"#),
+        (8, "unknown geometry type")
+    );
+    assert_eq!(
+        parse_error(r#"{"coordinates":[1,2]}"#),
+        (0, "missing type member")
+    );
+    assert_eq!(
+        parse_error(r#"{"type":"Point"}"#),
+        (0, "missing coordinates member")
+    );
+    assert_eq!(
+        parse_error(r#"{"type":"LineString","coordinates":[[1,2,3],[4,5]]}"#),
+        (
+            44,
+            "position has a different dimension than the first position"
+        )
+    );
+    assert_eq!(
+        parse_error(r#"{"type":"LineString","coordinates":[[1,2],[4]]}"#),
+        (42, "a position needs at least two numbers")
+    );
+    assert_eq!(
+        parse_error(r#"{"type":"Point","coordinates":[1,2]} x"#),
+        (37, "unexpected text after the geometry")
+    );
+    assert_eq!(
+        parse_error(r#"{"type":"Point","coordinates":[1,"2"]}"#),
+        (33, "expected a number")
+    );
+    assert_eq!(
+        parse_error(r#"{"type":"Point","coordinates":[1,2]"#),
+        (35, "unexpected end of JSON")
+    );
+}
+
+#[test]
+fn parse_depth_limit() {
+    let depth = 200;
+    let json = format!(
+        "{}{}",
+        r#"{"type":"GeometryCollection","geometries":["#.repeat(depth),
+        "]}".repeat(depth)
+    );
+    let mut buf = Vec::new();
+    let err = read_geojson(&json, &mut buf).unwrap_err();
+    assert!(matches!(
+        err,
+        WkbError::LimitExceeded {
+            limit: ReadLimit::Depth,
+            ..
+        }
+    ));
+}
@@ -0,0 +1,195 @@
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_types::{Geometry, GeometryCollection};
+
+use crate::error::WkbError;
+use crate::gpkg::{read_gpkg, write_gpkg, GpkgEnvelope, GpkgGeometry};
+use crate::reader::read_wkb;
+use crate::writer::{write_geometry, WkbFlavor, WriteOptions};
+use crate::Endianness;
+
+use super::data::*;
+
+#[test]
+fn round_trip_with_envelope() {
+    for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+        let geom = Geometry::Polygon(polygon_2d_with_interior());
+        let options = WriteOptions {
+            endianness,
+            ..Default::default()
+        };
+        let mut buf = Vec::new();
+        write_gpkg(&mut buf, &geom, 4326, &options).unwrap();
+
+        let gpkg = read_gpkg(&buf).unwrap();
+        assert_eq!(gpkg.version(), 0);
+        assert_eq!(gpkg.byte_order(), endianness);
+        assert_eq!(gpkg.srs_id(), 4326);
+        assert!(!gpkg.is_empty());
+        assert_eq!(
+            gpkg.envelope(),
+            Some(&GpkgEnvelope {
+                min_x: -111.,
+                max_x: -104.,
+                min_y: 41.,
+                max_y: 45.,
+                z_range: None,
+                m_range: None,
+            })
+        );
+        assert_eq!(gpkg.header_size(), 8 + 32);
+
+        let mut wkb = Vec::new();
+        write_geometry(&mut wkb, &geom, &options).unwrap();
+        assert_eq!(gpkg.wkb().buf(), wkb.as_slice());
+        assert_eq!(gpkg.into_wkb().to_geometry(), geom);
+    }
+}
+
+#[test]
+fn points_have_no_envelope() {
+    let mut buf = Vec::new();
+    write_gpkg(&mut buf, &point_2d(), 0, &Default::default()).unwrap();
+
+    let gpkg = read_gpkg(&buf).unwrap();
+    assert_eq!(gpkg.envelope(), None);
+    assert_eq!(gpkg.header_size(), 8);
+    assert_eq!(gpkg.wkb().to_geometry(), Geometry::Point(point_2d()));
+}
+
+#[test]
+fn empty_geometry_sets_flag() {
+    let geom = Geometry::GeometryCollection(GeometryCollection::<f64>::new_from(vec![]));
+    let mut buf = Vec::new();
+    write_gpkg(&mut buf, &geom, -1, &Default::default()).unwrap();
+
+    let gpkg = read_gpkg(&buf).unwrap();
+    assert!(gpkg.is_empty());
+    assert_eq!(gpkg.envelope(), None);
+    assert_eq!(gpkg.srs_id(), -1);
+}
+
+#[test]
+fn envelope_with_z_and_m() {
+    let wkb_buf = line_string_zm_wkb(&[[1., 2., 3., 4.], [-1., 5., -3., 8.]]);
+    let wkb = read_wkb(&wkb_buf).unwrap();
+    let mut buf = Vec::new();
+    write_gpkg(&mut buf, &wkb, 4326, &Default::default()).unwrap();
+
+    // Envelope contents indicator 4 in bits 1-3, little-endian in bit 0
+    assert_eq!(buf[3], 0b0000_1001);
+    let gpkg = read_gpkg(&buf).unwrap();
+    assert_eq!(
+        gpkg.envelope(),
+        Some(&GpkgEnvelope {
+            min_x: -1.,
+            max_x: 1.,
+            min_y: 2.,
+            max_y: 5.,
+            z_range: Some((-3., 3.)),
+            m_range: Some((4., 8.)),
+        })
+    );
+    assert_eq!(gpkg.header_size(), 8 + 64);
+    assert_eq!(gpkg.wkb().buf(), wkb_buf.as_slice());
+}
+
+#[test]
+fn read_each_envelope_type() {
+    let wkb = line_string_zm_wkb(&[[0., 0., 0., 0.]]);
+    for (indicator, size) in [(0, 0), (1, 32), (2, 48), (3, 48), (4, 64)] {
+        let mut buf = b"GP\x00".to_vec();
+        buf.push((indicator << 1) | 0x01);
+        buf.extend_from_slice(&27700i32.to_le_bytes());
+        for i in 0..size / 8 {
+            buf.extend_from_slice(&(i as f64).to_le_bytes());
+        }
+        buf.extend_from_slice(&wkb);
+
+        let gpkg = GpkgGeometry::try_new(&buf).unwrap();
+        assert_eq!(gpkg.srs_id(), 27700);
+        assert_eq!(gpkg.header_size(), 8 + size);
+        let envelope = gpkg.envelope();
+        assert_eq!(envelope.is_some(), indicator > 0);
+        if let Some(envelope) = envelope {
+            assert_eq!(
+                (
+                    envelope.min_x,
+                    envelope.max_x,
+                    envelope.min_y,
+                    envelope.max_y
+                ),
+                (0., 1., 2., 3.)
+            );
+            assert_eq!(envelope.z_range.is_some(), indicator == 2 || indicator == 4);
+            assert_eq!(envelope.m_range.is_some(), indicator == 3 || indicator == 4);
+        }
+        assert_eq!(gpkg.wkb().buf(), wkb.as_slice());
+    }
+}
+
+#[test]
+fn invalid_headers() {
+    let mut valid = Vec::new();
+    write_gpkg(&mut valid, &linestring_2d(), 0, &Default::default()).unwrap();
+
+    let mut buf = valid.clone();
+    buf[0] = b'X';
+    let err = read_gpkg(&buf).unwrap_err();
+    assert!(matches!(err, WkbError::InvalidHeader { offset: 0, .. }));
+
+    let mut buf = valid.clone();
+    buf[2] = 1;
+    let err = read_gpkg(&buf).unwrap_err();
+    assert!(matches!(err, WkbError::InvalidHeader { offset: 2, .. }));
+
+    let mut buf = valid.clone();
+    buf[3] = (5 << 1) | 0x01;
+    let err = read_gpkg(&buf).unwrap_err();
+    assert!(matches!(err, WkbError::InvalidHeader { offset: 3, .. }));
+
+    let mut buf = valid.clone();
+    buf[3] |= 0b0010_0000;
+    let err = read_gpkg(&buf).unwrap_err();
+    assert!(matches!(err, WkbError::NotYetImplemented(_)));
+
+    let err = read_gpkg(&valid[..20]).unwrap_err();
+    assert!(matches!(
+        err,
+        WkbError::UnexpectedEof {
+            offset: 8,
+            needed: 32,
+            available: 12,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn write_rejects_ewkb_options() {
+    let ewkb = WriteOptions {
+        flavor: WkbFlavor::Ewkb,
+        ..Default::default()
+    };
+    let srid = WriteOptions {
+        srid: Some(4326),
+        ..Default::default()
+    };
+    for options in [ewkb, srid] {
+        let mut buf = Vec::new();
+        let err = write_gpkg(&mut buf, &point_2d(), 4326, &options).unwrap_err();
+        assert!(matches!(err, WkbError::General(_)), "{err}");
+        assert!(buf.is_empty());
+    }
+}
+
+#[test]
+fn wkb_errors_are_offset_by_header() {
+    let mut buf = Vec::new();
+    write_gpkg(&mut buf, &polygon_2d(), 0, &Default::default()).unwrap();
+    let header_size = 8 + 32;
+    buf[header_size] = 0x02;
+
+    let err = read_gpkg(&buf).unwrap_err();
+    assert!(matches!(err, WkbError::InvalidByteOrder { byte: 2, .. }));
+    assert_eq!(err.offset(), Some(header_size as u64));
+}
@@ -0,0 +1,112 @@
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_types::Geometry;
+
+use crate::error::WkbError;
+use crate::hex::{decode_hex, decode_hex_into, encode_hex, HexCase, HexReader, HexWriter};
+use crate::reader::{read_wkb, WkbStreamReader};
+use crate::writer::{write_geometry, WkbFlavor, WriteOptions};
+
+use super::data::*;
+
+/// `SRID=4326;POINT(1 2)` as written by PostGIS
+const HEXEWKB_POINT: &str = "0101000020E6100000000000000000F03F0000000000000040";
+
+#[test]
+fn decode_postgis_hexewkb() {
+    let buf = decode_hex(HEXEWKB_POINT).unwrap();
+    let wkb = read_wkb(&buf).unwrap();
+    assert_eq!(wkb.srid(), Some(4326));
+    assert_eq!(
+        wkb.to_geometry(),
+        Geometry::Point(geo_types::point!(x: 1., y: 2.))
+    );
+
+    let lower = decode_hex(HEXEWKB_POINT.to_lowercase()).unwrap();
+    assert_eq!(lower, buf);
+    let prefixed = decode_hex(format!("\\x{}", HEXEWKB_POINT)).unwrap();
+    assert_eq!(prefixed, buf);
+}
+
+#[test]
+fn decode_into_reuses_buffer() {
+    let mut buf = vec![0xFF; 100];
+    decode_hex_into("0102", &mut buf).unwrap();
+    assert_eq!(buf, vec![0x01, 0x02]);
+}
+
+#[test]
+fn decode_invalid_hex() {
+    let err = decode_hex("\\x01G2").unwrap_err();
+    assert!(matches!(err, WkbError::InvalidHex { offset: 4, .. }));
+
+    let err = decode_hex("01020").unwrap_err();
+    assert!(matches!(err, WkbError::InvalidHex { offset: 5, .. }));
+
+    // The prefix is only recognized at the start
+    assert!(decode_hex("01\\x02").is_err());
+
+    assert_eq!(decode_hex("0g").unwrap_err().offset(), Some(1));
+}
+
+#[test]
+fn encode_round_trip() {
+    let buf = decode_hex(HEXEWKB_POINT).unwrap();
+    assert_eq!(encode_hex(&buf, HexCase::Upper), HEXEWKB_POINT);
+    assert_eq!(
+        encode_hex(&buf, HexCase::Lower),
+        HEXEWKB_POINT.to_lowercase()
+    );
+}
+
+#[test]
+fn write_geometry_as_hex() {
+    let geom = Geometry::GeometryCollection(geometry_collection_2d());
+    let options = WriteOptions {
+        flavor: WkbFlavor::Ewkb,
+        srid: Some(4326),
+        ..Default::default()
+    };
+
+    let mut binary = Vec::new();
+    write_geometry(&mut binary, &geom, &options).unwrap();
+
+    let mut writer = HexWriter::new(Vec::new(), HexCase::Upper);
+    write_geometry(&mut writer, &geom, &options).unwrap();
+    let hex = String::from_utf8(writer.into_inner()).unwrap();
+
+    assert_eq!(hex, encode_hex(&binary, HexCase::Upper));
+    assert_eq!(decode_hex(&hex).unwrap(), binary);
+}
+
+#[test]
+fn stream_concatenated_hex() {
+    let geometries = vec![
+        Geometry::Point(point_2d()),
+        Geometry::Polygon(polygon_2d_with_interior()),
+        Geometry::MultiLineString(multi_line_string_2d()),
+    ];
+    let mut writer = HexWriter::new(b"\\x".to_vec(), HexCase::Lower);
+    for geom in &geometries {
+        write_geometry(&mut writer, geom, &Default::default()).unwrap();
+    }
+    let hex = writer.into_inner();
+
+    let read = WkbStreamReader::new(HexReader::new(&hex))
+        .map(|wkb| read_wkb(&wkb.unwrap()).unwrap().to_geometry())
+        .collect::<Vec<_>>();
+    assert_eq!(read, geometries);
+}
+
+#[test]
+fn stream_invalid_hex() {
+    let mut hex = HEXEWKB_POINT.to_string();
+    hex.replace_range(20..21, "X");
+
+    let mut reader = WkbStreamReader::new(HexReader::new(&hex));
+    let err = reader.next().unwrap().unwrap_err();
+    let WkbError::IOError(err) = err else {
+        panic!("expected an IO error, got {err:?}");
+    };
+    let inner = err.into_inner().unwrap().downcast::<WkbError>().unwrap();
+    assert!(matches!(*inner, WkbError::InvalidHex { offset: 20, .. }));
+}
@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use crate::reader::Wkb;
+    use crate::common::{Dimension, WkbGeometryCode, WkbType};
+    use crate::error::{PathSegment, WkbError};
+    use crate::reader::{read_wkb, read_wkb_exact, GeometryType, ReadLimit, ReadOptions};
+    use crate::reader::{LazyGeometryCollection, Wkb};
 
     // --- Helper Functions ---
     fn make_wkb_header(type_id: u32, is_little_endian: bool) -> Vec<u8> {
@@ -197,4 +200,466 @@ mod tests {
         let result = Wkb::try_new(&wkb_data);
         assert!(result.is_err());
     }
+
+    // --- Geometry Type Codes ---
+    const Z_FLAG: u32 = 0x80000000;
+    const M_FLAG: u32 = 0x40000000;
+    const SRID_FLAG: u32 = 0x20000000;
+
+    /// Reference decoding of a geometry type code, written independently of the crate's decoder
+    fn expected_type(code: u32) -> Option<WkbType> {
+        let has_z = code & Z_FLAG != 0;
+        let has_m = code & M_FLAG != 0;
+        let rest = code & !(Z_FLAG | M_FLAG | SRID_FLAG);
+        let (thousands, base) = (rest / 1000, rest % 1000);
+
+        let dim = match (thousands, has_z, has_m) {
+            (0, false, false) => Dimension::Xy,
+            (0, true, false) | (1, false, false) => Dimension::Xyz,
+            (0, false, true) | (2, false, false) => Dimension::Xym,
+            (0, true, true) | (3, false, false) => Dimension::Xyzm,
+            _ => return None,
+        };
+
+        let typ = match base {
+            1 => WkbType::Point(dim),
+            2 => WkbType::LineString(dim),
+            3 => WkbType::Polygon(dim),
+            4 => WkbType::MultiPoint(dim),
+            5 => WkbType::MultiLineString(dim),
+            6 => WkbType::MultiPolygon(dim),
+            7 => WkbType::GeometryCollection(dim),
+            8 => WkbType::CircularString(dim),
+            9 => WkbType::CompoundCurve(dim),
+            10 => WkbType::CurvePolygon(dim),
+            11 => WkbType::MultiCurve(dim),
+            12 => WkbType::MultiSurface(dim),
+            15 => WkbType::PolyhedralSurface(dim),
+            16 => WkbType::Tin(dim),
+            17 => WkbType::Triangle(dim),
+            _ => return None,
+        };
+        Some(typ)
+    }
+
+    fn assert_decodes_as_expected(code: u32) {
+        let result = WkbGeometryCode::new(code).get_type();
+        match expected_type(code) {
+            Some(expected) => assert_eq!(result.unwrap(), expected, "code {code:#010x}"),
+            None => match result {
+                Err(WkbError::UnknownTypeCode { code: err_code, .. }) => {
+                    assert_eq!(err_code, code)
+                }
+                other => panic!("code {code:#010x} should be rejected, got {other:?}"),
+            },
+        }
+    }
+
+    #[test]
+    fn test_type_code_low_range_with_all_flags() {
+        // Every code below 5000 covers all base types and ISO dimension offsets, plus the first
+        // invalid offset. Each is checked with every combination of EWKB flags.
+        for flags in 0..8u32 {
+            let flag_bits = flags << 29;
+            for code in 0..5000 {
+                assert_decodes_as_expected(code | flag_bits);
+            }
+        }
+    }
+
+    #[test]
+    fn test_type_code_full_range() {
+        // A stride that is coprime to 1000 visits every base type and offset across the full
+        // u32 range.
+        let mut code = 0u32;
+        while let Some(next) = code.checked_add(65_537) {
+            assert_decodes_as_expected(code);
+            code = next;
+        }
+        assert_decodes_as_expected(u32::MAX);
+        for bit in 0..32 {
+            assert_decodes_as_expected(1 << bit);
+            assert_decodes_as_expected((1 << bit) | 1);
+        }
+    }
+
+    #[test]
+    fn test_type_code_no_longer_aliased() {
+        // These used to be decoded with `code & 0x7`
+        let rejected = [
+            0,
+            13,
+            14,
+            18,
+            1009 | Z_FLAG,
+            1001 | M_FLAG,
+            3001 | Z_FLAG,
+            4001,
+            999,
+        ];
+        for code in rejected {
+            assert!(matches!(
+                WkbGeometryCode::new(code).get_type(),
+                Err(WkbError::UnknownTypeCode { .. })
+            ));
+        }
+
+        assert_eq!(
+            WkbGeometryCode::new(15).get_type().unwrap(),
+            WkbType::PolyhedralSurface(Dimension::Xy)
+        );
+        assert_eq!(
+            WkbGeometryCode::new(1009).get_type().unwrap(),
+            WkbType::CompoundCurve(Dimension::Xyz)
+        );
+        // An SRID flag may be combined with ISO dimension offsets
+        assert_eq!(
+            WkbGeometryCode::new(1001 | SRID_FLAG).get_type().unwrap(),
+            WkbType::Point(Dimension::Xyz)
+        );
+    }
+
+    #[test]
+    fn test_wkb_unknown_type_code() {
+        let mut wkb_data = make_wkb_header(1 | 1000 | M_FLAG, true);
+        wkb_data.extend_from_slice(&[0u8; 32]);
+        let result = Wkb::try_new(&wkb_data);
+        assert!(matches!(
+            result,
+            Err(WkbError::UnknownTypeCode { code, offset: 1, .. }) if code == 1001 | M_FLAG
+        ));
+    }
+
+    // --- Error Locations ---
+    fn polygon_xy(num_rings: u32, num_points: u32) -> Vec<u8> {
+        let mut buf = make_wkb_header(3, true);
+        buf.extend_from_slice(&num_rings.to_le_bytes());
+        for _ in 0..num_rings {
+            buf.extend_from_slice(&num_points.to_le_bytes());
+            for i in 0..num_points * 2 {
+                buf.extend_from_slice(&(i as f64).to_le_bytes());
+            }
+        }
+        buf
+    }
+
+    fn collection(type_id: u32, members: &[Vec<u8>]) -> Vec<u8> {
+        let mut buf = make_wkb_header(type_id, true);
+        buf.extend_from_slice(&(members.len() as u32).to_le_bytes());
+        for member in members {
+            buf.extend_from_slice(member);
+        }
+        buf
+    }
+
+    #[test]
+    fn test_error_location_nested_coord() {
+        // Four polygons of 9 + 2 * (4 + 20 * 16) = 657 bytes each
+        let polygons = vec![polygon_xy(2, 20); 4];
+        let mut wkb_data = collection(6, &polygons);
+        let coord_offset = 9 + 3 * 657 + 9 + (4 + 20 * 16) + 4 + 17 * 16;
+        wkb_data.truncate(coord_offset + 8);
+
+        let err = Wkb::try_new(&wkb_data).unwrap_err();
+        assert!(matches!(
+            err,
+            WkbError::UnexpectedEof {
+                needed: 16,
+                available: 8,
+                ..
+            }
+        ));
+        assert_eq!(err.offset(), Some(coord_offset as u64));
+        let path = err.path().unwrap();
+        assert_eq!(path.to_string(), "MultiPolygon[3].rings[1].coords[17]");
+        assert_eq!(
+            path.segments().copied().collect::<Vec<_>>(),
+            vec![
+                PathSegment::Geometry(GeometryType::MultiPolygon),
+                PathSegment::Member(3),
+                PathSegment::Field("rings", 1),
+                PathSegment::Field("coords", 17),
+            ]
+        );
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Unexpected end of WKB at byte {} (MultiPolygon[3].rings[1].coords[17]): 16 bytes needed, but only 8 remain",
+                coord_offset
+            )
+        );
+    }
+
+    #[test]
+    fn test_error_location_collection_member() {
+        let mut invalid = make_wkb_header(1, true);
+        invalid[0] = 0x02;
+        let point = {
+            let mut buf = make_wkb_header(1, true);
+            buf.extend_from_slice(&[0u8; 16]);
+            buf
+        };
+        let wkb_data = collection(7, &[point.clone(), collection(7, &[point, invalid])]);
+
+        let err = Wkb::try_new(&wkb_data).unwrap_err();
+        assert!(matches!(err, WkbError::InvalidByteOrder { byte: 2, .. }));
+        assert_eq!(err.offset(), Some(9 + 21 + 9 + 21));
+        assert_eq!(
+            err.path().unwrap().to_string(),
+            "GeometryCollection[1].GeometryCollection[1]"
+        );
+    }
+
+    #[test]
+    fn test_error_location_unknown_nested_type_code() {
+        let mut member = make_wkb_header(13, true);
+        member.extend_from_slice(&0u32.to_le_bytes());
+        let wkb_data = collection(7, &[member]);
+        let err = Wkb::try_new(&wkb_data).unwrap_err();
+        assert!(matches!(err, WkbError::UnknownTypeCode { code: 13, .. }));
+        assert_eq!(err.offset(), Some(10));
+        assert_eq!(err.path().unwrap().to_string(), "GeometryCollection[0]");
+    }
+
+    #[test]
+    fn test_error_location_count_overflow() {
+        let mut wkb_data = make_wkb_header(3, true);
+        wkb_data.extend_from_slice(&u32::MAX.to_le_bytes());
+        wkb_data.extend_from_slice(&[0u8; 16]);
+
+        let err = Wkb::try_new(&wkb_data).unwrap_err();
+        assert!(matches!(
+            err,
+            WkbError::CountOverflow {
+                count: u32::MAX,
+                max: 4,
+                offset: 5,
+                ..
+            }
+        ));
+        assert_eq!(err.path().unwrap().to_string(), "Polygon");
+    }
+
+    #[test]
+    fn test_error_location_unexpected_member_type() {
+        let mut line_string = make_wkb_header(2, true);
+        line_string.extend_from_slice(&0u32.to_le_bytes());
+        let wkb_data = collection(9, &[line_string, polygon_xy(0, 0)]);
+
+        let err = Wkb::try_new(&wkb_data).unwrap_err();
+        assert!(matches!(
+            err,
+            WkbError::UnexpectedType {
+                found: GeometryType::Polygon,
+                offset: 18,
+                ..
+            }
+        ));
+        assert_eq!(err.path().unwrap().to_string(), "CompoundCurve[1]");
+    }
+
+    // --- Read Limits ---
+    fn line_string_xy(num_points: u32) -> Vec<u8> {
+        let mut buf = make_wkb_header(2, true);
+        buf.extend_from_slice(&num_points.to_le_bytes());
+        buf.extend(std::iter::repeat_n(0u8, num_points as usize * 16));
+        buf
+    }
+
+    fn nested_collections(depth: usize) -> Vec<u8> {
+        let mut wkb_data = collection(7, &[]);
+        for _ in 1..depth {
+            wkb_data = collection(7, &[wkb_data]);
+        }
+        wkb_data
+    }
+
+    #[test]
+    fn test_huge_counts_do_not_allocate() {
+        for type_id in [3, 5, 6, 7, 9, 10, 11, 12, 15, 16] {
+            let mut wkb_data = make_wkb_header(type_id, true);
+            wkb_data.extend_from_slice(&u32::MAX.to_le_bytes());
+            let result = Wkb::try_new(&wkb_data);
+            assert!(
+                matches!(result, Err(WkbError::CountOverflow { max: 0, .. })),
+                "type {type_id}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_max_depth() {
+        let wkb_data = nested_collections(128);
+        assert!(Wkb::try_new(&wkb_data).is_ok());
+
+        let wkb_data = nested_collections(129);
+        let err = Wkb::try_new(&wkb_data).unwrap_err();
+        assert!(matches!(
+            err,
+            WkbError::LimitExceeded {
+                limit: ReadLimit::Depth,
+                max: 128,
+                ..
+            }
+        ));
+        assert_eq!(err.offset(), Some(128 * 9));
+
+        let options = ReadOptions {
+            max_depth: 200,
+            ..Default::default()
+        };
+        assert!(Wkb::try_new_with_options(&nested_collections(200), &options).is_ok());
+    }
+
+    #[test]
+    fn test_max_depth_multi_geometry() {
+        let options = ReadOptions {
+            max_depth: 1,
+            ..Default::default()
+        };
+        let empty = collection(4, &[]);
+        assert!(Wkb::try_new_with_options(&empty, &options).is_ok());
+
+        let mut point = make_wkb_header(1, true);
+        point.extend_from_slice(&[0u8; 16]);
+        let multi_point = collection(4, &[point]);
+        let err = Wkb::try_new_with_options(&multi_point, &options).unwrap_err();
+        assert!(matches!(
+            err,
+            WkbError::LimitExceeded {
+                limit: ReadLimit::Depth,
+                offset: 9,
+                ..
+            }
+        ));
+        assert_eq!(err.path().unwrap().to_string(), "MultiPoint[0]");
+    }
+
+    #[test]
+    fn test_max_elements() {
+        let options = ReadOptions {
+            max_elements: 5,
+            ..Default::default()
+        };
+        assert!(Wkb::try_new_with_options(&line_string_xy(5), &options).is_ok());
+
+        let err = Wkb::try_new_with_options(&line_string_xy(6), &options).unwrap_err();
+        assert!(matches!(
+            err,
+            WkbError::LimitExceeded {
+                limit: ReadLimit::Elements,
+                max: 5,
+                offset: 5,
+                ..
+            }
+        ));
+
+        let wkb_data = collection(7, &vec![line_string_xy(0); 6]);
+        let err = Wkb::try_new_with_options(&wkb_data, &options).unwrap_err();
+        assert!(matches!(
+            err,
+            WkbError::LimitExceeded {
+                limit: ReadLimit::Elements,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_max_coords() {
+        let options = ReadOptions {
+            max_coords: 10,
+            ..Default::default()
+        };
+        let wkb_data = collection(5, &vec![line_string_xy(4); 2]);
+        assert!(Wkb::try_new_with_options(&wkb_data, &options).is_ok());
+
+        let wkb_data = collection(5, &vec![line_string_xy(4); 3]);
+        let err = Wkb::try_new_with_options(&wkb_data, &options).unwrap_err();
+        assert!(matches!(
+            err,
+            WkbError::LimitExceeded {
+                limit: ReadLimit::Coords,
+                max: 10,
+                ..
+            }
+        ));
+        assert_eq!(err.offset(), Some(9 + 2 * 73 + 5));
+        assert_eq!(err.path().unwrap().to_string(), "MultiLineString[2]");
+    }
+
+    // --- Trailing Bytes ---
+    #[test]
+    fn test_trailing_bytes() {
+        let mut wkb_data = line_string_xy(2);
+        assert!(read_wkb_exact(&wkb_data).is_ok());
+
+        wkb_data.extend_from_slice(&[0xFF; 3]);
+        assert_eq!(read_wkb(&wkb_data).unwrap().buf().len(), 41);
+        let err = read_wkb_exact(&wkb_data).unwrap_err();
+        assert!(matches!(
+            err,
+            WkbError::TrailingBytes {
+                offset: 41,
+                count: 3
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "3 trailing bytes after the end of the WKB geometry at byte 41"
+        );
+    }
+
+    #[test]
+    fn test_trailing_geometry() {
+        let mut wkb_data = collection(7, &[line_string_xy(1)]);
+        let end = wkb_data.len() as u64;
+        wkb_data.extend_from_slice(&line_string_xy(2));
+        let options = ReadOptions {
+            reject_trailing_bytes: true,
+            ..Default::default()
+        };
+        let err = Wkb::try_new_with_options(&wkb_data, &options).unwrap_err();
+        assert!(matches!(
+            err,
+            WkbError::TrailingBytes { offset, count: 41 } if offset == end
+        ));
+    }
+
+    // --- Lazy GeometryCollections ---
+    #[test]
+    fn test_lazy_collection_validates_members() {
+        let options = ReadOptions {
+            max_coords: 3,
+            ..Default::default()
+        };
+        let mut wkb_data = collection(7, &[line_string_xy(1), nested_collections(2)]);
+        assert!(LazyGeometryCollection::try_new_with_options(&wkb_data, &options).is_ok());
+
+        wkb_data.truncate(wkb_data.len() - 1);
+        let err = LazyGeometryCollection::try_new_with_options(&wkb_data, &options).unwrap_err();
+        assert_eq!(
+            err.path().unwrap().to_string(),
+            "GeometryCollection[1].GeometryCollection"
+        );
+
+        let wkb_data = collection(7, &[line_string_xy(2), line_string_xy(2)]);
+        let err = LazyGeometryCollection::try_new_with_options(&wkb_data, &options).unwrap_err();
+        assert!(matches!(
+            err,
+            WkbError::LimitExceeded {
+                limit: ReadLimit::Coords,
+                ..
+            }
+        ));
+
+        let err = LazyGeometryCollection::try_new(&line_string_xy(2)).unwrap_err();
+        assert!(matches!(
+            err,
+            WkbError::UnexpectedType {
+                found: GeometryType::LineString,
+                ..
+            }
+        ));
+    }
 }
@@ -1,5 +1,18 @@
+mod buf;
+mod curves;
 mod data;
 mod ewkb;
+mod geojson;
+mod gpkg;
+mod hex;
 mod invalid_ewkb;
 mod invalid_wkb;
+mod mysql;
+#[cfg(feature = "bytes")]
+mod shared;
+mod spatialite;
+mod stream;
+mod surfaces;
+mod twkb;
 mod wkb;
+mod wkt;
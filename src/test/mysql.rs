@@ -0,0 +1,92 @@
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_types::Geometry;
+
+use crate::error::WkbError;
+use crate::mysql::{read_mysql, write_mysql, MysqlGeometry};
+use crate::writer::{write_geometry, WkbFlavor, WriteOptions};
+use crate::Endianness;
+
+use super::data::*;
+
+/// `ST_GeomFromText('POINT(1 2)', 4326)` as stored by MySQL
+const MYSQL_POINT: [u8; 25] = [
+    0xE6, 0x10, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0,
+    0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40,
+];
+
+#[test]
+fn read_point() {
+    let geometry = read_mysql(&MYSQL_POINT).unwrap();
+    assert_eq!(geometry.srid(), 4326);
+    assert_eq!(geometry.wkb().buf(), &MYSQL_POINT[4..]);
+    assert_eq!(
+        geometry.into_wkb().to_geometry(),
+        Geometry::Point(geo_types::point!(x: 1., y: 2.))
+    );
+}
+
+#[test]
+fn write_point() {
+    let mut buf = Vec::new();
+    let point = geo_types::point!(x: 1., y: 2.);
+    write_mysql(&mut buf, &point, 4326, &Default::default()).unwrap();
+    assert_eq!(buf, MYSQL_POINT);
+}
+
+#[test]
+fn round_trip() {
+    let geom = Geometry::MultiPolygon(multi_polygon_2d());
+    // The SRID stays little-endian regardless of the WKB byte order
+    let options = WriteOptions {
+        endianness: Endianness::BigEndian,
+        ..Default::default()
+    };
+    let mut buf = Vec::new();
+    write_mysql(&mut buf, &geom, 3857, &options).unwrap();
+    assert_eq!(buf[..4], 3857u32.to_le_bytes());
+
+    let mut wkb = Vec::new();
+    write_geometry(&mut wkb, &geom, &options).unwrap();
+    assert_eq!(buf[4..], wkb);
+
+    let geometry = MysqlGeometry::try_new(&buf).unwrap();
+    assert_eq!(geometry.srid(), 3857);
+    assert_eq!(geometry.wkb().to_geometry(), geom);
+}
+
+#[test]
+fn write_rejects_ewkb_options() {
+    let ewkb = WriteOptions {
+        flavor: WkbFlavor::Ewkb,
+        srid: Some(4326),
+        ..Default::default()
+    };
+    let srid = WriteOptions {
+        srid: Some(4326),
+        ..Default::default()
+    };
+    for options in [ewkb, srid] {
+        let mut buf = Vec::new();
+        let err = write_mysql(&mut buf, &point_2d(), 4326, &options).unwrap_err();
+        assert!(matches!(err, WkbError::General(_)), "{err}");
+        assert!(buf.is_empty());
+    }
+}
+
+#[test]
+fn invalid_values() {
+    let err = read_mysql(&MYSQL_POINT[..3]).unwrap_err();
+    assert!(matches!(
+        err,
+        WkbError::UnexpectedEof {
+            offset: 0,
+            needed: 4,
+            available: 3,
+            ..
+        }
+    ));
+
+    let err = read_mysql(&MYSQL_POINT[..20]).unwrap_err();
+    assert!(matches!(err, WkbError::UnexpectedEof { .. }));
+    assert_eq!(err.offset(), Some(9));
+}
@@ -0,0 +1,98 @@
+use bytes::Bytes;
+use geo_traits::to_geo::{ToGeoGeometry, ToGeoPolygon};
+use geo_traits::GeometryCollectionTrait;
+use geo_types::{Geometry, GeometryCollection};
+
+use crate::reader::{BytesGeometryCollection, BytesWkb};
+use crate::writer::write_geometry;
+
+use super::data::*;
+
+fn to_bytes(geom: &Geometry) -> Bytes {
+    let mut buf = Vec::new();
+    write_geometry(&mut buf, geom, &Default::default()).unwrap();
+    buf.into()
+}
+
+#[test]
+fn typed_geometries() {
+    let polygon = polygon_2d_with_interior();
+    let wkb = BytesWkb::try_new(to_bytes(&Geometry::Polygon(polygon.clone()))).unwrap();
+    assert!(wkb.to_point().is_none());
+    assert!(wkb.to_geometry_collection().is_none());
+
+    let typed = wkb.to_polygon().unwrap();
+    assert_eq!(typed.to_polygon(), polygon);
+    assert_eq!(typed.to_geometry(), Geometry::Polygon(polygon));
+    // The typed geometry shares the buffer instead of copying it
+    assert_eq!(typed.bytes().as_ptr(), wkb.as_bytes().as_ptr());
+
+    let multi_polygon = multi_polygon_2d();
+    let wkb = BytesWkb::try_new(to_bytes(&Geometry::MultiPolygon(multi_polygon.clone()))).unwrap();
+    let polygons = wkb
+        .to_multi_polygon()
+        .unwrap()
+        .owned_polygons()
+        .collect::<Vec<_>>();
+    drop(wkb);
+    assert_eq!(polygons.len(), multi_polygon.0.len());
+    for (typed, expected) in polygons.iter().zip(&multi_polygon.0) {
+        assert_eq!(&typed.to_polygon(), expected);
+    }
+}
+
+#[test]
+fn geometry_collection_members_outlive_the_collection() {
+    let collection = GeometryCollection::new_from(vec![
+        Geometry::Point(point_2d()),
+        Geometry::GeometryCollection(geometry_collection_2d()),
+        Geometry::MultiLineString(multi_line_string_2d()),
+    ]);
+    let bytes = to_bytes(&Geometry::GeometryCollection(collection.clone()));
+
+    // Parsed geometries are 'static, so they can be handed to another thread
+    let parsed: BytesGeometryCollection = std::thread::spawn(move || {
+        BytesWkb::try_new(bytes)
+            .unwrap()
+            .to_geometry_collection()
+            .unwrap()
+    })
+    .join()
+    .unwrap();
+    assert_eq!(parsed.num_geometries(), 3);
+
+    let members = parsed.owned_geometries().collect::<Vec<_>>();
+    drop(parsed);
+    for (member, expected) in members.iter().zip(&collection.0) {
+        assert_eq!(&member.to_geometry(), expected);
+    }
+    assert!(members[0].to_point().is_some());
+    let nested = members[1].to_geometry_collection().unwrap();
+    assert_eq!(
+        nested.to_geometry(),
+        Geometry::GeometryCollection(geometry_collection_2d())
+    );
+    assert!(members[2].to_multi_line_string().is_some());
+}
+
+#[test]
+fn owned_members_hold_only_their_bytes() {
+    let collection = GeometryCollection::new_from(vec![
+        Geometry::Point(point_2d()),
+        Geometry::LineString(linestring_2d()),
+    ]);
+    let bytes = to_bytes(&Geometry::GeometryCollection(collection.clone()));
+    let parsed = BytesWkb::try_new(bytes)
+        .unwrap()
+        .to_geometry_collection()
+        .unwrap();
+
+    let member = parsed.owned_geometries().nth(1).unwrap();
+    let expected = to_bytes(&collection.0[1]);
+    assert_eq!(member.as_bytes(), expected.as_ref());
+
+    let cloned = member.clone();
+    assert_eq!(cloned.to_geometry(), collection.0[1]);
+    assert_eq!(cloned.as_bytes(), expected.as_ref());
+    assert_eq!(member.into_inner(), expected);
+}
@@ -0,0 +1,247 @@
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_traits::{CoordTrait, GeometryTrait, LineStringTrait};
+use geo_types::{Geometry, GeometryCollection, LineString, MultiLineString, MultiPolygon, Polygon};
+
+use crate::error::WkbError;
+use crate::reader::{read_wkb, Dimension};
+use crate::spatialite::{read_spatialite, write_spatialite, SpatialiteMbr, SpatialiteWriteOptions};
+use crate::Endianness;
+
+use super::data::*;
+
+/// `SRID=4326;POINT(1 2)` as written by SpatiaLite
+fn point_blob() -> Vec<u8> {
+    let mut blob = vec![0x00, 0x01];
+    blob.extend_from_slice(&4326i32.to_le_bytes());
+    for value in [1f64, 2., 1., 2.] {
+        blob.extend_from_slice(&value.to_le_bytes());
+    }
+    blob.push(0x7C);
+    blob.extend_from_slice(&1u32.to_le_bytes());
+    blob.extend_from_slice(&1f64.to_le_bytes());
+    blob.extend_from_slice(&2f64.to_le_bytes());
+    blob.push(0xFE);
+    blob
+}
+
+#[test]
+fn read_point_blob() {
+    let mut buf = Vec::new();
+    let geometry = read_spatialite(&point_blob(), &mut buf).unwrap();
+    assert_eq!(geometry.srid(), 4326);
+    assert_eq!(geometry.byte_order(), Endianness::LittleEndian);
+    assert_eq!(
+        geometry.mbr(),
+        &SpatialiteMbr {
+            min_x: 1.,
+            min_y: 2.,
+            max_x: 1.,
+            max_y: 2.,
+        }
+    );
+    assert_eq!(
+        geometry.wkb().to_geometry(),
+        Geometry::Point(geo_types::point!(x: 1., y: 2.))
+    );
+}
+
+#[test]
+fn write_point_blob() {
+    let mut blob = Vec::new();
+    let point = geo_types::point!(x: 1., y: 2.);
+    write_spatialite(&mut blob, &point, 4326, &Default::default()).unwrap();
+    assert_eq!(blob, point_blob());
+}
+
+#[test]
+fn round_trip() {
+    let mut buf = Vec::new();
+    for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+        for compressed in [false, true] {
+            let options = SpatialiteWriteOptions {
+                endianness,
+                compressed,
+            };
+            // Collections are flattened, which `collections_are_flattened` covers
+            let geometries = geometries_2d()
+                .into_iter()
+                .filter(|geom| !matches!(geom, Geometry::GeometryCollection(_)));
+            for geom in geometries {
+                let mut blob = Vec::new();
+                write_spatialite(&mut blob, &geom, 0, &options).unwrap();
+                let geometry = read_spatialite(&blob, &mut buf).unwrap();
+                assert_eq!(geometry.byte_order(), endianness);
+                assert_eq!(geometry.into_wkb().to_geometry(), geom);
+            }
+        }
+    }
+}
+
+#[test]
+fn round_trip_empty_members() {
+    // Each empty member is only an entity marker, class, and count
+    let empty_polygon = Polygon::new(LineString::new(vec![]), vec![]);
+    let geometries = [
+        Geometry::MultiLineString(MultiLineString::new(vec![LineString::new(vec![]); 3])),
+        Geometry::MultiPolygon(MultiPolygon::new(vec![empty_polygon; 4])),
+    ];
+    let mut buf = Vec::new();
+    for geom in geometries {
+        let mut blob = Vec::new();
+        write_spatialite(&mut blob, &geom, 0, &Default::default()).unwrap();
+        let geometry = read_spatialite(&blob, &mut buf).unwrap();
+        assert_eq!(geometry.into_wkb().to_geometry(), geom);
+    }
+}
+
+#[test]
+fn compressed_blobs_are_smaller() {
+    let geom = Geometry::Polygon(polygon_2d_with_interior());
+    let mut plain = Vec::new();
+    write_spatialite(&mut plain, &geom, 0, &Default::default()).unwrap();
+    let mut compressed = Vec::new();
+    let options = SpatialiteWriteOptions {
+        compressed: true,
+        ..Default::default()
+    };
+    write_spatialite(&mut compressed, &geom, 0, &options).unwrap();
+
+    // Each ring has 3 intermediate vertices stored as two f32 offsets instead of two f64 values
+    assert_eq!(plain.len() - compressed.len(), 2 * 3 * 8);
+    // The compressed Polygon class
+    assert_eq!(compressed[39..43], 1_000_003u32.to_le_bytes());
+}
+
+#[test]
+fn compressed_line_string_with_z_and_m() {
+    let coords = [[1., 2., 3., 4.], [2., 4., 6., 8.5], [0.5, 1.5, 2.5, 3.5]];
+    let wkb_buf = line_string_zm_wkb(&coords);
+    let wkb = read_wkb(&wkb_buf).unwrap();
+
+    let options = SpatialiteWriteOptions {
+        compressed: true,
+        ..Default::default()
+    };
+    let mut blob = Vec::new();
+    write_spatialite(&mut blob, &wkb, 0, &options).unwrap();
+    assert_eq!(blob[39..43], 1_003_002u32.to_le_bytes());
+
+    let mut buf = Vec::new();
+    let geometry = read_spatialite(&blob, &mut buf).unwrap();
+    assert_eq!(geometry.wkb().dimension(), Dimension::Xyzm);
+    let geo_traits::GeometryType::LineString(line_string) = geometry.wkb().as_type() else {
+        panic!("expected a LineString");
+    };
+    let read = line_string
+        .coords()
+        .map(|c| [c.x(), c.y(), c.nth_or_panic(2), c.nth_or_panic(3)])
+        .collect::<Vec<_>>();
+    assert_eq!(read, coords);
+    assert_eq!(geometry.wkb().buf(), wkb_buf.as_slice());
+}
+
+#[test]
+fn collections_are_flattened() {
+    let nested = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+        Geometry::Point(point_2d()),
+        Geometry::MultiLineString(multi_line_string_2d()),
+        Geometry::GeometryCollection(GeometryCollection::new_from(vec![Geometry::Polygon(
+            polygon_2d(),
+        )])),
+    ]));
+    let mut blob = Vec::new();
+    write_spatialite(&mut blob, &nested, 0, &Default::default()).unwrap();
+
+    let mut buf = Vec::new();
+    let geometry = read_spatialite(&blob, &mut buf).unwrap();
+    let mut members = vec![Geometry::Point(point_2d())];
+    members.extend(
+        multi_line_string_2d()
+            .0
+            .into_iter()
+            .map(Geometry::LineString),
+    );
+    members.push(Geometry::Polygon(polygon_2d()));
+    assert_eq!(
+        geometry.wkb().to_geometry(),
+        Geometry::GeometryCollection(GeometryCollection::new_from(members))
+    );
+}
+
+#[test]
+fn invalid_blobs() {
+    let mut buf = Vec::new();
+    let valid = point_blob();
+
+    let mut blob = valid.clone();
+    blob[0] = 0x01;
+    let err = read_spatialite(&blob, &mut buf).unwrap_err();
+    assert!(matches!(err, WkbError::InvalidHeader { offset: 0, .. }));
+
+    let mut blob = valid.clone();
+    blob[1] = 0x02;
+    let err = read_spatialite(&blob, &mut buf).unwrap_err();
+    assert!(matches!(err, WkbError::InvalidHeader { offset: 1, .. }));
+
+    let mut blob = valid.clone();
+    blob[38] = 0x00;
+    let err = read_spatialite(&blob, &mut buf).unwrap_err();
+    assert!(matches!(err, WkbError::InvalidHeader { offset: 38, .. }));
+
+    let mut blob = valid.clone();
+    *blob.last_mut().unwrap() = 0x00;
+    let err = read_spatialite(&blob, &mut buf).unwrap_err();
+    assert!(matches!(err, WkbError::InvalidHeader { offset: 59, .. }));
+
+    let mut blob = valid.clone();
+    blob.push(0x00);
+    let err = read_spatialite(&blob, &mut buf).unwrap_err();
+    assert!(matches!(
+        err,
+        WkbError::TrailingBytes {
+            offset: 60,
+            count: 1
+        }
+    ));
+
+    // The compressed classes only exist for LineStrings and Polygons
+    let mut blob = valid.clone();
+    blob[39..43].copy_from_slice(&1_000_001u32.to_le_bytes());
+    let err = read_spatialite(&blob, &mut buf).unwrap_err();
+    assert!(matches!(
+        err,
+        WkbError::UnknownTypeCode {
+            code: 1_000_001,
+            offset: 39,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn invalid_entities() {
+    let mut buf = Vec::new();
+    let mut valid = Vec::new();
+    let geom = Geometry::MultiPolygon(multi_polygon_2d());
+    write_spatialite(&mut valid, &geom, 0, &Default::default()).unwrap();
+    let first_entity = 39 + 4 + 4;
+    let polygon_size = 1 + 4 + 4 + 4 + 5 * 16;
+    let second_entity = first_entity + polygon_size;
+
+    let mut blob = valid.clone();
+    blob[second_entity] = 0x00;
+    let err = read_spatialite(&blob, &mut buf).unwrap_err();
+    assert!(matches!(err, WkbError::InvalidHeader { .. }));
+    assert_eq!(err.offset(), Some(second_entity as u64));
+
+    let mut blob = valid.clone();
+    blob[second_entity + 1..second_entity + 5].copy_from_slice(&2u32.to_le_bytes());
+    let err = read_spatialite(&blob, &mut buf).unwrap_err();
+    assert!(matches!(err, WkbError::InvalidGeometry { .. }));
+    assert_eq!(err.path().unwrap().to_string(), "MultiPolygon[1]");
+
+    let err = read_spatialite(&valid[..second_entity + 20], &mut buf).unwrap_err();
+    assert!(matches!(err, WkbError::CountOverflow { count: 5, .. }));
+    assert_eq!(err.offset(), Some(second_entity as u64 + 9));
+    assert_eq!(err.path().unwrap().to_string(), "MultiPolygon[1].rings[0]");
+}
@@ -0,0 +1,191 @@
+use std::io::{Cursor, Read};
+
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_types::Geometry;
+
+use crate::error::WkbError;
+use crate::reader::{iter_wkb, read_wkb, ReadLimit, ReadOptions, WkbStreamReader};
+use crate::writer::{write_geometry, WkbFlavor, WriteOptions};
+use crate::Endianness;
+
+use super::data::*;
+
+/// Write each geometry with alternating byte orders and WKB flavors
+fn concatenated(geometries: &[Geometry]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    for (i, geom) in geometries.iter().enumerate() {
+        let options = if i % 2 == 0 {
+            WriteOptions::default()
+        } else {
+            WriteOptions {
+                endianness: Endianness::BigEndian,
+                flavor: WkbFlavor::Ewkb,
+                srid: Some(4326),
+                ..Default::default()
+            }
+        };
+        write_geometry(&mut buf, geom, &options).unwrap();
+    }
+    buf
+}
+
+/// A reader that returns at most one byte per call
+struct OneByteReader<R>(R);
+
+impl<R: Read> Read for OneByteReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = buf.len().min(1);
+        self.0.read(&mut buf[..len])
+    }
+}
+
+#[test]
+fn read_concatenated_geometries() {
+    let geometries = geometries_2d();
+    let buf = concatenated(&geometries);
+
+    let mut reader = WkbStreamReader::new(Cursor::new(&buf));
+    let mut position = 0;
+    for geom in &geometries {
+        let wkb = reader.read_next().unwrap().unwrap();
+        assert_eq!(&buf[position..position + wkb.len()], wkb.as_slice());
+        assert_eq!(read_wkb(&wkb).unwrap().to_geometry(), *geom);
+        position += wkb.len();
+        assert_eq!(reader.position(), position as u64);
+    }
+    assert!(reader.read_next().unwrap().is_none());
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn read_one_byte_at_a_time() {
+    let geometries = geometries_2d();
+    let buf = concatenated(&geometries);
+
+    let reader = WkbStreamReader::new(OneByteReader(Cursor::new(&buf)));
+    let read = reader
+        .map(|wkb| read_wkb(&wkb.unwrap()).unwrap().to_geometry())
+        .collect::<Vec<_>>();
+    assert_eq!(read, geometries);
+}
+
+#[test]
+fn read_empty_stream() {
+    let mut reader = WkbStreamReader::new(Cursor::new(Vec::new()));
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn read_truncated_stream() {
+    let geometries = geometries_2d();
+    let buf = concatenated(&geometries[..3]);
+    let first_two = concatenated(&geometries[..2]).len();
+
+    // Cut the polygon off in the middle of the first coordinate of its second ring
+    let mut polygon = Vec::new();
+    write_geometry(&mut polygon, &geometries[2], &Default::default()).unwrap();
+    let ring_offset = polygon.len() - (4 + 5 * 16);
+    let truncated = &buf[..first_two + ring_offset + 4 + 8];
+
+    let mut reader = WkbStreamReader::new(Cursor::new(truncated));
+    assert!(reader.next().unwrap().is_ok());
+    assert!(reader.next().unwrap().is_ok());
+    let err = reader.next().unwrap().unwrap_err();
+    assert!(matches!(
+        err,
+        WkbError::UnexpectedEof {
+            needed: 16,
+            available: 8,
+            ..
+        }
+    ));
+    assert_eq!(err.offset(), Some((first_two + ring_offset + 4) as u64));
+    assert_eq!(
+        err.path().unwrap().to_string(),
+        "Polygon.rings[1].coords[0]"
+    );
+
+    // Iteration stops after an error
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn read_stream_with_limits() {
+    let geometries = geometries_2d();
+    let buf = concatenated(&geometries);
+    let options = ReadOptions {
+        max_coords: 10,
+        ..Default::default()
+    };
+
+    let mut reader = WkbStreamReader::with_options(Cursor::new(&buf), options);
+    for _ in 0..5 {
+        assert!(reader.next().unwrap().is_ok());
+    }
+    let err = reader.next().unwrap().unwrap_err();
+    assert!(matches!(
+        err,
+        WkbError::LimitExceeded {
+            limit: ReadLimit::Coords,
+            ..
+        }
+    ));
+    assert_eq!(err.path().unwrap().to_string(), "MultiPolygon[1].rings[1]");
+}
+
+#[test]
+fn huge_count_does_not_allocate() {
+    // A LineString declaring u32::MAX points, followed by a single point
+    let mut buf = vec![0x01];
+    buf.extend_from_slice(&2u32.to_le_bytes());
+    buf.extend_from_slice(&u32::MAX.to_le_bytes());
+    buf.extend_from_slice(&[0u8; 16]);
+
+    let mut reader = WkbStreamReader::new(Cursor::new(buf));
+    let err = reader.next().unwrap().unwrap_err();
+    assert!(matches!(
+        err,
+        WkbError::UnexpectedEof {
+            offset: 25,
+            needed: 16,
+            available: 0,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn iter_concatenated_geometries() {
+    let geometries = geometries_2d();
+    let buf = concatenated(&geometries);
+
+    let mut offset = 0;
+    let mut read = Vec::new();
+    for wkb in iter_wkb(&buf) {
+        let wkb = wkb.unwrap();
+        assert_eq!(wkb.buf(), &buf[offset..offset + wkb.size() as usize]);
+        offset += wkb.size() as usize;
+        read.push(wkb.to_geometry());
+    }
+    assert_eq!(offset, buf.len());
+    assert_eq!(read, geometries);
+
+    assert!(iter_wkb(&[]).next().is_none());
+}
+
+#[test]
+fn iter_stops_after_error() {
+    let geometries = geometries_2d();
+    let mut buf = concatenated(&geometries[..2]);
+    let end = buf.len();
+    buf.extend_from_slice(&[0x02, 0x01, 0x00, 0x00, 0x00]);
+    buf.extend_from_slice(&concatenated(&geometries[..1]));
+
+    let mut iter = iter_wkb(&buf);
+    assert!(iter.next().unwrap().is_ok());
+    assert!(iter.next().unwrap().is_ok());
+    let err = iter.next().unwrap().unwrap_err();
+    assert!(matches!(err, WkbError::InvalidByteOrder { byte: 2, .. }));
+    assert_eq!(err.offset(), Some(end as u64));
+    assert!(iter.next().is_none());
+}
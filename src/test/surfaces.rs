@@ -0,0 +1,342 @@
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_traits::{
+    CoordTrait, GeometryTrait, LineStringTrait, MultiPolygonTrait, PolygonTrait, TriangleTrait,
+};
+use geo_types::{coord, Geometry, LineString, MultiPolygon, Polygon, Triangle};
+
+use crate::error::WkbError;
+use crate::reader::{read_wkb, Dimension, GeometryType};
+use crate::writer::{
+    wkb_size, write_polyhedral_surface, write_tin, write_triangle, write_wkb, WriteOptions,
+};
+use crate::Endianness;
+
+use super::data::*;
+
+/// A single-ring geometry with the Polygon layout and XYZ coordinates
+fn ring_z(code: u32, coords: &[[f64; 3]]) -> Vec<u8> {
+    let mut buf = wkb_header(code);
+    buf.extend_from_slice(&1u32.to_le_bytes());
+    buf.extend_from_slice(&(coords.len() as u32).to_le_bytes());
+    for coord in coords {
+        for val in coord {
+            buf.extend_from_slice(&val.to_le_bytes());
+        }
+    }
+    buf
+}
+
+fn triangle_z(a: [f64; 3], b: [f64; 3], c: [f64; 3]) -> Vec<u8> {
+    ring_z(1017, &[a, b, c, a])
+}
+
+fn tin_z() -> Vec<u8> {
+    wkb_container(
+        1016,
+        &[
+            triangle_z([0., 0., 0.], [1., 0., 0.], [0., 1., 1.]),
+            triangle_z([1., 0., 0.], [1., 1., 1.], [0., 1., 1.]),
+        ],
+    )
+}
+
+fn polyhedral_surface_z() -> Vec<u8> {
+    wkb_container(
+        1015,
+        &[
+            ring_z(
+                1003,
+                &[
+                    [0., 0., 0.],
+                    [0., 1., 0.],
+                    [1., 1., 0.],
+                    [1., 0., 0.],
+                    [0., 0., 0.],
+                ],
+            ),
+            ring_z(
+                1003,
+                &[
+                    [0., 0., 0.],
+                    [0., 0., 1.],
+                    [0., 1., 1.],
+                    [0., 1., 0.],
+                    [0., 0., 0.],
+                ],
+            ),
+        ],
+    )
+}
+
+fn line_string(coords: &[f64]) -> Vec<u8> {
+    let mut buf = wkb_header(2);
+    buf.extend_from_slice(&((coords.len() / 2) as u32).to_le_bytes());
+    for val in coords {
+        buf.extend_from_slice(&val.to_le_bytes());
+    }
+    buf
+}
+
+fn circular_string(coords: &[f64]) -> Vec<u8> {
+    let mut buf = line_string(coords);
+    buf[1..5].copy_from_slice(&8u32.to_le_bytes());
+    buf
+}
+
+#[test]
+fn read_triangle() {
+    let buf = triangle_z([0., 0., 0.], [1., 0., 0.], [0., 1., 1.]);
+    let wkb = read_wkb(&buf).unwrap();
+    assert_eq!(wkb.geometry_type(), GeometryType::Triangle);
+    assert_eq!(wkb.dimension(), Dimension::Xyz);
+
+    let geo_traits::GeometryType::Triangle(tri) = wkb.as_type() else {
+        panic!("expected a Triangle");
+    };
+    assert_eq!(tri.second().x(), 1.);
+    assert_eq!(tri.third().y(), 1.);
+    assert_eq!(tri.third().nth_or_panic(2), 1.);
+}
+
+#[test]
+fn read_empty_triangle() {
+    let buf = wkb_container(17, &[]);
+    let wkb = read_wkb(&buf).unwrap();
+    assert_eq!(wkb.geometry_type(), GeometryType::Triangle);
+    assert!(wkb.as_triangle().unwrap().is_empty());
+
+    let geo_traits::GeometryType::Polygon(polygon) = wkb.as_type() else {
+        panic!("expected an empty Polygon");
+    };
+    assert!(polygon.exterior().is_none());
+}
+
+#[test]
+fn read_triangle_invalid_ring() {
+    let buf = ring_z(
+        1017,
+        &[
+            [0., 0., 0.],
+            [1., 0., 0.],
+            [1., 1., 0.],
+            [0., 1., 0.],
+            [0., 0., 0.],
+        ],
+    );
+    assert!(read_wkb(&buf).is_err());
+}
+
+#[test]
+fn read_tin() {
+    let buf = tin_z();
+    let wkb = read_wkb(&buf).unwrap();
+    assert_eq!(wkb.geometry_type(), GeometryType::Tin);
+    assert_eq!(wkb.dimension(), Dimension::Xyz);
+
+    let tin = wkb.as_tin().unwrap();
+    assert_eq!(tin.num_triangles(), 2);
+    let second = tin.triangle(1).unwrap();
+    assert_eq!(second.second().x(), 1.);
+    assert_eq!(second.second().y(), 1.);
+    assert_eq!(tin.triangles().count(), 2);
+
+    let geo_traits::GeometryType::MultiPolygon(mp) = wkb.as_type() else {
+        panic!("expected a MultiPolygon");
+    };
+    assert_eq!(mp.num_polygons(), 2);
+}
+
+#[test]
+fn read_tin_invalid_patch() {
+    let patch = ring_z(
+        1003,
+        &[[0., 0., 0.], [1., 0., 0.], [0., 1., 1.], [0., 0., 0.]],
+    );
+    let buf = wkb_container(1016, &[patch]);
+    assert!(read_wkb(&buf).is_err());
+}
+
+#[test]
+fn read_patch_header_mismatch() {
+    // A Triangle Z inside a 2D TIN
+    let patch = triangle_z([0., 0., 0.], [1., 0., 0.], [0., 1., 1.]);
+    let buf = wkb_container(16, std::slice::from_ref(&patch));
+    let err = read_wkb(&buf).unwrap_err();
+    assert!(matches!(err, WkbError::InvalidGeometry { .. }), "{err}");
+
+    // A big-endian Triangle inside a little-endian TIN
+    let mut big_endian = patch;
+    big_endian[0] = 0;
+    big_endian[1..5].copy_from_slice(&1017u32.to_be_bytes());
+    let buf = wkb_container(1016, &[big_endian]);
+    let err = read_wkb(&buf).unwrap_err();
+    assert!(matches!(err, WkbError::InvalidGeometry { .. }), "{err}");
+
+    // A Polygon Z inside a 2D PolyhedralSurface
+    let patch = ring_z(
+        1003,
+        &[[0., 0., 0.], [1., 0., 0.], [0., 1., 1.], [0., 0., 0.]],
+    );
+    let buf = wkb_container(15, &[patch]);
+    let err = read_wkb(&buf).unwrap_err();
+    assert!(matches!(err, WkbError::InvalidGeometry { .. }), "{err}");
+}
+
+#[test]
+fn empty_tin_patch() {
+    // Rejected by both the reader and the writer
+    let buf = wkb_container(1016, &[wkb_container(1017, &[])]);
+    let err = read_wkb(&buf).unwrap_err();
+    assert!(matches!(err, WkbError::InvalidGeometry { .. }), "{err}");
+
+    let empty = MultiPolygon::new(vec![Polygon::new(LineString::new(vec![]), vec![])]);
+    let mut buf = Vec::new();
+    let err = write_tin(&mut buf, &empty, &WriteOptions::default()).unwrap_err();
+    assert!(matches!(err, WkbError::General(_)), "{err}");
+}
+
+#[test]
+fn read_polyhedral_surface() {
+    let buf = polyhedral_surface_z();
+    let wkb = read_wkb(&buf).unwrap();
+    assert_eq!(wkb.geometry_type(), GeometryType::PolyhedralSurface);
+
+    let surface = wkb.as_polyhedral_surface().unwrap();
+    assert_eq!(surface.num_patches(), 2);
+    let patch = surface.patch(1).unwrap();
+    assert_eq!(patch.exterior().unwrap().num_coords(), 5);
+}
+
+#[test]
+fn read_multi_curve_and_multi_surface() {
+    let curves = [
+        line_string(&[0., 0., 1., 1.]),
+        circular_string(&[0., 0., 1., 1., 2., 0.]),
+    ];
+    let buf = wkb_container(11, &curves);
+    let wkb = read_wkb(&buf).unwrap();
+    assert_eq!(wkb.geometry_type(), GeometryType::MultiCurve);
+    let mc = wkb.as_multi_curve().unwrap();
+    assert_eq!(mc.num_curves(), 2);
+    assert_eq!(
+        mc.curve(1).unwrap().geometry_type(),
+        GeometryType::CircularString
+    );
+
+    let curve_polygon = wkb_container(10, &[circular_string(&[0., 0., 1., 1., 0., 0.])]);
+    let buf = wkb_container(12, &[curve_polygon]);
+    let wkb = read_wkb(&buf).unwrap();
+    assert_eq!(wkb.geometry_type(), GeometryType::MultiSurface);
+    let ms = wkb.as_multi_surface().unwrap();
+    assert_eq!(ms.num_surfaces(), 1);
+    assert_eq!(
+        ms.surface(0).unwrap().geometry_type(),
+        GeometryType::CurvePolygon
+    );
+
+    // A MultiSurface cannot contain curves
+    let buf = wkb_container(12, &curves);
+    assert!(read_wkb(&buf).is_err());
+}
+
+#[test]
+fn read_abstract_types() {
+    for code in [13, 14, 1013, 3014] {
+        let buf = wkb_container(code, &[]);
+        assert!(read_wkb(&buf).is_err());
+    }
+}
+
+#[test]
+fn write_triangle_native() {
+    let tri = Triangle::new(
+        coord! { x: 0., y: 0. },
+        coord! { x: 1., y: 0. },
+        coord! { x: 0., y: 1. },
+    );
+
+    // Written as a Polygon by default
+    let mut buf = Vec::new();
+    write_triangle(&mut buf, &tri, &WriteOptions::default()).unwrap();
+    let wkb = read_wkb(&buf).unwrap();
+    assert_eq!(wkb.geometry_type(), GeometryType::Polygon);
+
+    let options = WriteOptions {
+        native_triangles: true,
+        ..Default::default()
+    };
+    let mut buf = Vec::new();
+    write_triangle(&mut buf, &tri, &options).unwrap();
+    let wkb = read_wkb(&buf).unwrap();
+    assert_eq!(wkb.geometry_type(), GeometryType::Triangle);
+    assert_eq!(Geometry::Triangle(tri), wkb.to_geometry());
+}
+
+#[test]
+fn write_surfaces_from_multi_polygon() {
+    let orig = multi_polygon_2d();
+    let mut buf = Vec::new();
+    write_polyhedral_surface(&mut buf, &orig, &WriteOptions::default()).unwrap();
+    let wkb = read_wkb(&buf).unwrap();
+    assert_eq!(wkb.geometry_type(), GeometryType::PolyhedralSurface);
+    assert_eq!(Geometry::MultiPolygon(orig), wkb.to_geometry());
+
+    let triangles: MultiPolygon = MultiPolygon::new(vec![
+        Triangle::new(
+            coord! { x: 0., y: 0. },
+            coord! { x: 1., y: 0. },
+            coord! { x: 0., y: 1. },
+        )
+        .to_polygon(),
+        Triangle::new(
+            coord! { x: 1., y: 0. },
+            coord! { x: 1., y: 1. },
+            coord! { x: 0., y: 1. },
+        )
+        .to_polygon(),
+    ]);
+    let mut buf = Vec::new();
+    write_tin(&mut buf, &triangles, &WriteOptions::default()).unwrap();
+    let wkb = read_wkb(&buf).unwrap();
+    assert_eq!(wkb.geometry_type(), GeometryType::Tin);
+    assert_eq!(wkb.as_tin().unwrap().num_triangles(), 2);
+}
+
+#[test]
+fn write_tin_invalid_patch() {
+    // Neither a quadrilateral nor a polygon with a hole is a triangle
+    for patch in [polygon_2d(), polygon_2d_with_interior()] {
+        let mut buf = Vec::new();
+        let err = write_tin(
+            &mut buf,
+            &MultiPolygon::new(vec![patch]),
+            &WriteOptions::default(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, WkbError::General(_)), "{err}");
+    }
+}
+
+#[test]
+fn round_trip_surfaces() {
+    let triangle = triangle_z([0., 0., 0.], [1., 0., 0.], [0., 1., 1.]);
+    let nested = wkb_container(1007, &[tin_z(), triangle.clone()]);
+    for buf in [tin_z(), polyhedral_surface_z(), triangle, nested] {
+        let wkb = read_wkb(&buf).unwrap();
+        assert_eq!(wkb_size(&wkb, &WriteOptions::default()), buf.len());
+
+        let mut big_endian = Vec::new();
+        let options = WriteOptions {
+            endianness: Endianness::BigEndian,
+            ..Default::default()
+        };
+        write_wkb(&mut big_endian, &wkb, &options).unwrap();
+
+        let retour = read_wkb(&big_endian).unwrap();
+        assert_eq!(retour.geometry_type(), wkb.geometry_type());
+
+        let mut little_endian = Vec::new();
+        write_wkb(&mut little_endian, &retour, &WriteOptions::default()).unwrap();
+        assert_eq!(little_endian, buf);
+    }
+}
@@ -0,0 +1,243 @@
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_traits::{GeometryTrait, GeometryType, PointTrait};
+use geo_types::{line_string, Geometry, GeometryCollection};
+
+use crate::error::WkbError;
+use crate::hex::decode_hex;
+use crate::reader::{read_wkb, Dimension, ReadLimit};
+use crate::twkb::{read_twkb, write_twkb, write_twkb_with_ids, TwkbBbox, TwkbWriteOptions};
+
+use super::data::*;
+
+fn write(geom: &impl GeometryTrait<T = f64>, options: &TwkbWriteOptions) -> Vec<u8> {
+    let mut twkb = Vec::new();
+    write_twkb(&mut twkb, geom, options).unwrap();
+    twkb
+}
+
+#[test]
+fn postgis_examples() {
+    // SELECT ST_AsTWKB('POINT(1 2)'::geometry)
+    let point = geo_types::point!(x: 1., y: 2.);
+    assert_eq!(write(&point, &Default::default()), [0x01, 0x00, 0x02, 0x04]);
+
+    // SELECT ST_AsTWKB('LINESTRING(1 1,5 5)'::geometry, 0, 0, 0, true, true)
+    let expected = decode_hex("020309020802080202020808").unwrap();
+    let line_string = line_string![(x: 1., y: 1.), (x: 5., y: 5.)];
+    let options = TwkbWriteOptions {
+        include_size: true,
+        include_bbox: true,
+        ..Default::default()
+    };
+    assert_eq!(write(&line_string, &options), expected);
+
+    let mut buf = Vec::new();
+    let geometry = read_twkb(&expected, &mut buf).unwrap();
+    assert_eq!(geometry.precision(), 0);
+    assert_eq!(geometry.size(), Some(9));
+    assert_eq!(
+        geometry.bbox(),
+        Some(&TwkbBbox {
+            min_x: 1.,
+            max_x: 5.,
+            min_y: 1.,
+            max_y: 5.,
+            z_range: None,
+            m_range: None,
+        })
+    );
+    assert_eq!(
+        geometry.wkb().to_geometry(),
+        Geometry::LineString(line_string)
+    );
+}
+
+#[test]
+fn round_trip() {
+    let mut buf = Vec::new();
+    for include_size in [false, true] {
+        for include_bbox in [false, true] {
+            let options = TwkbWriteOptions {
+                include_size,
+                include_bbox,
+                ..Default::default()
+            };
+            for geom in geometries_2d() {
+                let twkb = write(&geom, &options);
+                let geometry = read_twkb(&twkb, &mut buf).unwrap();
+                assert_eq!(geometry.size().is_some(), include_size);
+                assert_eq!(geometry.bbox().is_some(), include_bbox);
+                assert_eq!(geometry.into_wkb().to_geometry(), geom);
+            }
+        }
+    }
+}
+
+#[test]
+fn precision() {
+    let line_string = line_string![(x: 1.234, y: -5.678), (x: 123.456, y: 0.)];
+    let mut buf = Vec::new();
+
+    let options = TwkbWriteOptions {
+        precision: 2,
+        ..Default::default()
+    };
+    let geometry = read_twkb(&write(&line_string, &options), &mut buf).unwrap();
+    assert_eq!(geometry.precision(), 2);
+    assert_eq!(
+        geometry.wkb().to_geometry(),
+        Geometry::LineString(line_string![
+            (x: 1.23, y: -5.68),
+            (x: 123.46, y: 0.)
+        ])
+    );
+
+    let options = TwkbWriteOptions {
+        precision: -1,
+        ..Default::default()
+    };
+    let geometry = read_twkb(&write(&line_string, &options), &mut buf).unwrap();
+    assert_eq!(geometry.precision(), -1);
+    assert_eq!(
+        geometry.wkb().to_geometry(),
+        Geometry::LineString(line_string![(x: 0., y: -10.), (x: 120., y: 0.)])
+    );
+
+    let options = TwkbWriteOptions {
+        precision: 8,
+        ..Default::default()
+    };
+    let mut twkb = Vec::new();
+    assert!(write_twkb(&mut twkb, &line_string, &options).is_err());
+}
+
+#[test]
+fn z_and_m() {
+    let wkb_buf = line_string_zm_wkb(&[[1.5, 2., 3.25, 4.], [-1., 5., -3., 8.125]]);
+    let wkb = read_wkb(&wkb_buf).unwrap();
+    let options = TwkbWriteOptions {
+        precision: 1,
+        z_precision: 2,
+        m_precision: 3,
+        include_bbox: true,
+        ..Default::default()
+    };
+
+    let mut buf = Vec::new();
+    let geometry = read_twkb(&write(&wkb, &options), &mut buf).unwrap();
+    assert_eq!(geometry.z_precision(), Some(2));
+    assert_eq!(geometry.m_precision(), Some(3));
+    assert_eq!(
+        geometry.bbox(),
+        Some(&TwkbBbox {
+            min_x: -1.,
+            max_x: 1.5,
+            min_y: 2.,
+            max_y: 5.,
+            z_range: Some((-3., 3.25)),
+            m_range: Some((4., 8.125)),
+        })
+    );
+    assert_eq!(geometry.wkb().dimension(), Dimension::Xyzm);
+    assert_eq!(geometry.wkb().buf(), wkb_buf.as_slice());
+}
+
+#[test]
+fn id_lists() {
+    let geom = Geometry::MultiPoint(multi_point_2d());
+    let mut twkb = Vec::new();
+    write_twkb_with_ids(&mut twkb, &geom, &[7, -300], &Default::default()).unwrap();
+
+    let mut buf = Vec::new();
+    let geometry = read_twkb(&twkb, &mut buf).unwrap();
+    assert_eq!(geometry.ids(), Some([7, -300].as_slice()));
+    assert_eq!(geometry.wkb().to_geometry(), geom);
+
+    let err = write_twkb_with_ids(&mut twkb, &geom, &[1], &Default::default()).unwrap_err();
+    assert!(matches!(err, WkbError::General(_)));
+    let err = write_twkb_with_ids(&mut twkb, &point_2d(), &[1], &Default::default()).unwrap_err();
+    assert!(matches!(err, WkbError::General(_)));
+}
+
+#[test]
+fn empty_geometries() {
+    let mut buf = Vec::new();
+
+    let empty_collection =
+        Geometry::GeometryCollection(GeometryCollection::<f64>::new_from(vec![]));
+    let twkb = write(&empty_collection, &Default::default());
+    // Only the type and metadata bytes, with the empty flag set
+    assert_eq!(twkb, [0x07, 0x10]);
+    let geometry = read_twkb(&twkb, &mut buf).unwrap();
+    assert_eq!(geometry.wkb().to_geometry(), empty_collection);
+
+    let geometry = read_twkb(&[0x01, 0x10], &mut buf).unwrap();
+    let GeometryType::Point(point) = geometry.wkb().as_type() else {
+        panic!("expected a Point");
+    };
+    assert!(point.coord().is_none());
+}
+
+#[test]
+fn invalid_twkb() {
+    let mut buf = Vec::new();
+    let twkb = write(&polygon_2d_with_interior(), &Default::default());
+
+    let mut invalid = twkb.clone();
+    invalid[0] = 0x08;
+    let err = read_twkb(&invalid, &mut buf).unwrap_err();
+    assert!(matches!(
+        err,
+        WkbError::UnknownTypeCode {
+            code: 8,
+            offset: 0,
+            ..
+        }
+    ));
+
+    let err = read_twkb(&twkb[..twkb.len() - 1], &mut buf).unwrap_err();
+    assert!(matches!(err, WkbError::CountOverflow { count: 5, .. }));
+    assert_eq!(err.path().unwrap().to_string(), "Polygon.rings[1]");
+
+    // A varint cut off after its continuation bit
+    let err = read_twkb(&[0x01, 0x00, 0x02, 0x84], &mut buf).unwrap_err();
+    assert!(matches!(err, WkbError::UnexpectedEof { offset: 4, .. }));
+    assert_eq!(err.path().unwrap().to_string(), "Point");
+
+    // A count that cannot fit in the remaining bytes
+    let err = read_twkb(&[0x02, 0x00, 0xFF, 0x01], &mut buf).unwrap_err();
+    assert!(matches!(err, WkbError::CountOverflow { count: 255, .. }));
+
+    // A varint longer than 64 bits
+    let mut invalid = vec![0x01, 0x00];
+    invalid.extend_from_slice(&[0xFF; 10]);
+    invalid.push(0x01);
+    let err = read_twkb(&invalid, &mut buf).unwrap_err();
+    assert!(matches!(err, WkbError::InvalidGeometry { offset: 2, .. }));
+
+    // A size that does not match the geometry
+    let options = TwkbWriteOptions {
+        include_size: true,
+        ..Default::default()
+    };
+    let mut invalid = write(&linestring_2d(), &options);
+    invalid[2] += 1;
+    invalid.push(0x00);
+    let err = read_twkb(&invalid, &mut buf).unwrap_err();
+    assert!(matches!(err, WkbError::InvalidGeometry { offset: 3, .. }));
+}
+
+#[test]
+fn nesting_is_limited() {
+    // Geometry collections that each hold one more geometry collection
+    let twkb = [0x07, 0x00, 0x01].repeat(1000);
+    let mut buf = Vec::new();
+    let err = read_twkb(&twkb, &mut buf).unwrap_err();
+    assert!(matches!(
+        err,
+        WkbError::LimitExceeded {
+            limit: ReadLimit::Depth,
+            ..
+        }
+    ));
+}
@@ -1,8 +1,11 @@
 use geo_traits::to_geo::ToGeoGeometry;
-use geo_traits::{CoordTrait, GeometryTrait, LineStringTrait, PointTrait, PolygonTrait};
-use geo_types::Geometry;
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, PointTrait,
+    PolygonTrait,
+};
+use geo_types::{Geometry, GeometryCollection, LineString, MultiPolygon, Polygon};
 
-use crate::reader::read_wkb;
+use crate::reader::{read_wkb, LazyGeometryCollection};
 use crate::writer::{
     write_geometry, write_geometry_collection, write_line_string, write_multi_line_string,
     write_multi_point, write_multi_polygon, write_point, write_polygon, WriteOptions,
@@ -20,10 +23,12 @@ fn round_trip_point() {
         &orig,
         &WriteOptions {
             endianness: Endianness::LittleEndian,
+            ..Default::default()
         },
     )
     .unwrap();
     let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), None);
     assert_eq!(Geometry::Point(orig), retour.to_geometry());
 
     // Big endian
@@ -33,6 +38,7 @@ fn round_trip_point() {
         &orig,
         &WriteOptions {
             endianness: Endianness::BigEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -50,6 +56,7 @@ fn round_trip_line_string() {
         &orig,
         &WriteOptions {
             endianness: Endianness::LittleEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -63,6 +70,7 @@ fn round_trip_line_string() {
         &orig,
         &WriteOptions {
             endianness: Endianness::BigEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -80,6 +88,7 @@ fn round_trip_polygon() {
         &orig,
         &WriteOptions {
             endianness: Endianness::LittleEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -93,6 +102,7 @@ fn round_trip_polygon() {
         &orig,
         &WriteOptions {
             endianness: Endianness::BigEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -110,6 +120,7 @@ fn round_trip_polygon_with_interior() {
         &orig,
         &WriteOptions {
             endianness: Endianness::LittleEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -123,6 +134,7 @@ fn round_trip_polygon_with_interior() {
         &orig,
         &WriteOptions {
             endianness: Endianness::BigEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -140,6 +152,7 @@ fn round_trip_multi_point() {
         &orig,
         &WriteOptions {
             endianness: Endianness::LittleEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -153,6 +166,7 @@ fn round_trip_multi_point() {
         &orig,
         &WriteOptions {
             endianness: Endianness::BigEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -170,6 +184,7 @@ fn round_trip_multi_line_string() {
         &orig,
         &WriteOptions {
             endianness: Endianness::LittleEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -186,6 +201,7 @@ fn round_trip_multi_line_string() {
         &orig,
         &WriteOptions {
             endianness: Endianness::BigEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -203,6 +219,7 @@ fn round_trip_multi_polygon() {
         &orig,
         &WriteOptions {
             endianness: Endianness::LittleEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -216,6 +233,7 @@ fn round_trip_multi_polygon() {
         &orig,
         &WriteOptions {
             endianness: Endianness::BigEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -233,6 +251,7 @@ fn round_trip_geometry_collection() {
         &orig,
         &WriteOptions {
             endianness: Endianness::LittleEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -249,6 +268,7 @@ fn round_trip_geometry_collection() {
         &orig,
         &WriteOptions {
             endianness: Endianness::BigEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -265,6 +285,7 @@ fn wkb_point_coord() {
         &p,
         &WriteOptions {
             endianness: Endianness::LittleEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -291,6 +312,7 @@ fn wkb_linestring_coords() {
         &ls,
         &WriteOptions {
             endianness: Endianness::LittleEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -325,6 +347,7 @@ fn wkb_polygon_coords() {
         &poly,
         &WriteOptions {
             endianness: Endianness::LittleEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -412,6 +435,7 @@ fn test_wkb_buf_with_trailing_data(g: &Geometry) {
         g,
         &WriteOptions {
             endianness: Endianness::LittleEndian,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -466,3 +490,243 @@ fn wkb_multi_polygon_buf_with_trailing_data() {
 fn wkb_geometry_collection_buf_with_trailing_data() {
     test_wkb_buf_with_trailing_data(&Geometry::GeometryCollection(geometry_collection_2d()));
 }
+
+#[test]
+fn decode_coords() {
+    for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+        let options = WriteOptions {
+            endianness,
+            ..Default::default()
+        };
+
+        let mut buf = Vec::new();
+        write_line_string(&mut buf, &linestring_2d(), &options).unwrap();
+        let wkb = read_wkb(&buf).unwrap();
+        let geo_traits::GeometryType::LineString(line_string) = wkb.as_type() else {
+            panic!("expected a LineString");
+        };
+        // Any values after the coordinates are left untouched
+        let mut out = [-1.; 6];
+        assert_eq!(line_string.decode_coords(&mut out), 4);
+        assert_eq!(out, [0., 1., 1., 2., -1., -1.]);
+
+        let mut buf = Vec::new();
+        write_polygon(&mut buf, &polygon_2d_with_interior(), &options).unwrap();
+        let wkb = read_wkb(&buf).unwrap();
+        let geo_traits::GeometryType::Polygon(polygon) = wkb.as_type() else {
+            panic!("expected a Polygon");
+        };
+        let mut out = [0.; 10];
+        let interior = polygon.interior(0).unwrap();
+        assert_eq!(interior.decode_coords(&mut out), 10);
+        assert_eq!(
+            out,
+            [-110., 44., -110., 42., -105., 42., -105., 44., -110., 44.]
+        );
+        let coords = interior.coords().flat_map(|c| [c.x(), c.y()]);
+        assert!(coords.eq(out));
+    }
+
+    let mut buf = Vec::new();
+    let wkb = crate::wkt::read_wkt("LINESTRING ZM (1 2 3 4,5 6 7 8)", &mut buf).unwrap();
+    let geo_traits::GeometryType::LineString(line_string) = wkb.as_type() else {
+        panic!("expected a LineString");
+    };
+    let mut out = [0.; 8];
+    assert_eq!(line_string.decode_coords(&mut out), 8);
+    assert_eq!(out, [1., 2., 3., 4., 5., 6., 7., 8.]);
+}
+
+#[test]
+#[should_panic(expected = "too short")]
+fn decode_coords_short_buffer() {
+    let mut buf = Vec::new();
+    write_line_string(&mut buf, &linestring_2d(), &Default::default()).unwrap();
+    let wkb = read_wkb(&buf).unwrap();
+    let geo_traits::GeometryType::LineString(line_string) = wkb.as_type() else {
+        panic!("expected a LineString");
+    };
+    line_string.decode_coords(&mut [0.; 3]);
+}
+
+#[test]
+fn write_coords_interleaved() {
+    for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+        let options = WriteOptions {
+            endianness,
+            ..Default::default()
+        };
+        let (point, line_string, polygon) = (point_2d(), linestring_2d(), polygon_2d());
+        let collection = GeometryCollection::new_from(vec![
+            Geometry::Point(point),
+            Geometry::LineString(line_string.clone()),
+            Geometry::Polygon(polygon.clone()),
+        ]);
+        let mut buf = Vec::new();
+        write_geometry(&mut buf, &collection, &options).unwrap();
+        let wkb = read_wkb(&buf).unwrap();
+
+        // Existing values are kept
+        let mut out = vec![-1.];
+        wkb.write_coords_interleaved(&mut out);
+        let coords = std::iter::once(point.0)
+            .chain(line_string.0)
+            .chain(polygon.exterior().0.clone());
+        let expected = std::iter::once(-1.).chain(coords.flat_map(|c| [c.x, c.y]));
+        assert!(out.into_iter().eq(expected));
+    }
+
+    let mut buf = Vec::new();
+    let wkb = crate::wkt::read_wkt(
+        "GEOMETRYCOLLECTION Z (POINT Z (1 2 3),MULTIPOINT Z ((4 5 6),EMPTY))",
+        &mut buf,
+    )
+    .unwrap();
+    let mut out = Vec::new();
+    wkb.write_coords_interleaved(&mut out);
+    assert_eq!(out[..6], [1., 2., 3., 4., 5., 6.]);
+    assert_eq!(out.len(), 9);
+    assert!(out[6..].iter().all(|value| value.is_nan()));
+}
+
+#[test]
+fn write_coords_separated() {
+    let mut buf = Vec::new();
+    let wkb = crate::wkt::read_wkt(
+        "COMPOUNDCURVE M ((0 0 1,1 1 2),CIRCULARSTRING M (1 1 2,2 0 3,3 1 4))",
+        &mut buf,
+    )
+    .unwrap();
+    let mut out: [Vec<f64>; 4] = Default::default();
+    wkb.write_coords_separated(&mut out);
+    assert_eq!(
+        out,
+        [
+            vec![0., 1., 1., 2., 3.],
+            vec![0., 1., 1., 0., 1.],
+            vec![],
+            vec![1., 2., 2., 3., 4.]
+        ]
+    );
+
+    let mut buf = Vec::new();
+    write_polygon(
+        &mut buf,
+        &polygon_2d_with_interior(),
+        &WriteOptions {
+            endianness: Endianness::BigEndian,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let wkb = read_wkb(&buf).unwrap();
+    let geo_traits::GeometryType::Polygon(polygon) = wkb.as_type() else {
+        panic!("expected a Polygon");
+    };
+    let mut out: [Vec<f64>; 4] = Default::default();
+    polygon
+        .interior(0)
+        .unwrap()
+        .write_coords_separated(&mut out);
+    assert_eq!(out[0], [-110., -110., -105., -105., -110.]);
+    assert_eq!(out[1], [44., 42., 42., 44., 44.]);
+
+    // Every ring of the polygon, in order
+    let mut out: [Vec<f64>; 4] = Default::default();
+    polygon.write_coords_separated(&mut out);
+    assert_eq!(out[0].len(), 10);
+    assert_eq!(out[0][5..], [-110., -110., -105., -105., -110.]);
+
+    // A run longer than a single decoded block, in both byte orders
+    let coords = (0..500)
+        .map(|i| geo_types::coord! { x: i as f64, y: -(i as f64) })
+        .collect::<Vec<_>>();
+    let line_string = LineString::new(coords);
+    for endianness in [Endianness::BigEndian, Endianness::LittleEndian] {
+        let mut buf = Vec::new();
+        write_line_string(
+            &mut buf,
+            &line_string,
+            &WriteOptions {
+                endianness,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let mut out: [Vec<f64>; 4] = Default::default();
+        read_wkb(&buf).unwrap().write_coords_separated(&mut out);
+        assert_eq!(out[0], (0..500).map(|i| i as f64).collect::<Vec<_>>());
+        assert_eq!(out[1], (0..500).map(|i| -(i as f64)).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn polygons_beyond_inline_capacity() {
+    let square =
+        |x: f64| LineString::from(vec![(x, 0.), (x + 1., 0.), (x + 1., 1.), (x, 1.), (x, 0.)]);
+    let interiors = (1..7).map(|i| square(i as f64 * 2.)).collect::<Vec<_>>();
+    let polygon = Polygon::new(square(0.), interiors);
+    let multi_polygon = MultiPolygon::new(vec![polygon.clone(); 10]);
+
+    for orig in [
+        Geometry::Polygon(polygon.clone()),
+        Geometry::MultiPolygon(multi_polygon),
+    ] {
+        let mut buf = Vec::new();
+        write_geometry(&mut buf, &orig, &Default::default()).unwrap();
+        let wkb = read_wkb(&buf).unwrap();
+        assert_eq!(wkb.size(), buf.len() as u64);
+        assert_eq!(wkb.to_geometry(), orig);
+    }
+
+    let mut buf = Vec::new();
+    write_polygon(&mut buf, &polygon, &Default::default()).unwrap();
+    let wkb = read_wkb(&buf).unwrap();
+    let GeometryType::Polygon(wkb) = wkb.as_type() else {
+        unreachable!()
+    };
+    assert_eq!(wkb.num_interiors(), 6);
+    let last = wkb.interior(5).unwrap();
+    assert_eq!(last.num_coords(), 5);
+    assert_eq!(last.coord(0).unwrap().x(), 12.);
+    assert!(wkb.interior(6).is_none());
+}
+
+#[test]
+fn lazy_geometry_collection() {
+    let orig = geometry_collection_2d();
+    // More members than are kept inline
+    let points = GeometryCollection::new_from(vec![Geometry::Point(point_2d()); 10]);
+    let nested = GeometryCollection::new_from(vec![
+        Geometry::GeometryCollection(points),
+        Geometry::GeometryCollection(orig.clone()),
+    ]);
+    for orig in [orig, nested] {
+        let mut buf = Vec::new();
+        write_geometry(&mut buf, &orig, &Default::default()).unwrap();
+        let lazy = LazyGeometryCollection::try_new(&buf).unwrap();
+        assert_eq!(lazy.size(), buf.len() as u64);
+        assert_eq!(lazy.num_geometries(), orig.0.len());
+        assert_eq!(
+            lazy.to_geometry(),
+            Geometry::GeometryCollection(orig.clone())
+        );
+
+        // Random access and reverse iteration agree with forward iteration
+        let mut backward = lazy
+            .geometries()
+            .rev()
+            .map(|g| g.to_geometry())
+            .collect::<Vec<_>>();
+        backward.reverse();
+        assert_eq!(backward, orig.0);
+        for (i, geom) in orig.0.iter().enumerate() {
+            assert_eq!(&lazy.geometry(i).unwrap().to_geometry(), geom);
+        }
+        assert!(lazy.geometry(orig.0.len()).is_none());
+
+        let mut retour = Vec::new();
+        write_geometry(&mut retour, &lazy, &Default::default()).unwrap();
+        assert_eq!(retour, buf);
+    }
+}
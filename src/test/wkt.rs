@@ -0,0 +1,345 @@
+use geo_types::{line_string, Geometry, GeometryCollection, MultiPoint};
+use wkt::TryFromWkt;
+
+use crate::error::WkbError;
+use crate::reader::{read_wkb, Dimension, ReadLimit};
+use crate::wkt::{read_wkt, wkt_to_wkb, WktOptions};
+use crate::writer::{write_geometry, WkbFlavor, WriteOptions};
+use crate::Endianness;
+
+use super::data::*;
+
+fn to_wkt(geom: &Geometry) -> String {
+    let mut buf = Vec::new();
+    write_geometry(&mut buf, geom, &Default::default()).unwrap();
+    read_wkb(&buf).unwrap().to_string()
+}
+
+#[test]
+fn basic_geometries() {
+    assert_eq!(to_wkt(&Geometry::Point(point_2d())), "POINT(0 1)");
+    assert_eq!(
+        to_wkt(&Geometry::LineString(
+            line_string![(x: 1., y: -2.5), (x: 1e-7, y: 3e20)]
+        )),
+        "LINESTRING(1 -2.5,0.0000001 300000000000000000000)"
+    );
+    assert_eq!(
+        to_wkt(&Geometry::MultiPoint(MultiPoint::from(vec![
+            (1., 2.),
+            (3., 4.)
+        ]))),
+        "MULTIPOINT((1 2),(3 4))"
+    );
+}
+
+#[test]
+fn output_parses_as_the_same_geometry() {
+    let geometries = [
+        Geometry::Point(point_2d()),
+        Geometry::LineString(linestring_2d()),
+        Geometry::Polygon(polygon_2d_with_interior()),
+        Geometry::MultiPoint(multi_point_2d()),
+        Geometry::MultiLineString(multi_line_string_2d()),
+        Geometry::MultiPolygon(multi_polygon_2d()),
+        Geometry::GeometryCollection(geometry_collection_2d()),
+    ];
+    for geom in geometries {
+        let wkt = to_wkt(&geom);
+        assert_eq!(Geometry::try_from_wkt_str(&wkt).unwrap(), geom, "{wkt}");
+    }
+}
+
+#[test]
+fn dimension_tags() {
+    let point_z = wkb_point(1001, &[1., 2., 3.]);
+    assert_eq!(read_wkb(&point_z).unwrap().to_string(), "POINT Z (1 2 3)");
+
+    let line_string_m = wkb_point_sequence(2002, 3, &[1., 2., 3., 4., 5., 6.]);
+    assert_eq!(
+        read_wkb(&line_string_m).unwrap().to_string(),
+        "LINESTRING M (1 2 3,4 5 6)"
+    );
+
+    let line_string_zm = wkb_point_sequence(3002, 4, &[1., 2., 3., 4.]);
+    let collection = wkb_container(3007, &[line_string_zm]);
+    assert_eq!(
+        read_wkb(&collection).unwrap().to_string(),
+        "GEOMETRYCOLLECTION ZM (LINESTRING ZM (1 2 3 4))"
+    );
+}
+
+#[test]
+fn empty_geometries() {
+    let empty_point = wkb_point(1, &[f64::NAN, f64::NAN]);
+    assert_eq!(read_wkb(&empty_point).unwrap().to_string(), "POINT EMPTY");
+
+    let empty_polygon_z = wkb_container(1003, &[]);
+    assert_eq!(
+        read_wkb(&empty_polygon_z).unwrap().to_string(),
+        "POLYGON Z EMPTY"
+    );
+
+    let multi_point = wkb_container(4, &[empty_point, wkb_point(1, &[1., 2.])]);
+    assert_eq!(
+        read_wkb(&multi_point).unwrap().to_string(),
+        "MULTIPOINT(EMPTY,(1 2))"
+    );
+
+    // A Polygon holding one ring with no points
+    let polygon_with_empty_ring = wkb_container(3, &[0u32.to_le_bytes().to_vec()]);
+    assert_eq!(
+        read_wkb(&polygon_with_empty_ring).unwrap().to_string(),
+        "POLYGON(EMPTY)"
+    );
+
+    let collection = Geometry::GeometryCollection(GeometryCollection::<f64>::new_from(vec![]));
+    assert_eq!(to_wkt(&collection), "GEOMETRYCOLLECTION EMPTY");
+}
+
+#[test]
+fn curves_and_surfaces() {
+    let circular_string = wkb_point_sequence(8, 2, &[0., 0., 1., 1., 2., 0.]);
+    let line_string = wkb_point_sequence(2, 2, &[2., 0., 0., 0.]);
+    let compound_curve = wkb_container(9, &[circular_string.clone(), line_string.clone()]);
+    assert_eq!(
+        read_wkb(&compound_curve).unwrap().to_string(),
+        "COMPOUNDCURVE(CIRCULARSTRING(0 0,1 1,2 0),(2 0,0 0))"
+    );
+
+    let curve_polygon = wkb_container(10, &[compound_curve]);
+    assert_eq!(
+        read_wkb(&curve_polygon).unwrap().to_string(),
+        "CURVEPOLYGON(COMPOUNDCURVE(CIRCULARSTRING(0 0,1 1,2 0),(2 0,0 0)))"
+    );
+
+    let multi_curve = wkb_container(11, &[line_string, circular_string]);
+    assert_eq!(
+        read_wkb(&multi_curve).unwrap().to_string(),
+        "MULTICURVE((2 0,0 0),CIRCULARSTRING(0 0,1 1,2 0))"
+    );
+
+    let ring = wkb_point_sequence(2, 2, &[0., 0., 1., 0., 0., 1., 0., 0.])[5..].to_vec();
+    let polygon = [wkb_header(3), 1u32.to_le_bytes().to_vec(), ring.clone()].concat();
+    let multi_surface = wkb_container(12, &[polygon.clone(), curve_polygon]);
+    assert_eq!(
+        read_wkb(&multi_surface).unwrap().to_string(),
+        "MULTISURFACE(((0 0,1 0,0 1,0 0)),CURVEPOLYGON(COMPOUNDCURVE(CIRCULARSTRING(0 0,1 1,2 0),(2 0,0 0))))"
+    );
+
+    let polyhedral_surface = wkb_container(15, &[polygon.clone(), polygon]);
+    assert_eq!(
+        read_wkb(&polyhedral_surface).unwrap().to_string(),
+        "POLYHEDRALSURFACE(((0 0,1 0,0 1,0 0)),((0 0,1 0,0 1,0 0)))"
+    );
+
+    let triangle = [wkb_header(17), 1u32.to_le_bytes().to_vec(), ring].concat();
+    assert_eq!(
+        read_wkb(&triangle).unwrap().to_string(),
+        "TRIANGLE((0 0,1 0,0 1,0 0))"
+    );
+    let tin = wkb_container(16, &[triangle]);
+    assert_eq!(
+        read_wkb(&tin).unwrap().to_string(),
+        "TIN(((0 0,1 0,0 1,0 0)))"
+    );
+}
+
+#[test]
+fn precision() {
+    let line_string = line_string![(x: 1.23456, y: -0.0001), (x: 10., y: 2.6)];
+    let mut buf = Vec::new();
+    write_geometry(&mut buf, &line_string, &Default::default()).unwrap();
+    let geometry = read_wkb(&buf).unwrap();
+
+    assert_eq!(format!("{geometry:.2}"), "LINESTRING(1.23 0,10 2.6)");
+    assert_eq!(format!("{geometry:.0}"), "LINESTRING(1 0,10 3)");
+    let options = WktOptions {
+        precision: Some(3),
+        ..Default::default()
+    };
+    assert_eq!(
+        geometry.display_wkt(options).to_string(),
+        "LINESTRING(1.235 0,10 2.6)"
+    );
+}
+
+#[test]
+fn srid_prefix() {
+    let options = WriteOptions {
+        flavor: WkbFlavor::Ewkb,
+        srid: Some(4326),
+        ..Default::default()
+    };
+    let mut buf = Vec::new();
+    write_geometry(&mut buf, &point_2d(), &options).unwrap();
+    let geometry = read_wkb(&buf).unwrap();
+
+    let with_srid = WktOptions {
+        include_srid: true,
+        ..Default::default()
+    };
+    assert_eq!(geometry.to_string(), "POINT(0 1)");
+    assert_eq!(
+        geometry.display_wkt(with_srid).to_string(),
+        "SRID=4326;POINT(0 1)"
+    );
+
+    // Geometries without an SRID are written as plain WKT
+    let mut buf = Vec::new();
+    write_geometry(&mut buf, &point_2d(), &Default::default()).unwrap();
+    let geometry = read_wkb(&buf).unwrap();
+    assert_eq!(geometry.display_wkt(with_srid).to_string(), "POINT(0 1)");
+}
+
+fn parse_error(wkt: &str) -> WkbError {
+    let mut buf = Vec::new();
+    read_wkt(wkt, &mut buf).unwrap_err()
+}
+
+#[test]
+fn parse_formatted_output() {
+    let circular_string = wkb_point_sequence(8, 2, &[0., 0., 1., 1., 2., 0.]);
+    let line_string = wkb_point_sequence(2, 2, &[2., 0., 0., 0.]);
+    let compound_curve = wkb_container(9, &[circular_string.clone(), line_string.clone()]);
+    let ring = wkb_point_sequence(2, 2, &[0., 0., 1., 0., 0., 1., 0., 0.])[5..].to_vec();
+    let polygon = [wkb_header(3), 1u32.to_le_bytes().to_vec(), ring.clone()].concat();
+    let triangle = [wkb_header(17), 1u32.to_le_bytes().to_vec(), ring].concat();
+    let empty_point = wkb_point(1, &[f64::NAN, f64::NAN]);
+
+    let geometries = [
+        wkb_point(1001, &[1., 2., 3.]),
+        wkb_point_sequence(2002, 3, &[1., 2., 3., 4., 5., 6.]),
+        wkb_container(3007, &[wkb_point_sequence(3002, 4, &[1., 2., 3., 4.])]),
+        empty_point.clone(),
+        wkb_point(2001, &[f64::NAN, f64::NAN, f64::NAN]),
+        wkb_container(1003, &[]),
+        wkb_container(4, &[empty_point.clone(), wkb_point(1, &[1., 2.])]),
+        // Collection members may differ in dimension
+        wkb_container(
+            7,
+            &[wkb_point(1, &[1., 2.]), wkb_point(1001, &[1., 2., 3.])],
+        ),
+        wkb_container(
+            1007,
+            &[wkb_point(1, &[1., 2.]), wkb_point(1001, &[1., 2., 3.])],
+        ),
+        wkb_container(2007, &[empty_point, wkb_point(2001, &[1., 2., 3.])]),
+        wkb_container(3, &[0u32.to_le_bytes().to_vec()]),
+        wkb_container(10, &[compound_curve.clone(), line_string.clone()]),
+        wkb_container(11, &[line_string, circular_string]),
+        wkb_container(12, &[polygon.clone(), wkb_container(10, &[compound_curve])]),
+        wkb_container(15, &[polygon.clone(), polygon]),
+        wkb_container(16, std::slice::from_ref(&triangle)),
+        triangle,
+    ];
+    let mut buf = Vec::new();
+    for wkb in geometries {
+        let wkt = read_wkb(&wkb).unwrap().to_string();
+        let geometry = read_wkt(&wkt, &mut buf).unwrap();
+        assert_eq!(geometry.buf(), wkb.as_slice(), "{wkt}");
+    }
+}
+
+#[test]
+fn parse_matches_writer() {
+    let geometries = [
+        Geometry::Point(point_2d()),
+        Geometry::LineString(linestring_2d()),
+        Geometry::Polygon(polygon_2d_with_interior()),
+        Geometry::MultiPoint(multi_point_2d()),
+        Geometry::MultiLineString(multi_line_string_2d()),
+        Geometry::MultiPolygon(multi_polygon_2d()),
+        Geometry::GeometryCollection(geometry_collection_2d()),
+    ];
+    let options = WriteOptions {
+        endianness: Endianness::BigEndian,
+        ..Default::default()
+    };
+    for geom in geometries {
+        let mut expected = Vec::new();
+        write_geometry(&mut expected, &geom, &options).unwrap();
+        let mut wkb = Vec::new();
+        wkt_to_wkb(&to_wkt(&geom), &mut wkb, &options).unwrap();
+        assert_eq!(wkb, expected);
+    }
+}
+
+#[test]
+fn parse_syntax_variants() {
+    let mut buf = Vec::new();
+    let geometry = read_wkt("  point zm(1 2 3 4) ", &mut buf).unwrap();
+    assert_eq!(geometry.to_string(), "POINT ZM (1 2 3 4)");
+
+    // PostGIS EWKT attaches the tag to the keyword
+    let geometry = read_wkt("POINTM(1 2 3)", &mut buf).unwrap();
+    assert_eq!(geometry.dimension(), Dimension::Xym);
+
+    // Untagged geometries take their dimension from the first coordinate
+    let geometry = read_wkt("LINESTRING(1 2 3,4 5 6)", &mut buf).unwrap();
+    assert_eq!(geometry.dimension(), Dimension::Xyz);
+    let geometry = read_wkt("MULTIPOINT(EMPTY,1 2 3 4)", &mut buf).unwrap();
+    assert_eq!(geometry.to_string(), "MULTIPOINT ZM (EMPTY,(1 2 3 4))");
+    let geometry = read_wkt("GEOMETRYCOLLECTION(POINT M (1 2 3))", &mut buf).unwrap();
+    assert_eq!(geometry.dimension(), Dimension::Xym);
+
+    let geometry = read_wkt("MULTIPOINT(1 2,-3.5e2 +4)", &mut buf).unwrap();
+    assert_eq!(geometry.to_string(), "MULTIPOINT((1 2),(-350 4))");
+}
+
+#[test]
+fn parse_srid() {
+    let wkt = "SRID=4326;POINT(1 2)";
+    let mut buf = Vec::new();
+    let geometry = read_wkt(wkt, &mut buf).unwrap();
+    assert_eq!(geometry.srid(), Some(4326));
+
+    let mut iso = Vec::new();
+    wkt_to_wkb(wkt, &mut iso, &Default::default()).unwrap();
+    assert_eq!(read_wkb(&iso).unwrap().srid(), None);
+
+    let options = WriteOptions {
+        flavor: WkbFlavor::Ewkb,
+        ..Default::default()
+    };
+    let mut ewkb = Vec::new();
+    wkt_to_wkb(wkt, &mut ewkb, &options).unwrap();
+    assert_eq!(ewkb, buf);
+}
+
+#[test]
+fn parse_errors() {
+    let cases = [
+        ("POINT(1 2", 9),
+        ("POINT Z (1 2)", 12),
+        ("LINESTRING(1 2 3,4 5)", 20),
+        ("POINT(1 2 3 4 5)", 10),
+        ("POINT(1 x)", 8),
+        ("POINT(1 2) x", 11),
+        ("FOO(1 2)", 0),
+        ("POLYGON((0 0,1 1) (0 0))", 18),
+        ("MULTICURVE Z (CIRCULARSTRING M (1 2 3,4 5 6))", 14),
+        ("POINT(nan inf)", 6),
+        ("POINT(1 inf)", 8),
+        ("COMPOUNDCURVE(POLYGON((0 0,1 0,0 0)))", 14),
+        ("SRID=abc;POINT(1 2)", 5),
+        ("", 0),
+    ];
+    for (wkt, offset) in cases {
+        let err = parse_error(wkt);
+        assert!(matches!(err, WkbError::InvalidWkt { .. }), "{wkt}: {err}");
+        assert_eq!(err.offset(), Some(offset), "{wkt}: {err}");
+    }
+}
+
+#[test]
+fn parse_nesting_is_limited() {
+    let wkt = "GEOMETRYCOLLECTION(".repeat(1000);
+    assert!(matches!(
+        parse_error(&wkt),
+        WkbError::LimitExceeded {
+            limit: ReadLimit::Depth,
+            ..
+        }
+    ));
+}
@@ -0,0 +1,803 @@
+//! Read and write geometries in the Tiny Well-Known Binary (TWKB) format.
+//!
+//! TWKB stores coordinates as variable-length integers, scaled by a decimal precision and
+//! delta-encoded against the previous coordinate, which makes it much smaller than WKB. See
+//! [the TWKB specification](https://github.com/TWKB/Specification/blob/master/twkb.md).
+//!
+//! TWKB is read by converting it to little-endian ISO WKB in a caller-provided buffer, so that
+//! the result can be accessed through the same [`Wkb`] type and `geo_traits` implementations as
+//! any other WKB.
+//!
+//! ```
+//! use geo_types::line_string;
+//! use wkb::twkb::{read_twkb, write_twkb, TwkbWriteOptions};
+//!
+//! let line_string = line_string![(x: 1.25, y: 1.5), (x: 5., y: 5.)];
+//! let options = TwkbWriteOptions {
+//!     precision: 2,
+//!     ..Default::default()
+//! };
+//! let mut twkb = Vec::new();
+//! write_twkb(&mut twkb, &line_string, &options).unwrap();
+//!
+//! let mut buf = Vec::new();
+//! let geometry = read_twkb(&twkb, &mut buf).unwrap();
+//! assert_eq!(geometry.precision(), 2);
+//! ```
+
+use std::io::Write;
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
+};
+
+use crate::bounds::Bounds;
+use crate::common::{Dimension, WkbType};
+use crate::error::{PathSegment, WkbError, WkbPath, WkbResult};
+use crate::reader::util::unexpected_eof;
+use crate::reader::{ReadLimit, ReadOptions, Wkb};
+use crate::Endianness;
+
+// Bits of the metadata byte
+const FLAG_BBOX: u8 = 0b0000_0001;
+const FLAG_SIZE: u8 = 0b0000_0010;
+const FLAG_IDLIST: u8 = 0b0000_0100;
+const FLAG_EXTENDED: u8 = 0b0000_1000;
+const FLAG_EMPTY: u8 = 0b0001_0000;
+
+// The TWKB geometry type codes, which match the basic WKB geometry codes
+const POINT: u8 = 1;
+const LINE_STRING: u8 = 2;
+const POLYGON: u8 = 3;
+const MULTI_POINT: u8 = 4;
+const MULTI_LINE_STRING: u8 = 5;
+const MULTI_POLYGON: u8 = 6;
+const GEOMETRY_COLLECTION: u8 = 7;
+
+/// The bounding box stored in a TWKB header
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwkbBbox {
+    /// The minimum x value
+    pub min_x: f64,
+    /// The maximum x value
+    pub max_x: f64,
+    /// The minimum y value
+    pub min_y: f64,
+    /// The maximum y value
+    pub max_y: f64,
+    /// The minimum and maximum z values, if the geometry has z
+    pub z_range: Option<(f64, f64)>,
+    /// The minimum and maximum m values, if the geometry has m
+    pub m_range: Option<(f64, f64)>,
+}
+
+/// A TWKB geometry that has been converted to WKB
+#[derive(Debug, Clone)]
+pub struct TwkbGeometry<'a> {
+    precision: i8,
+    z_precision: Option<u8>,
+    m_precision: Option<u8>,
+    bbox: Option<TwkbBbox>,
+    size: Option<u64>,
+    ids: Option<Vec<i64>>,
+    wkb: Wkb<'a>,
+}
+
+impl<'a> TwkbGeometry<'a> {
+    /// The number of decimal digits of x and y values, which may be negative
+    pub fn precision(&self) -> i8 {
+        self.precision
+    }
+
+    /// The number of decimal digits of z values, if the geometry has z
+    pub fn z_precision(&self) -> Option<u8> {
+        self.z_precision
+    }
+
+    /// The number of decimal digits of m values, if the geometry has m
+    pub fn m_precision(&self) -> Option<u8> {
+        self.m_precision
+    }
+
+    /// The bounding box stored in the header, if any
+    pub fn bbox(&self) -> Option<&TwkbBbox> {
+        self.bbox.as_ref()
+    }
+
+    /// The size stored in the header, if any, which counts the bytes following the size itself
+    pub fn size(&self) -> Option<u64> {
+        self.size
+    }
+
+    /// The ids of the members of a multi geometry or collection, if the header has an id list
+    pub fn ids(&self) -> Option<&[i64]> {
+        self.ids.as_deref()
+    }
+
+    /// The geometry, converted to WKB
+    pub fn wkb(&self) -> &Wkb<'a> {
+        &self.wkb
+    }
+
+    /// Consume this geometry, returning the geometry converted to WKB
+    pub fn into_wkb(self) -> Wkb<'a> {
+        self.wkb
+    }
+}
+
+/// Read a TWKB geometry by converting it to WKB in `buf`.
+///
+/// The previous contents of `buf` are replaced. Errors are reported with offsets into `twkb`.
+/// Nested collections are limited to the default [`ReadOptions::max_depth`].
+pub fn read_twkb<'a>(twkb: &[u8], buf: &'a mut Vec<u8>) -> WkbResult<TwkbGeometry<'a>> {
+    buf.clear();
+    let mut decoder = Decoder {
+        twkb,
+        out: buf,
+        pos: 0,
+        depth: 0,
+        max_depth: ReadOptions::default().max_depth,
+    };
+    let header = decoder.geometry()?;
+
+    Ok(TwkbGeometry {
+        precision: header.precision,
+        z_precision: header.dim().has_z().then_some(header.z_precision),
+        m_precision: header.dim().has_m().then_some(header.m_precision),
+        bbox: header.bbox,
+        size: header.size,
+        ids: header.ids,
+        wkb: Wkb::try_new(buf)?,
+    })
+}
+
+/// The header of a single TWKB geometry
+struct Header {
+    wkb_type: WkbType,
+    precision: i8,
+    z_precision: u8,
+    m_precision: u8,
+    is_empty: bool,
+    has_ids: bool,
+    bbox: Option<TwkbBbox>,
+    size: Option<u64>,
+    /// The offset of the first byte counted by `size`
+    size_start: usize,
+    ids: Option<Vec<i64>>,
+}
+
+impl Header {
+    fn dim(&self) -> Dimension {
+        self.wkb_type.dimension()
+    }
+}
+
+/// The factor that each ordinate is multiplied by before rounding to an integer
+fn scales(dim: Dimension, precision: i8, z_precision: u8, m_precision: u8) -> [f64; 4] {
+    let xy = 10f64.powi(precision.into());
+    let z = 10f64.powi(z_precision.into());
+    let m = 10f64.powi(m_precision.into());
+    match dim {
+        Dimension::Xym => [xy, xy, m, m],
+        _ => [xy, xy, z, m],
+    }
+}
+
+/// The integer values of the previous coordinate, which the next coordinate is relative to
+struct DeltaState {
+    last: [i64; 4],
+    scales: [f64; 4],
+    size: usize,
+}
+
+/// Converts TWKB to little-endian ISO WKB
+struct Decoder<'b> {
+    twkb: &'b [u8],
+    out: &'b mut Vec<u8>,
+    /// The offset of the next unread byte of `twkb`
+    pos: usize,
+    depth: usize,
+    max_depth: usize,
+}
+
+impl Decoder<'_> {
+    fn read_u8(&mut self) -> WkbResult<u8> {
+        let byte = *self
+            .twkb
+            .get(self.pos)
+            .ok_or_else(|| unexpected_eof(self.pos as u64, 1, self.twkb.len()))?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn read_uvarint(&mut self) -> WkbResult<u64> {
+        let start = self.pos;
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.read_u8()?;
+            value |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                // The 10th byte may only hold the single remaining bit
+                if shift == 63 && byte > 1 {
+                    break;
+                }
+                return Ok(value);
+            }
+        }
+        Err(invalid_geometry("varint overflows 64 bits", start))
+    }
+
+    fn read_varint(&mut self) -> WkbResult<i64> {
+        Ok(zigzag_decode(self.read_uvarint()?))
+    }
+
+    /// Read an element count, where every element takes up at least `min_element_size` bytes,
+    /// copying it to the output
+    fn count(&mut self, min_element_size: usize) -> WkbResult<usize> {
+        let offset = self.pos;
+        let count = self.read_uvarint()?;
+        let count = u32::try_from(count)
+            .map_err(|_| invalid_geometry("count does not fit in 32 bits", offset))?;
+        let max = (self.twkb.len() - self.pos) / min_element_size;
+        if count as usize > max {
+            return Err(WkbError::CountOverflow {
+                count,
+                max: max as u64,
+                offset: offset as u64,
+                path: WkbPath::default(),
+            });
+        }
+        self.out.write_u32::<LittleEndian>(count)?;
+        Ok(count as usize)
+    }
+
+    fn header(&mut self) -> WkbResult<Header> {
+        let offset = self.pos;
+        let type_and_precision = self.read_u8()?;
+        let precision = zigzag_decode((type_and_precision >> 4).into()) as i8;
+        let kind = type_and_precision & 0x0F;
+        let metadata = self.read_u8()?;
+
+        let (dim, z_precision, m_precision) = if metadata & FLAG_EXTENDED != 0 {
+            let extended = self.read_u8()?;
+            let dim = match (extended & 0b01 != 0, extended & 0b10 != 0) {
+                (false, false) => Dimension::Xy,
+                (true, false) => Dimension::Xyz,
+                (false, true) => Dimension::Xym,
+                (true, true) => Dimension::Xyzm,
+            };
+            (dim, (extended >> 2) & 0b111, extended >> 5)
+        } else {
+            (Dimension::Xy, 0, 0)
+        };
+
+        let wkb_type = match kind {
+            POINT => WkbType::Point(dim),
+            LINE_STRING => WkbType::LineString(dim),
+            POLYGON => WkbType::Polygon(dim),
+            MULTI_POINT => WkbType::MultiPoint(dim),
+            MULTI_LINE_STRING => WkbType::MultiLineString(dim),
+            MULTI_POLYGON => WkbType::MultiPolygon(dim),
+            GEOMETRY_COLLECTION => WkbType::GeometryCollection(dim),
+            _ => {
+                return Err(WkbError::UnknownTypeCode {
+                    code: kind.into(),
+                    offset: offset as u64,
+                    path: WkbPath::default(),
+                })
+            }
+        };
+
+        let size = if metadata & FLAG_SIZE != 0 {
+            Some(self.read_uvarint()?)
+        } else {
+            None
+        };
+        let size_start = self.pos;
+
+        let bbox = if metadata & FLAG_BBOX != 0 {
+            let scales = scales(dim, precision, z_precision, m_precision);
+            let mut ranges = [(0., 0.); 4];
+            for (range, scale) in ranges[..dim.size()].iter_mut().zip(scales) {
+                let min = self.read_varint()?;
+                let delta = self.read_varint()?;
+                *range = (min as f64 / scale, min.wrapping_add(delta) as f64 / scale);
+            }
+            Some(TwkbBbox {
+                min_x: ranges[0].0,
+                max_x: ranges[0].1,
+                min_y: ranges[1].0,
+                max_y: ranges[1].1,
+                z_range: dim.has_z().then_some(ranges[2]),
+                m_range: match dim {
+                    Dimension::Xym => Some(ranges[2]),
+                    Dimension::Xyzm => Some(ranges[3]),
+                    _ => None,
+                },
+            })
+        } else {
+            None
+        };
+
+        Ok(Header {
+            wkb_type,
+            precision,
+            z_precision,
+            m_precision,
+            is_empty: metadata & FLAG_EMPTY != 0,
+            has_ids: metadata & FLAG_IDLIST != 0,
+            bbox,
+            size,
+            size_start,
+            ids: None,
+        })
+    }
+
+    /// Convert a geometry starting at its header
+    fn geometry(&mut self) -> WkbResult<Header> {
+        if self.depth >= self.max_depth {
+            return Err(WkbError::LimitExceeded {
+                limit: ReadLimit::Depth,
+                max: self.max_depth,
+                offset: self.pos as u64,
+                path: WkbPath::default(),
+            });
+        }
+
+        let mut header = self.header()?;
+        let segment = PathSegment::Geometry(header.wkb_type.geometry_type());
+        self.depth += 1;
+        let result = self.body(&mut header);
+        self.depth -= 1;
+        result.map_err(|e| e.nested(0, Some(segment)))?;
+
+        if let Some(size) = header.size {
+            if (self.pos - header.size_start) as u64 != size {
+                return Err(invalid_geometry(
+                    "size does not match the geometry",
+                    header.size_start,
+                ));
+            }
+        }
+        Ok(header)
+    }
+
+    fn body(&mut self, header: &mut Header) -> WkbResult<()> {
+        let dim = header.dim();
+        self.out.push(Endianness::LittleEndian.into());
+        self.out.write_u32::<LittleEndian>(header.wkb_type.into())?;
+
+        if header.is_empty {
+            if let WkbType::Point(_) = header.wkb_type {
+                // Write POINT EMPTY as NaN values, as in WKB
+                for _ in 0..dim.size() {
+                    self.out.write_f64::<LittleEndian>(f64::NAN)?;
+                }
+            } else {
+                self.out.write_u32::<LittleEndian>(0)?;
+            }
+            return Ok(());
+        }
+
+        let mut state = DeltaState {
+            last: [0; 4],
+            scales: scales(
+                dim,
+                header.precision,
+                header.z_precision,
+                header.m_precision,
+            ),
+            size: dim.size(),
+        };
+        match header.wkb_type {
+            WkbType::Point(_) => self.coord(&mut state),
+            WkbType::LineString(_) => self.coords(&mut state),
+            WkbType::Polygon(_) => self.polygon(&mut state),
+            WkbType::GeometryCollection(_) => {
+                // Each member has at least a type and metadata byte
+                let num_geometries = self.count(2)?;
+                header.ids = self.ids(header.has_ids, num_geometries)?;
+                for i in 0..num_geometries {
+                    self.geometry()
+                        .map_err(|e| e.nested(0, Some(PathSegment::Member(i))))?;
+                }
+                Ok(())
+            }
+            multi_type => {
+                // Members of typed multi geometries have no header and share the delta state
+                let (member_type, min_member_size) = match multi_type {
+                    WkbType::MultiPoint(_) => (WkbType::Point(dim), dim.size()),
+                    WkbType::MultiLineString(_) => (WkbType::LineString(dim), 1),
+                    _ => (WkbType::Polygon(dim), 1),
+                };
+                let num_members = self.count(min_member_size)?;
+                header.ids = self.ids(header.has_ids, num_members)?;
+                for i in 0..num_members {
+                    self.out.push(Endianness::LittleEndian.into());
+                    self.out.write_u32::<LittleEndian>(member_type.into())?;
+                    match member_type {
+                        WkbType::Point(_) => self.coord(&mut state),
+                        WkbType::LineString(_) => self.coords(&mut state),
+                        _ => self.polygon(&mut state),
+                    }
+                    .map_err(|e| e.nested(0, Some(PathSegment::Member(i))))?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn ids(&mut self, has_ids: bool, count: usize) -> WkbResult<Option<Vec<i64>>> {
+        if !has_ids {
+            return Ok(None);
+        }
+        // The count has been checked against the remaining bytes, and each id takes at least one
+        (0..count)
+            .map(|_| self.read_varint())
+            .collect::<WkbResult<_>>()
+            .map(Some)
+    }
+
+    fn polygon(&mut self, state: &mut DeltaState) -> WkbResult<()> {
+        let num_rings = self.count(1)?;
+        for i in 0..num_rings {
+            self.coords(state)
+                .map_err(|e| e.nested(0, Some(PathSegment::Field("rings", i))))?;
+        }
+        Ok(())
+    }
+
+    fn coords(&mut self, state: &mut DeltaState) -> WkbResult<()> {
+        let num_coords = self.count(state.size)?;
+        self.out.reserve(num_coords * state.size * 8);
+        for i in 0..num_coords {
+            self.coord(state)
+                .map_err(|e| e.nested(0, Some(PathSegment::Field("coords", i))))?;
+        }
+        Ok(())
+    }
+
+    fn coord(&mut self, state: &mut DeltaState) -> WkbResult<()> {
+        for i in 0..state.size {
+            let delta = self.read_varint()?;
+            state.last[i] = state.last[i].wrapping_add(delta);
+            self.out
+                .write_f64::<LittleEndian>(state.last[i] as f64 / state.scales[i])?;
+        }
+        Ok(())
+    }
+}
+
+/// Options for writing geometries as TWKB
+#[derive(Debug, Clone, Default)]
+pub struct TwkbWriteOptions {
+    /// The number of decimal digits to keep for x and y values, from -8 to 7.
+    ///
+    /// Negative values round to tens, hundreds, and so on. The default of 0 rounds to integers.
+    pub precision: i8,
+
+    /// The number of decimal digits to keep for z values, from 0 to 7
+    pub z_precision: u8,
+
+    /// The number of decimal digits to keep for m values, from 0 to 7
+    pub m_precision: u8,
+
+    /// Whether to write the size of the geometry in its header, so that readers can skip it
+    pub include_size: bool,
+
+    /// Whether to write the bounding box of the geometry in its header
+    pub include_bbox: bool,
+}
+
+/// Write a geometry as TWKB.
+///
+/// Lines are written as LineStrings, and Rects and Triangles as Polygons.
+pub fn write_twkb(
+    writer: &mut impl Write,
+    geom: &impl GeometryTrait<T = f64>,
+    options: &TwkbWriteOptions,
+) -> WkbResult<()> {
+    write_twkb_impl(writer, geom, None, options)
+}
+
+/// Write a multi geometry or geometry collection as TWKB with an id for each of its members.
+///
+/// The number of ids must match the number of members.
+pub fn write_twkb_with_ids(
+    writer: &mut impl Write,
+    geom: &impl GeometryTrait<T = f64>,
+    ids: &[i64],
+    options: &TwkbWriteOptions,
+) -> WkbResult<()> {
+    write_twkb_impl(writer, geom, Some(ids), options)
+}
+
+fn write_twkb_impl(
+    writer: &mut impl Write,
+    geom: &impl GeometryTrait<T = f64>,
+    ids: Option<&[i64]>,
+    options: &TwkbWriteOptions,
+) -> WkbResult<()> {
+    if !(-8..=7).contains(&options.precision) {
+        return Err(WkbError::General(format!(
+            "TWKB precision must be between -8 and 7, found {}",
+            options.precision
+        )));
+    }
+    if options.z_precision > 7 || options.m_precision > 7 {
+        return Err(WkbError::General(
+            "TWKB z and m precisions must be between 0 and 7".to_string(),
+        ));
+    }
+
+    let dim = geom.dim().try_into()?;
+    let encoder = Encoder {
+        options,
+        dim,
+        scales: scales(
+            dim,
+            options.precision,
+            options.z_precision,
+            options.m_precision,
+        ),
+    };
+    let mut out = Vec::new();
+    encoder.geometry(&mut out, geom, ids)?;
+    writer.write_all(&out)?;
+    Ok(())
+}
+
+struct Encoder<'o> {
+    options: &'o TwkbWriteOptions,
+    dim: Dimension,
+    scales: [f64; 4],
+}
+
+impl Encoder<'_> {
+    fn geometry(
+        &self,
+        out: &mut Vec<u8>,
+        geom: &impl GeometryTrait<T = f64>,
+        ids: Option<&[i64]>,
+    ) -> WkbResult<()> {
+        use GeometryType::*;
+        let (kind, num_elements) = match geom.as_type() {
+            Point(p) => (POINT, usize::from(p.coord().is_some())),
+            LineString(ls) => (LINE_STRING, ls.num_coords()),
+            Polygon(p) => (POLYGON, p.exterior().map_or(0, |_| 1 + p.num_interiors())),
+            MultiPoint(mp) => (MULTI_POINT, mp.num_points()),
+            MultiLineString(ml) => (MULTI_LINE_STRING, ml.num_line_strings()),
+            MultiPolygon(mp) => (MULTI_POLYGON, mp.num_polygons()),
+            GeometryCollection(gc) => (GEOMETRY_COLLECTION, gc.num_geometries()),
+            Rect(_) | Triangle(_) => (POLYGON, 1),
+            Line(_) => (LINE_STRING, 2),
+        };
+        let is_empty = num_elements == 0;
+        if let Some(ids) = ids {
+            if kind < MULTI_POINT {
+                return Err(WkbError::General(
+                    "TWKB id lists require a multi geometry or geometry collection".to_string(),
+                ));
+            }
+            if ids.len() != num_elements {
+                return Err(WkbError::General(format!(
+                    "Expected {} TWKB ids, found {}",
+                    num_elements,
+                    ids.len()
+                )));
+            }
+        }
+
+        let include_bbox = self.options.include_bbox && !is_empty;
+        let mut metadata = 0;
+        if include_bbox {
+            metadata |= FLAG_BBOX;
+        }
+        if self.options.include_size {
+            metadata |= FLAG_SIZE;
+        }
+        if ids.is_some() && !is_empty {
+            metadata |= FLAG_IDLIST;
+        }
+        if self.dim != Dimension::Xy {
+            metadata |= FLAG_EXTENDED;
+        }
+        if is_empty {
+            metadata |= FLAG_EMPTY;
+        }
+
+        out.push(((zigzag_encode(self.options.precision.into()) as u8) << 4) | kind);
+        out.push(metadata);
+        if self.dim != Dimension::Xy {
+            out.push(
+                u8::from(self.dim.has_z())
+                    | (u8::from(self.dim.has_m()) << 1)
+                    | (self.options.z_precision << 2)
+                    | (self.options.m_precision << 5),
+            );
+        }
+
+        // The size counts the bytes after itself, so the rest of the geometry is buffered first
+        let mut rest = Vec::new();
+        if include_bbox {
+            self.bbox(&mut rest, &Bounds::of_geometry(geom));
+        }
+        if !is_empty {
+            self.body(&mut rest, geom, ids)?;
+        }
+        if self.options.include_size {
+            write_uvarint(out, rest.len() as u64);
+        }
+        out.extend_from_slice(&rest);
+        Ok(())
+    }
+
+    fn bbox(&self, out: &mut Vec<u8>, bounds: &Bounds) {
+        let mut ranges = vec![bounds.x(), bounds.y()];
+        if self.dim.has_z() {
+            ranges.push(bounds.z().unwrap_or_default());
+        }
+        if self.dim.has_m() {
+            ranges.push(bounds.m().unwrap_or_default());
+        }
+        for ((min, max), scale) in ranges.into_iter().zip(self.scales) {
+            let (min, max) = (quantize(min, scale), quantize(max, scale));
+            write_varint(out, min);
+            write_varint(out, max.wrapping_sub(min));
+        }
+    }
+
+    fn body(
+        &self,
+        out: &mut Vec<u8>,
+        geom: &impl GeometryTrait<T = f64>,
+        ids: Option<&[i64]>,
+    ) -> WkbResult<()> {
+        use GeometryType::*;
+        let mut last = [0; 4];
+        match geom.as_type() {
+            Point(p) => {
+                if let Some(coord) = p.coord() {
+                    self.coord(out, &coord, &mut last);
+                }
+            }
+            LineString(ls) => self.coords(out, ls.num_coords(), ls.coords(), &mut last),
+            Polygon(p) => self.polygon(out, p, &mut last),
+            MultiPoint(mp) => {
+                write_uvarint(out, mp.num_points() as u64);
+                write_ids(out, ids);
+                for point in mp.points() {
+                    let coord = point.coord().ok_or_else(|| {
+                        WkbError::General(
+                            "TWKB cannot represent an empty Point inside a MultiPoint".to_string(),
+                        )
+                    })?;
+                    self.coord(out, &coord, &mut last);
+                }
+            }
+            MultiLineString(ml) => {
+                write_uvarint(out, ml.num_line_strings() as u64);
+                write_ids(out, ids);
+                for ls in ml.line_strings() {
+                    self.coords(out, ls.num_coords(), ls.coords(), &mut last);
+                }
+            }
+            MultiPolygon(mp) => {
+                write_uvarint(out, mp.num_polygons() as u64);
+                write_ids(out, ids);
+                for polygon in mp.polygons() {
+                    self.polygon(out, &polygon, &mut last);
+                }
+            }
+            GeometryCollection(gc) => {
+                write_uvarint(out, gc.num_geometries() as u64);
+                write_ids(out, ids);
+                for member in gc.geometries() {
+                    self.geometry(out, &member, None)?;
+                }
+            }
+            Rect(rect) => {
+                let (min, max) = (rect.min(), rect.max());
+                let ring = [
+                    (min.x(), min.y()),
+                    (min.x(), max.y()),
+                    (max.x(), max.y()),
+                    (max.x(), min.y()),
+                    (min.x(), min.y()),
+                ];
+                write_uvarint(out, 1);
+                self.coords(out, ring.len(), ring.into_iter(), &mut last);
+            }
+            Triangle(tri) => {
+                let ring = tri.coords().into_iter().chain(std::iter::once(tri.first()));
+                write_uvarint(out, 1);
+                self.coords(out, 4, ring, &mut last);
+            }
+            Line(line) => self.coords(out, 2, line.coords().into_iter(), &mut last),
+        }
+        Ok(())
+    }
+
+    fn polygon(
+        &self,
+        out: &mut Vec<u8>,
+        polygon: &impl PolygonTrait<T = f64>,
+        last: &mut [i64; 4],
+    ) {
+        let num_rings = polygon
+            .exterior()
+            .map_or(0, |_| 1 + polygon.num_interiors());
+        write_uvarint(out, num_rings as u64);
+        if let Some(exterior) = polygon.exterior() {
+            self.coords(out, exterior.num_coords(), exterior.coords(), last);
+        }
+        for interior in polygon.interiors() {
+            self.coords(out, interior.num_coords(), interior.coords(), last);
+        }
+    }
+
+    /// Write a count followed by `count` coordinates
+    fn coords<C: CoordTrait<T = f64>>(
+        &self,
+        out: &mut Vec<u8>,
+        count: usize,
+        coords: impl Iterator<Item = C>,
+        last: &mut [i64; 4],
+    ) {
+        write_uvarint(out, count as u64);
+        for coord in coords {
+            self.coord(out, &coord, last);
+        }
+    }
+
+    fn coord(&self, out: &mut Vec<u8>, coord: &impl CoordTrait<T = f64>, last: &mut [i64; 4]) {
+        for (i, last) in last[..self.dim.size()].iter_mut().enumerate() {
+            let value = quantize(coord.nth(i).unwrap_or(f64::NAN), self.scales[i]);
+            write_varint(out, value.wrapping_sub(*last));
+            *last = value;
+        }
+    }
+}
+
+fn write_ids(out: &mut Vec<u8>, ids: Option<&[i64]>) {
+    for id in ids.into_iter().flatten() {
+        write_varint(out, *id);
+    }
+}
+
+/// Scale a value to an integer, where NaN becomes zero
+fn quantize(value: f64, scale: f64) -> i64 {
+    (value * scale).round() as i64
+}
+
+fn write_uvarint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7F) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn write_varint(out: &mut Vec<u8>, value: i64) {
+    write_uvarint(out, zigzag_encode(value));
+}
+
+fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn zigzag_decode(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+#[cold]
+fn invalid_geometry(reason: &'static str, offset: usize) -> WkbError {
+    WkbError::InvalidGeometry {
+        reason,
+        offset: offset as u64,
+        path: WkbPath::default(),
+    }
+}
@@ -0,0 +1,237 @@
+use std::fmt::{self, Write};
+
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, LineStringTrait, MultiLineStringTrait, MultiPointTrait,
+    MultiPolygonTrait, PointTrait, PolygonTrait,
+};
+
+use crate::common::{format_rounded, Dimension};
+use crate::reader::{Wkb, WkbInner};
+
+/// Options for formatting geometries as WKT
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WktOptions {
+    /// The maximum number of decimal places to write for each ordinate.
+    ///
+    /// Trailing zeros are removed, so that `1.50` is written as `1.5`. By default, each ordinate
+    /// is written with the fewest digits that read back as the same `f64`.
+    pub precision: Option<usize>,
+
+    /// Whether to prefix the output with `SRID=...;`, as in PostGIS extended WKT (EWKT).
+    ///
+    /// Only the SRID embedded in the header of the outermost geometry is written. Geometries
+    /// without an SRID are written as plain WKT.
+    pub include_srid: bool,
+}
+
+/// A [`Wkb`] geometry formatted as WKT with the given [`WktOptions`].
+///
+/// This is created by [`Wkb::display_wkt`].
+#[derive(Debug, Clone, Copy)]
+pub struct WktDisplay<'g, 'a> {
+    geometry: &'g Wkb<'a>,
+    options: WktOptions,
+}
+
+impl fmt::Display for WktDisplay<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_wkt(f, self.geometry, &self.options)
+    }
+}
+
+impl<'a> Wkb<'a> {
+    /// Format this geometry as WKT with the given options.
+    ///
+    /// The [`Display`][fmt::Display] implementation of `Wkb` uses the default options, taking the
+    /// precision from the formatter.
+    pub fn display_wkt(&self, options: WktOptions) -> WktDisplay<'_, 'a> {
+        WktDisplay {
+            geometry: self,
+            options,
+        }
+    }
+}
+
+impl fmt::Display for Wkb<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let options = WktOptions {
+            precision: f.precision(),
+            include_srid: false,
+        };
+        write_wkt(f, self, &options)
+    }
+}
+
+/// Write a parsed WKB geometry as WKT.
+///
+/// Geometries with Z or M values are tagged as in ISO WKT, e.g. `POINT ZM (1 2 3 4)`. Curves and
+/// surfaces are written with their own keywords, such as `CIRCULARSTRING` and `TIN`, and
+/// LineStrings and Polygons nested inside them are untagged, as in
+/// `COMPOUNDCURVE((0 0,1 1),CIRCULARSTRING(1 1,2 0,3 1))`.
+pub fn write_wkt(out: &mut impl Write, geometry: &Wkb, options: &WktOptions) -> fmt::Result {
+    if options.include_srid {
+        if let Some(srid) = geometry.srid() {
+            write!(out, "SRID={srid};")?;
+        }
+    }
+    WktWriter {
+        out,
+        precision: options.precision,
+        scratch: String::new(),
+    }
+    .geometry(geometry)
+}
+
+struct WktWriter<'o, W: Write> {
+    out: &'o mut W,
+    precision: Option<usize>,
+    /// Reused to format each rounded ordinate
+    scratch: String,
+}
+
+impl<W: Write> WktWriter<'_, W> {
+    /// Write a geometry with its keyword and dimension tag
+    fn geometry(&mut self, geometry: &Wkb) -> fmt::Result {
+        self.out.write_str(keyword(geometry.inner()))?;
+        self.out.write_str(match geometry.dimension() {
+            Dimension::Xy => "",
+            Dimension::Xyz => " Z",
+            Dimension::Xym => " M",
+            Dimension::Xyzm => " ZM",
+        })?;
+        if is_empty(geometry.inner()) {
+            return self.out.write_str(" EMPTY");
+        }
+        if geometry.dimension() != Dimension::Xy {
+            self.out.write_char(' ')?;
+        }
+
+        match geometry.inner() {
+            WkbInner::Point(g) => self.point(g),
+            WkbInner::LineString(g) => self.line_string(g),
+            WkbInner::Polygon(g) => self.polygon(g),
+            WkbInner::MultiPoint(g) => self.list(g.points(), |w, p| w.point(&p)),
+            WkbInner::MultiLineString(g) => self.list(g.line_strings(), |w, l| w.line_string(l)),
+            WkbInner::MultiPolygon(g) => self.list(g.polygons(), |w, p| w.polygon(&p)),
+            WkbInner::GeometryCollection(g) => self.list(g.geometries(), |w, g| w.geometry(g)),
+            WkbInner::CircularString(g) => self.line_string(g),
+            WkbInner::CompoundCurve(g) => self.list(g.segments(), |w, g| w.member(g)),
+            WkbInner::CurvePolygon(g) => self.list(g.rings(), |w, g| w.member(g)),
+            WkbInner::MultiCurve(g) => self.list(g.curves(), |w, g| w.member(g)),
+            WkbInner::MultiSurface(g) => self.list(g.surfaces(), |w, g| w.member(g)),
+            WkbInner::PolyhedralSurface(g) => self.list(g.patches(), |w, p| w.polygon(&p)),
+            WkbInner::Tin(g) => self.list(g.triangles(), |w, t| w.polygon(t.as_polygon())),
+            WkbInner::Triangle(g) => self.polygon(g.as_polygon()),
+        }
+    }
+
+    /// Write a member of a curve or surface, where LineStrings and Polygons are untagged
+    fn member(&mut self, geometry: &Wkb) -> fmt::Result {
+        match geometry.inner() {
+            WkbInner::LineString(g) => self.line_string(g),
+            WkbInner::Polygon(g) => self.polygon(g),
+            _ => self.geometry(geometry),
+        }
+    }
+
+    fn point(&mut self, point: &impl PointTrait<T = f64>) -> fmt::Result {
+        match point.coord() {
+            Some(coord) => self.list([coord], |w, c| w.coord(&c)),
+            None => self.out.write_str("EMPTY"),
+        }
+    }
+
+    fn line_string(&mut self, line_string: &impl LineStringTrait<T = f64>) -> fmt::Result {
+        self.list(line_string.coords(), |w, c| w.coord(&c))
+    }
+
+    fn polygon(&mut self, polygon: &impl PolygonTrait<T = f64>) -> fmt::Result {
+        match polygon.exterior() {
+            Some(exterior) => self.list(
+                std::iter::once(exterior).chain(polygon.interiors()),
+                |w, ring| w.line_string(&ring),
+            ),
+            None => self.out.write_str("EMPTY"),
+        }
+    }
+
+    /// Write items separated by commas inside parentheses, or `EMPTY` if there are none
+    fn list<I>(
+        &mut self,
+        items: impl IntoIterator<Item = I>,
+        mut write_item: impl FnMut(&mut Self, I) -> fmt::Result,
+    ) -> fmt::Result {
+        let mut items = items.into_iter().peekable();
+        if items.peek().is_none() {
+            return self.out.write_str("EMPTY");
+        }
+        self.out.write_char('(')?;
+        for (i, item) in items.enumerate() {
+            if i > 0 {
+                self.out.write_char(',')?;
+            }
+            write_item(self, item)?;
+        }
+        self.out.write_char(')')
+    }
+
+    fn coord(&mut self, coord: &impl CoordTrait<T = f64>) -> fmt::Result {
+        for n in 0..coord.dim().size() {
+            if n > 0 {
+                self.out.write_char(' ')?;
+            }
+            self.ordinate(coord.nth_or_panic(n))?;
+        }
+        Ok(())
+    }
+
+    fn ordinate(&mut self, value: f64) -> fmt::Result {
+        match self.precision {
+            Some(precision) => {
+                self.out
+                    .write_str(format_rounded(&mut self.scratch, value, precision))
+            }
+            None => write!(self.out, "{value}"),
+        }
+    }
+}
+
+fn keyword(inner: &WkbInner) -> &'static str {
+    match inner {
+        WkbInner::Point(_) => "POINT",
+        WkbInner::LineString(_) => "LINESTRING",
+        WkbInner::Polygon(_) => "POLYGON",
+        WkbInner::MultiPoint(_) => "MULTIPOINT",
+        WkbInner::MultiLineString(_) => "MULTILINESTRING",
+        WkbInner::MultiPolygon(_) => "MULTIPOLYGON",
+        WkbInner::GeometryCollection(_) => "GEOMETRYCOLLECTION",
+        WkbInner::CircularString(_) => "CIRCULARSTRING",
+        WkbInner::CompoundCurve(_) => "COMPOUNDCURVE",
+        WkbInner::CurvePolygon(_) => "CURVEPOLYGON",
+        WkbInner::MultiCurve(_) => "MULTICURVE",
+        WkbInner::MultiSurface(_) => "MULTISURFACE",
+        WkbInner::PolyhedralSurface(_) => "POLYHEDRALSURFACE",
+        WkbInner::Tin(_) => "TIN",
+        WkbInner::Triangle(_) => "TRIANGLE",
+    }
+}
+
+fn is_empty(inner: &WkbInner) -> bool {
+    match inner {
+        WkbInner::Point(g) => g.is_empty(),
+        WkbInner::LineString(g) => g.num_coords() == 0,
+        WkbInner::Polygon(g) => g.exterior().is_none(),
+        WkbInner::MultiPoint(g) => g.num_points() == 0,
+        WkbInner::MultiLineString(g) => g.num_line_strings() == 0,
+        WkbInner::MultiPolygon(g) => g.num_polygons() == 0,
+        WkbInner::GeometryCollection(g) => g.num_geometries() == 0,
+        WkbInner::CircularString(g) => g.num_coords() == 0,
+        WkbInner::CompoundCurve(g) => g.num_segments() == 0,
+        WkbInner::CurvePolygon(g) => g.num_rings() == 0,
+        WkbInner::MultiCurve(g) => g.num_curves() == 0,
+        WkbInner::MultiSurface(g) => g.num_surfaces() == 0,
+        WkbInner::PolyhedralSurface(g) => g.num_patches() == 0,
+        WkbInner::Tin(g) => g.num_triangles() == 0,
+        WkbInner::Triangle(g) => g.is_empty(),
+    }
+}
@@ -0,0 +1,54 @@
+//! Convert between WKB and Well-Known Text (WKT) or extended WKT (EWKT).
+//!
+//! [`Wkb`][crate::reader::Wkb] implements [`Display`][std::fmt::Display], writing ISO WKT with
+//! ` Z`, ` M`, or ` ZM` tags. The formatter precision, as in `{:.3}`, limits the number of
+//! decimal places. Use [`WktOptions`] to also write the `SRID=...;` prefix used by PostGIS EWKT.
+//!
+//! ```
+//! use geo_types::line_string;
+//! use wkb::reader::read_wkb;
+//! use wkb::writer::{write_geometry, WkbFlavor, WriteOptions};
+//! use wkb::wkt::WktOptions;
+//!
+//! let line_string = line_string![(x: 1., y: 2.), (x: 1.23456, y: -4.5678)];
+//! let mut buf = Vec::new();
+//! let options = WriteOptions {
+//!     flavor: WkbFlavor::Ewkb,
+//!     srid: Some(4326),
+//!     ..Default::default()
+//! };
+//! write_geometry(&mut buf, &line_string, &options).unwrap();
+//! let geometry = read_wkb(&buf).unwrap();
+//!
+//! assert_eq!(geometry.to_string(), "LINESTRING(1 2,1.23456 -4.5678)");
+//! assert_eq!(format!("{geometry:.2}"), "LINESTRING(1 2,1.23 -4.57)");
+//!
+//! let options = WktOptions {
+//!     include_srid: true,
+//!     ..Default::default()
+//! };
+//! assert_eq!(
+//!     geometry.display_wkt(options).to_string(),
+//!     "SRID=4326;LINESTRING(1 2,1.23456 -4.5678)"
+//! );
+//! ```
+//!
+//! In the other direction, [`read_wkt`] and [`wkt_to_wkb`] write WKT directly as WKB, keeping M
+//! values and `EMPTY` geometries that cannot be represented by `geo_types`.
+//!
+//! ```
+//! use wkb::reader::Dimension;
+//! use wkb::wkt::read_wkt;
+//!
+//! let mut buf = Vec::new();
+//! let geometry = read_wkt("SRID=4326;LINESTRING M (1 2 3,4 5 6)", &mut buf).unwrap();
+//! assert_eq!(geometry.dimension(), Dimension::Xym);
+//! assert_eq!(geometry.srid(), Some(4326));
+//! assert_eq!(geometry.to_string(), "LINESTRING M (1 2 3,4 5 6)");
+//! ```
+
+mod display;
+mod parse;
+
+pub use display::{write_wkt, WktDisplay, WktOptions};
+pub use parse::{read_wkt, wkt_to_wkb};
@@ -0,0 +1,455 @@
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+
+use crate::common::{Dimension, WkbType};
+use crate::error::{WkbError, WkbResult};
+use crate::reader::{GeometryType, Wkb};
+use crate::writer::text::{starts_number, TextInput, WkbOutput};
+use crate::writer::{WkbFlavor, WriteOptions};
+use crate::Endianness;
+
+/// The geometry keywords, each of which may be followed by a `Z`, `M`, or `ZM` suffix
+const KEYWORDS: [(&[u8], GeometryType); 15] = [
+    (b"POINT", GeometryType::Point),
+    (b"LINESTRING", GeometryType::LineString),
+    (b"POLYGON", GeometryType::Polygon),
+    (b"MULTIPOINT", GeometryType::MultiPoint),
+    (b"MULTILINESTRING", GeometryType::MultiLineString),
+    (b"MULTIPOLYGON", GeometryType::MultiPolygon),
+    (b"GEOMETRYCOLLECTION", GeometryType::GeometryCollection),
+    (b"CIRCULARSTRING", GeometryType::CircularString),
+    (b"COMPOUNDCURVE", GeometryType::CompoundCurve),
+    (b"CURVEPOLYGON", GeometryType::CurvePolygon),
+    (b"MULTICURVE", GeometryType::MultiCurve),
+    (b"MULTISURFACE", GeometryType::MultiSurface),
+    (b"POLYHEDRALSURFACE", GeometryType::PolyhedralSurface),
+    (b"TIN", GeometryType::Tin),
+    (b"TRIANGLE", GeometryType::Triangle),
+];
+
+const CURVES: &[GeometryType] = &[
+    GeometryType::LineString,
+    GeometryType::CircularString,
+    GeometryType::CompoundCurve,
+];
+
+const SURFACES: &[GeometryType] = &[
+    GeometryType::Polygon,
+    GeometryType::CurvePolygon,
+    GeometryType::PolyhedralSurface,
+    GeometryType::Tin,
+    GeometryType::Triangle,
+];
+
+/// Parse WKT or EWKT by converting it to WKB in `buf`.
+///
+/// The previous contents of `buf` are replaced. The geometry is written as little-endian ISO WKB,
+/// or as EWKB with an embedded SRID if the text starts with an EWKT `SRID=...;` prefix.
+///
+/// See [`wkt_to_wkb`] for the accepted syntax.
+pub fn read_wkt<'a>(wkt: &str, buf: &'a mut Vec<u8>) -> WkbResult<Wkb<'a>> {
+    buf.clear();
+    let (srid, start) = srid_prefix(wkt.as_bytes())?;
+    let options = WriteOptions {
+        flavor: if srid.is_some() {
+            WkbFlavor::Ewkb
+        } else {
+            WkbFlavor::Iso
+        },
+        srid,
+        ..Default::default()
+    };
+    Parser::<LittleEndian>::new(wkt.as_bytes(), start, buf).parse(&options)?;
+
+    let buf: &'a [u8] = buf;
+    Wkb::try_new(buf)
+}
+
+/// Convert WKT or EWKT to WKB, appending it to `out`.
+///
+/// The geometry is written as it is parsed, without building intermediate objects.
+///
+/// All of the geometry types that [`write_wkt`][super::write_wkt] produces are accepted, in upper
+/// or lower case. Dimensions are taken from ` Z`, ` M`, and ` ZM` tags, from the PostGIS forms
+/// such as `POINTM`, or else from the number of ordinates in the first coordinate, where three
+/// ordinates are Z values. As in [`geojson_to_wkb`][crate::geojson::geojson_to_wkb], the members
+/// of a GeometryCollection may differ in dimension from each other and from the collection, and
+/// untagged members of an M collection take three ordinates as M values. `EMPTY` Points are written with NaN coordinates and other `EMPTY`
+/// geometries with no elements. MultiPoint members may omit their parentheses.
+///
+/// An EWKT `SRID=...;` prefix is embedded when writing [`WkbFlavor::Ewkb`] and
+/// [`WriteOptions::srid`] is not set, and is ignored otherwise. Invalid text is reported as
+/// [`WkbError::InvalidWkt`] with the byte offset of the problem.
+pub fn wkt_to_wkb(wkt: &str, out: &mut Vec<u8>, options: &WriteOptions) -> WkbResult<()> {
+    let (srid, start) = srid_prefix(wkt.as_bytes())?;
+    let mut options = options.clone();
+    if options.flavor == WkbFlavor::Ewkb && options.srid.is_none() {
+        options.srid = srid;
+    }
+
+    match options.endianness {
+        Endianness::LittleEndian => {
+            Parser::<LittleEndian>::new(wkt.as_bytes(), start, out).parse(&options)
+        }
+        Endianness::BigEndian => {
+            Parser::<BigEndian>::new(wkt.as_bytes(), start, out).parse(&options)
+        }
+    }
+}
+
+/// Split off an EWKT `SRID=...;` prefix, returning the SRID and the offset of the geometry
+fn srid_prefix(wkt: &[u8]) -> WkbResult<(Option<u32>, usize)> {
+    let start = wkt.iter().take_while(|b| b.is_ascii_whitespace()).count();
+    let rest = &wkt[start..];
+    if rest.len() < 5 || !rest[..5].eq_ignore_ascii_case(b"SRID=") {
+        return Ok((None, 0));
+    }
+
+    let digits_start = start + 5;
+    let Some(len) = wkt[digits_start..].iter().position(|&b| b == b';') else {
+        return Err(invalid_wkt(digits_start, "expected ';' after the SRID"));
+    };
+    let srid = std::str::from_utf8(&wkt[digits_start..digits_start + len])
+        .ok()
+        .and_then(|digits| digits.trim().parse().ok())
+        .ok_or_else(|| invalid_wkt(digits_start, "invalid SRID"))?;
+    Ok((Some(srid), digits_start + len + 1))
+}
+
+struct Parser<'w, 'o, B: ByteOrder> {
+    input: TextInput<'w>,
+    out: WkbOutput<'o, B>,
+}
+
+impl<'w, 'o, B: ByteOrder> Parser<'w, 'o, B> {
+    fn new(wkt: &'w [u8], pos: usize, out: &'o mut Vec<u8>) -> Self {
+        Self {
+            input: TextInput::new(wkt, pos),
+            out: WkbOutput::new(out),
+        }
+    }
+
+    fn parse(mut self, options: &WriteOptions) -> WkbResult<()> {
+        self.geometry(None, None, options)?;
+        if self.input.peek().is_some() {
+            return Err(invalid_wkt(
+                self.input.pos,
+                "unexpected text after the geometry",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Parse a tagged geometry, such as `POINT Z (1 2 3)`.
+    ///
+    /// Members of a GeometryCollection, which is the only container that allows any geometry type,
+    /// have their own dimension. Other nested geometries take the dimension of the enclosing
+    /// geometry, and may only repeat it in their own tag.
+    fn geometry(
+        &mut self,
+        parent_dim: Option<Dimension>,
+        allowed: Option<&[GeometryType]>,
+        options: &WriteOptions,
+    ) -> WkbResult<()> {
+        self.input.skip_whitespace();
+        let start = self.input.pos;
+        let word = self.word();
+        let Some((geometry_type, suffix_tag)) = keyword(word) else {
+            let reason = if word.is_empty() {
+                "expected a geometry type"
+            } else {
+                "unknown geometry type"
+            };
+            return Err(invalid_wkt(start, reason));
+        };
+        if allowed.is_some_and(|allowed| !allowed.contains(&geometry_type)) {
+            return Err(invalid_wkt(start, "geometry type is not allowed here"));
+        }
+
+        let tag = suffix_tag.or_else(|| self.dimension_tag());
+        let dim = match (tag, parent_dim) {
+            (Some(tag), Some(parent)) if tag != parent && allowed.is_some() => {
+                return Err(invalid_wkt(
+                    start,
+                    "dimension does not match the enclosing geometry",
+                ))
+            }
+            (Some(dim), _) => dim,
+            (None, Some(parent)) if allowed.is_some() => parent,
+            (None, parent) => self.infer_dimension(parent),
+        };
+
+        self.out.enter(start)?;
+        let result = self.member(geometry_type, dim, options);
+        self.out.leave();
+        result
+    }
+
+    /// Write the header and parse the body of a geometry whose type is already known
+    fn member(
+        &mut self,
+        geometry_type: GeometryType,
+        dim: Dimension,
+        options: &WriteOptions,
+    ) -> WkbResult<()> {
+        self.out.header(WkbType::new(geometry_type, dim), options)?;
+
+        let nested = options.nested();
+        match geometry_type {
+            GeometryType::Point => self.point(dim),
+            GeometryType::LineString | GeometryType::CircularString => self.coords(dim),
+            GeometryType::Polygon | GeometryType::Triangle => self.list(|p| p.coords(dim)),
+            GeometryType::MultiPoint => self.list(|p| p.multi_point_member(dim, &nested)),
+            GeometryType::MultiLineString => {
+                self.list(|p| p.member(GeometryType::LineString, dim, &nested))
+            }
+            GeometryType::MultiPolygon | GeometryType::PolyhedralSurface => {
+                self.list(|p| p.member(GeometryType::Polygon, dim, &nested))
+            }
+            GeometryType::Tin => self.list(|p| p.member(GeometryType::Triangle, dim, &nested)),
+            GeometryType::GeometryCollection => self.list(|p| p.geometry(Some(dim), None, &nested)),
+            GeometryType::CompoundCurve => {
+                self.list(|p| p.untagged_or(GeometryType::LineString, &CURVES[..2], dim, &nested))
+            }
+            GeometryType::CurvePolygon | GeometryType::MultiCurve => {
+                self.list(|p| p.untagged_or(GeometryType::LineString, CURVES, dim, &nested))
+            }
+            GeometryType::MultiSurface => {
+                self.list(|p| p.untagged_or(GeometryType::Polygon, SURFACES, dim, &nested))
+            }
+        }
+    }
+
+    /// Parse a member of a curve or surface, which is untagged if it has the default type
+    fn untagged_or(
+        &mut self,
+        default: GeometryType,
+        allowed: &[GeometryType],
+        dim: Dimension,
+        options: &WriteOptions,
+    ) -> WkbResult<()> {
+        if self.input.peek() == Some(b'(') || self.at_empty() {
+            self.member(default, dim, options)
+        } else {
+            self.geometry(Some(dim), Some(allowed), options)
+        }
+    }
+
+    /// Parse a MultiPoint member, which may be `EMPTY`, `(1 2)`, or a bare `1 2`
+    fn multi_point_member(&mut self, dim: Dimension, options: &WriteOptions) -> WkbResult<()> {
+        if self.input.peek() == Some(b'(') || self.at_empty() {
+            self.member(GeometryType::Point, dim, options)
+        } else {
+            self.out.header(WkbType::Point(dim), options)?;
+            self.coord(dim)
+        }
+    }
+
+    fn point(&mut self, dim: Dimension) -> WkbResult<()> {
+        if self.empty() {
+            return self.out.empty_coord(dim);
+        }
+        self.expect(b'(')?;
+        self.coord(dim)?;
+        self.expect(b')')
+    }
+
+    fn coords(&mut self, dim: Dimension) -> WkbResult<()> {
+        self.list(|p| p.coord(dim))
+    }
+
+    /// Parse `EMPTY` or a parenthesized, comma-separated list, writing the number of elements
+    /// followed by each element
+    fn list(&mut self, mut element: impl FnMut(&mut Self) -> WkbResult<()>) -> WkbResult<()> {
+        let slot = self.out.begin_count()?;
+        if self.empty() {
+            return self.out.end_count(slot, 0);
+        }
+
+        self.expect(b'(')?;
+        let mut count = 0usize;
+        loop {
+            element(self)?;
+            count += 1;
+            if self.input.consume(b')') {
+                break;
+            }
+            if !self.input.consume(b',') {
+                return Err(invalid_wkt(self.input.pos, "expected ',' or ')'"));
+            }
+        }
+        self.out.end_count(slot, count)
+    }
+
+    fn coord(&mut self, dim: Dimension) -> WkbResult<()> {
+        for n in 0..dim.size() {
+            self.input.skip_whitespace();
+            if n >= 2 && matches!(self.input.text.get(self.input.pos), Some(b',' | b')')) {
+                return Err(invalid_wkt(
+                    self.input.pos,
+                    "too few ordinates for the geometry's dimension",
+                ));
+            }
+            let value = self.number()?;
+            self.out.ordinate(value)?;
+        }
+
+        self.input.skip_whitespace();
+        if self
+            .input
+            .text
+            .get(self.input.pos)
+            .is_some_and(|&b| starts_number(b))
+        {
+            return Err(invalid_wkt(
+                self.input.pos,
+                "too many ordinates for the geometry's dimension",
+            ));
+        }
+        Ok(())
+    }
+
+    fn number(&mut self) -> WkbResult<f64> {
+        let start = self.input.pos;
+        let len = self.input.text[start..]
+            .iter()
+            .take_while(|&&b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'))
+            .count();
+        if len == 0 {
+            return Err(invalid_wkt(start, "expected a number"));
+        }
+        self.input.pos += len;
+        // Rust also parses `nan` and `inf`, which WKT does not allow
+        std::str::from_utf8(&self.input.text[start..self.input.pos])
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .filter(|number: &f64| number.is_finite())
+            .ok_or_else(|| invalid_wkt(start, "invalid number"))
+    }
+
+    /// Guess the dimension of an untagged geometry from the first tag or coordinate within it.
+    ///
+    /// Three ordinates are Z values unless the geometry is a member of an M collection.
+    fn infer_dimension(&self, collection_dim: Option<Dimension>) -> Dimension {
+        let mut pos = self.input.pos;
+        let mut depth = 0usize;
+        while pos < self.input.text.len() {
+            let b = self.input.text[pos];
+            // Stop at the end of the geometry, such as after `EMPTY`
+            if (b == b')' && depth <= 1) || (b == b',' && depth == 0) {
+                break;
+            } else if b == b'(' || b == b')' {
+                depth = if b == b'(' { depth + 1 } else { depth - 1 };
+                pos += 1;
+            } else if b.is_ascii_alphabetic() {
+                let word = alphabetic_run(&self.input.text[pos..]);
+                if let Some(dim) = dimension_tag(word).or_else(|| keyword(word)?.1) {
+                    return dim;
+                }
+                pos += word.len();
+            } else if starts_number(b) {
+                let mut ordinates = 0;
+                while pos < self.input.text.len() && starts_number(self.input.text[pos]) {
+                    ordinates += 1;
+                    pos += self.input.text[pos..]
+                        .iter()
+                        .take_while(|b| !b.is_ascii_whitespace() && !matches!(b, b',' | b')'))
+                        .count();
+                    pos += self.input.text[pos..]
+                        .iter()
+                        .take_while(|b| b.is_ascii_whitespace())
+                        .count();
+                }
+                return match (ordinates, collection_dim) {
+                    (3, Some(Dimension::Xym)) => Dimension::Xym,
+                    (3, _) => Dimension::Xyz,
+                    (4, _) => Dimension::Xyzm,
+                    _ => Dimension::Xy,
+                };
+            } else {
+                pos += 1;
+            }
+        }
+        Dimension::Xy
+    }
+
+    /// Consume a separate ` Z`, ` M`, or ` ZM` tag, if there is one
+    fn dimension_tag(&mut self) -> Option<Dimension> {
+        let pos = self.input.pos;
+        let dim = dimension_tag(self.word());
+        if dim.is_none() {
+            self.input.pos = pos;
+        }
+        dim
+    }
+
+    /// Consume `EMPTY`, if it is next
+    fn empty(&mut self) -> bool {
+        let is_empty = self.at_empty();
+        if is_empty {
+            self.word();
+        }
+        is_empty
+    }
+
+    fn at_empty(&mut self) -> bool {
+        self.input.skip_whitespace();
+        alphabetic_run(&self.input.text[self.input.pos..]).eq_ignore_ascii_case(b"EMPTY")
+    }
+
+    /// Consume a run of letters following any whitespace
+    fn word(&mut self) -> &'w [u8] {
+        self.input.skip_whitespace();
+        let word = alphabetic_run(&self.input.text[self.input.pos..]);
+        self.input.pos += word.len();
+        word
+    }
+
+    fn expect(&mut self, expected: u8) -> WkbResult<()> {
+        if self.input.consume(expected) {
+            return Ok(());
+        }
+        let reason = match expected {
+            b'(' => "expected '('",
+            _ => "expected ')'",
+        };
+        Err(invalid_wkt(self.input.pos, reason))
+    }
+}
+
+/// Match a geometry keyword with an optional dimension suffix, as in `POINTZM`
+fn keyword(word: &[u8]) -> Option<(GeometryType, Option<Dimension>)> {
+    KEYWORDS.iter().find_map(|&(name, geometry_type)| {
+        if word.len() < name.len() || !word[..name.len()].eq_ignore_ascii_case(name) {
+            return None;
+        }
+        match &word[name.len()..] {
+            [] => Some((geometry_type, None)),
+            suffix => dimension_tag(suffix).map(|dim| (geometry_type, Some(dim))),
+        }
+    })
+}
+
+fn dimension_tag(word: &[u8]) -> Option<Dimension> {
+    if word.eq_ignore_ascii_case(b"Z") {
+        Some(Dimension::Xyz)
+    } else if word.eq_ignore_ascii_case(b"M") {
+        Some(Dimension::Xym)
+    } else if word.eq_ignore_ascii_case(b"ZM") {
+        Some(Dimension::Xyzm)
+    } else {
+        None
+    }
+}
+
+fn alphabetic_run(wkt: &[u8]) -> &[u8] {
+    let len = wkt.iter().take_while(|b| b.is_ascii_alphabetic()).count();
+    &wkt[..len]
+}
+
+#[cold]
+fn invalid_wkt(offset: usize, reason: &'static str) -> WkbError {
+    WkbError::InvalidWkt {
+        offset: offset as u64,
+        reason,
+    }
+}
@@ -0,0 +1,58 @@
+use crate::common::WkbType;
+use crate::error::WkbResult;
+use crate::writer::coord::write_coord;
+use crate::writer::header::{write_byte_order, write_geometry_code};
+use crate::writer::WriteOptions;
+use crate::Endianness;
+use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
+use geo_traits::LineStringTrait;
+use std::io::Write;
+
+/// The number of bytes this CircularString will take up when encoded as WKB
+pub fn circular_string_wkb_size(
+    geom: &impl LineStringTrait<T = f64>,
+    options: &WriteOptions,
+) -> usize {
+    let header = options.header_size() + 4;
+    let each_coord = geom.dim().size() * 8;
+    let all_coords = geom.num_coords() * each_coord;
+    header + all_coords
+}
+
+/// Write a CircularString geometry to a Writer encoded as WKB
+///
+/// The coordinates of `geom` are written as the control points of the circular arcs.
+pub fn write_circular_string(
+    writer: &mut impl Write,
+    geom: &impl LineStringTrait<T = f64>,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    // Byte order
+    write_byte_order(writer, options)?;
+
+    // Content
+    match options.endianness {
+        Endianness::LittleEndian => {
+            write_circular_string_content::<LittleEndian>(writer, geom, options)
+        }
+        Endianness::BigEndian => write_circular_string_content::<BigEndian>(writer, geom, options),
+    }
+}
+
+fn write_circular_string_content<B: ByteOrder>(
+    writer: &mut impl Write,
+    geom: &impl LineStringTrait<T = f64>,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    let wkb_type = WkbType::CircularString(geom.dim().try_into()?);
+    write_geometry_code::<B>(writer, wkb_type, options)?;
+
+    // numPoints
+    writer.write_u32::<B>(geom.num_coords().try_into()?)?;
+
+    for coord in geom.coords() {
+        write_coord::<B>(writer, &coord)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use crate::common::WkbType;
+use crate::error::WkbResult;
+use crate::reader::CompoundCurve;
+use crate::writer::header::{write_byte_order, write_geometry_code};
+use crate::writer::wkb::{wkb_size, write_wkb};
+use crate::writer::WriteOptions;
+use crate::Endianness;
+use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
+use std::io::Write;
+
+/// The number of bytes this CompoundCurve will take up when encoded as WKB
+pub fn compound_curve_wkb_size(geom: &CompoundCurve, options: &WriteOptions) -> usize {
+    let mut sum = options.header_size() + 4;
+    let nested_options = options.nested();
+    for segment in geom.segments() {
+        sum += wkb_size(segment, &nested_options);
+    }
+
+    sum
+}
+
+/// Write a CompoundCurve geometry to a Writer encoded as WKB
+///
+/// Since [`geo_traits`] has no notion of curves, this takes a parsed [`CompoundCurve`] so that the
+/// type of each segment is preserved.
+pub fn write_compound_curve(
+    writer: &mut impl Write,
+    geom: &CompoundCurve,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    // Byte order
+    write_byte_order(writer, options)?;
+
+    // Content
+    match options.endianness {
+        Endianness::LittleEndian => {
+            write_compound_curve_content::<LittleEndian>(writer, geom, options)
+        }
+        Endianness::BigEndian => write_compound_curve_content::<BigEndian>(writer, geom, options),
+    }
+}
+
+fn write_compound_curve_content<B: ByteOrder>(
+    writer: &mut impl Write,
+    geom: &CompoundCurve,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    let wkb_type = WkbType::CompoundCurve(geom.dimension());
+    write_geometry_code::<B>(writer, wkb_type, options)?;
+
+    // numCurves
+    writer.write_u32::<B>(geom.num_segments().try_into()?)?;
+
+    let nested_options = options.nested();
+    for segment in geom.segments() {
+        write_wkb(writer, segment, &nested_options)?;
+    }
+
+    Ok(())
+}
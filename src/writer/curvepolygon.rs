@@ -0,0 +1,60 @@
+use crate::common::WkbType;
+use crate::error::WkbResult;
+use crate::reader::CurvePolygon;
+use crate::writer::header::{write_byte_order, write_geometry_code};
+use crate::writer::wkb::{wkb_size, write_wkb};
+use crate::writer::WriteOptions;
+use crate::Endianness;
+use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
+use std::io::Write;
+
+/// The number of bytes this CurvePolygon will take up when encoded as WKB
+pub fn curve_polygon_wkb_size(geom: &CurvePolygon, options: &WriteOptions) -> usize {
+    let mut sum = options.header_size() + 4;
+    let nested_options = options.nested();
+    for ring in geom.rings() {
+        sum += wkb_size(ring, &nested_options);
+    }
+
+    sum
+}
+
+/// Write a CurvePolygon geometry to a Writer encoded as WKB
+///
+/// Since [`geo_traits`] has no notion of curves, this takes a parsed [`CurvePolygon`] so that the
+/// type of each ring is preserved.
+pub fn write_curve_polygon(
+    writer: &mut impl Write,
+    geom: &CurvePolygon,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    // Byte order
+    write_byte_order(writer, options)?;
+
+    // Content
+    match options.endianness {
+        Endianness::LittleEndian => {
+            write_curve_polygon_content::<LittleEndian>(writer, geom, options)
+        }
+        Endianness::BigEndian => write_curve_polygon_content::<BigEndian>(writer, geom, options),
+    }
+}
+
+fn write_curve_polygon_content<B: ByteOrder>(
+    writer: &mut impl Write,
+    geom: &CurvePolygon,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    let wkb_type = WkbType::CurvePolygon(geom.dimension());
+    write_geometry_code::<B>(writer, wkb_type, options)?;
+
+    // numRings
+    writer.write_u32::<B>(geom.num_rings().try_into()?)?;
+
+    let nested_options = options.nested();
+    for ring in geom.rings() {
+        write_wkb(writer, ring, &nested_options)?;
+    }
+
+    Ok(())
+}
@@ -10,19 +10,19 @@ use geo_traits::{GeometryTrait, GeometryType};
 use std::io::Write;
 
 /// The number of bytes this geometry will take up when encoded as WKB
-pub fn geometry_wkb_size(geom: &impl GeometryTrait<T = f64>) -> usize {
+pub fn geometry_wkb_size(geom: &impl GeometryTrait<T = f64>, options: &WriteOptions) -> usize {
     use GeometryType::*;
     match geom.as_type() {
-        Point(_) => point_wkb_size(geom.dim()),
-        LineString(ls) => line_string_wkb_size(ls),
-        Polygon(p) => polygon_wkb_size(p),
-        MultiPoint(mp) => multi_point_wkb_size(mp),
-        MultiLineString(ml) => multi_line_string_wkb_size(ml),
-        MultiPolygon(mp) => multi_polygon_wkb_size(mp),
-        GeometryCollection(gc) => geometry_collection_wkb_size(gc),
-        Rect(r) => rect_wkb_size(r),
-        Triangle(tri) => triangle_wkb_size(tri),
-        Line(line) => line_wkb_size(line),
+        Point(_) => point_wkb_size(geom.dim(), options),
+        LineString(ls) => line_string_wkb_size(ls, options),
+        Polygon(p) => polygon_wkb_size(p, options),
+        MultiPoint(mp) => multi_point_wkb_size(mp, options),
+        MultiLineString(ml) => multi_line_string_wkb_size(ml, options),
+        MultiPolygon(mp) => multi_polygon_wkb_size(mp, options),
+        GeometryCollection(gc) => geometry_collection_wkb_size(gc, options),
+        Rect(r) => rect_wkb_size(r, options),
+        Triangle(tri) => triangle_wkb_size(tri, options),
+        Line(line) => line_wkb_size(line, options),
     }
 }
 
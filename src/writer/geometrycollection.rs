@@ -1,6 +1,7 @@
 use crate::common::WkbType;
 use crate::error::WkbResult;
 use crate::writer::geometry::{geometry_wkb_size, write_geometry};
+use crate::writer::header::{write_byte_order, write_geometry_code};
 use crate::writer::WriteOptions;
 use crate::Endianness;
 use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
@@ -8,11 +9,15 @@ use geo_traits::GeometryCollectionTrait;
 use std::io::Write;
 
 /// The number of bytes this GeometryCollection will take up when encoded as WKB
-pub fn geometry_collection_wkb_size(geom: &impl GeometryCollectionTrait<T = f64>) -> usize {
-    let mut sum = 1 + 4 + 4;
+pub fn geometry_collection_wkb_size(
+    geom: &impl GeometryCollectionTrait<T = f64>,
+    options: &WriteOptions,
+) -> usize {
+    let mut sum = options.header_size() + 4;
+    let nested_options = options.nested();
 
     for inner_geom in geom.geometries() {
-        sum += geometry_wkb_size(&inner_geom);
+        sum += geometry_wkb_size(&inner_geom, &nested_options);
     }
 
     sum
@@ -25,7 +30,7 @@ pub fn write_geometry_collection(
     options: &WriteOptions,
 ) -> WkbResult<()> {
     // Byte order
-    writer.write_u8(options.endianness.into())?;
+    write_byte_order(writer, options)?;
 
     // Content
     match options.endianness {
@@ -44,13 +49,14 @@ fn write_geometry_collection_content<B: ByteOrder>(
     options: &WriteOptions,
 ) -> WkbResult<()> {
     let wkb_type = WkbType::GeometryCollection(geom.dim().try_into()?);
-    writer.write_u32::<B>(wkb_type.into())?;
+    write_geometry_code::<B>(writer, wkb_type, options)?;
 
     // numGeometries
     writer.write_u32::<B>(geom.num_geometries().try_into()?)?;
 
+    let nested_options = options.nested();
     for inner_geom in geom.geometries() {
-        write_geometry(writer, &inner_geom, options)?;
+        write_geometry(writer, &inner_geom, &nested_options)?;
     }
 
     Ok(())
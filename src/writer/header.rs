@@ -0,0 +1,46 @@
+use std::io::Write;
+
+use byteorder::{ByteOrder, WriteBytesExt};
+
+use crate::common::WkbType;
+use crate::error::{WkbError, WkbResult};
+use crate::writer::{WkbFlavor, WriteOptions};
+
+/// Check that `options` can be written, before any bytes of the geometry are.
+pub(crate) fn check_options(options: &WriteOptions) -> WkbResult<()> {
+    if options.flavor == WkbFlavor::Iso && options.srid.is_some() {
+        return Err(WkbError::General(
+            "An SRID can only be written with the EWKB flavor".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Check `options` and write the byte order marker that starts each geometry.
+pub(crate) fn write_byte_order(writer: &mut impl Write, options: &WriteOptions) -> WkbResult<()> {
+    check_options(options)?;
+    Ok(writer.write_u8(options.endianness.into())?)
+}
+
+/// Write the geometry code, followed by the SRID if one should be embedded.
+///
+/// The byte order marker must already have been written by [`write_byte_order`].
+pub(crate) fn write_geometry_code<B: ByteOrder>(
+    writer: &mut impl Write,
+    wkb_type: WkbType,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    match (options.flavor, options.srid) {
+        (WkbFlavor::Iso, None) => writer.write_u32::<B>(wkb_type.into())?,
+        (WkbFlavor::Iso, Some(_)) => check_options(options)?,
+        (WkbFlavor::Ewkb, srid) => {
+            let code = wkb_type.as_ewkb_geometry_code(srid.is_some());
+            writer.write_u32::<B>(code.into())?;
+            if let Some(srid) = srid {
+                writer.write_u32::<B>(srid)?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::common::WkbType;
 use crate::error::WkbResult;
 use crate::writer::coord::write_coord;
+use crate::writer::header::{write_byte_order, write_geometry_code};
 use crate::writer::WriteOptions;
 use crate::Endianness;
 use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
@@ -8,8 +9,8 @@ use geo_traits::LineTrait;
 use std::io::Write;
 
 /// The number of bytes this Line will take up when encoded as WKB
-pub fn line_wkb_size(geom: &impl LineTrait<T = f64>) -> usize {
-    let header = 1 + 4 + 4;
+pub fn line_wkb_size(geom: &impl LineTrait<T = f64>, options: &WriteOptions) -> usize {
+    let header = options.header_size() + 4;
     let each_coord = geom.dim().size() * 8;
     let all_coords = 2 * each_coord;
     header + all_coords
@@ -22,21 +23,22 @@ pub fn write_line(
     options: &WriteOptions,
 ) -> WkbResult<()> {
     // Byte order
-    writer.write_u8(options.endianness.into()).unwrap();
+    write_byte_order(writer, options)?;
 
     // Content
     match options.endianness {
-        Endianness::LittleEndian => write_line_content::<LittleEndian>(writer, geom),
-        Endianness::BigEndian => write_line_content::<BigEndian>(writer, geom),
+        Endianness::LittleEndian => write_line_content::<LittleEndian>(writer, geom, options),
+        Endianness::BigEndian => write_line_content::<BigEndian>(writer, geom, options),
     }
 }
 
 fn write_line_content<B: ByteOrder>(
     writer: &mut impl Write,
     geom: &impl LineTrait<T = f64>,
+    options: &WriteOptions,
 ) -> WkbResult<()> {
     let wkb_type = WkbType::LineString(geom.dim().try_into()?);
-    writer.write_u32::<B>(wkb_type.into())?;
+    write_geometry_code::<B>(writer, wkb_type, options)?;
 
     // numPoints
     writer.write_u32::<B>(2).unwrap();
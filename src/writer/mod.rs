@@ -1,28 +1,46 @@
 //! Write geometries to Well-Known Binary encoding.
 
+mod circularstring;
+mod compoundcurve;
 mod coord;
+mod curvepolygon;
 mod geometry;
 mod geometrycollection;
+pub(crate) mod header;
 mod line;
 mod linestring;
+mod multicurve;
 mod multilinestring;
 mod multipoint;
 mod multipolygon;
+mod multisurface;
 mod options;
 mod point;
 mod polygon;
+mod polyhedralsurface;
 mod rect;
+pub(crate) mod text;
+mod tin;
 mod triangle;
+mod wkb;
 
+pub use circularstring::{circular_string_wkb_size, write_circular_string};
+pub use compoundcurve::{compound_curve_wkb_size, write_compound_curve};
+pub use curvepolygon::{curve_polygon_wkb_size, write_curve_polygon};
 pub use geometry::{geometry_wkb_size, write_geometry};
 pub use geometrycollection::{geometry_collection_wkb_size, write_geometry_collection};
 pub use line::{line_wkb_size, write_line};
 pub use linestring::{line_string_wkb_size, write_line_string};
+pub use multicurve::{multi_curve_wkb_size, write_multi_curve};
 pub use multilinestring::{multi_line_string_wkb_size, write_multi_line_string};
 pub use multipoint::{multi_point_wkb_size, write_multi_point};
 pub use multipolygon::{multi_polygon_wkb_size, write_multi_polygon};
-pub use options::WriteOptions;
+pub use multisurface::{multi_surface_wkb_size, write_multi_surface};
+pub use options::{WkbFlavor, WriteOptions};
 pub use point::{point_wkb_size, write_point};
 pub use polygon::{polygon_wkb_size, write_polygon};
+pub use polyhedralsurface::{polyhedral_surface_wkb_size, write_polyhedral_surface};
 pub use rect::{rect_wkb_size, write_rect};
+pub use tin::{tin_wkb_size, write_tin};
 pub use triangle::{triangle_wkb_size, write_triangle};
+pub use wkb::{wkb_size, write_wkb};
@@ -0,0 +1,60 @@
+use crate::common::WkbType;
+use crate::error::WkbResult;
+use crate::reader::MultiCurve;
+use crate::writer::header::{write_byte_order, write_geometry_code};
+use crate::writer::wkb::{wkb_size, write_wkb};
+use crate::writer::WriteOptions;
+use crate::Endianness;
+use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
+use std::io::Write;
+
+/// The number of bytes this MultiCurve will take up when encoded as WKB
+pub fn multi_curve_wkb_size(geom: &MultiCurve, options: &WriteOptions) -> usize {
+    let mut sum = options.header_size() + 4;
+    let nested_options = options.nested();
+    for curve in geom.curves() {
+        sum += wkb_size(curve, &nested_options);
+    }
+
+    sum
+}
+
+/// Write a MultiCurve geometry to a Writer encoded as WKB
+///
+/// Since [`geo_traits`] has no notion of curves, this takes a parsed [`MultiCurve`] so that the
+/// type of each member is preserved.
+pub fn write_multi_curve(
+    writer: &mut impl Write,
+    geom: &MultiCurve,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    // Byte order
+    write_byte_order(writer, options)?;
+
+    // Content
+    match options.endianness {
+        Endianness::LittleEndian => {
+            write_multi_curve_content::<LittleEndian>(writer, geom, options)
+        }
+        Endianness::BigEndian => write_multi_curve_content::<BigEndian>(writer, geom, options),
+    }
+}
+
+fn write_multi_curve_content<B: ByteOrder>(
+    writer: &mut impl Write,
+    geom: &MultiCurve,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    let wkb_type = WkbType::MultiCurve(geom.dimension());
+    write_geometry_code::<B>(writer, wkb_type, options)?;
+
+    // numCurves
+    writer.write_u32::<B>(geom.num_curves().try_into()?)?;
+
+    let nested_options = options.nested();
+    for curve in geom.curves() {
+        write_wkb(writer, curve, &nested_options)?;
+    }
+
+    Ok(())
+}
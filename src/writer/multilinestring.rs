@@ -1,5 +1,6 @@
 use crate::common::WkbType;
 use crate::error::WkbResult;
+use crate::writer::header::{write_byte_order, write_geometry_code};
 use crate::writer::linestring::{line_string_wkb_size, write_line_string};
 use crate::writer::WriteOptions;
 use crate::Endianness;
@@ -8,10 +9,14 @@ use geo_traits::MultiLineStringTrait;
 use std::io::Write;
 
 /// The number of bytes this MultiLineString will take up when encoded as WKB
-pub fn multi_line_string_wkb_size(geom: &impl MultiLineStringTrait<T = f64>) -> usize {
-    let mut sum = 1 + 4 + 4;
+pub fn multi_line_string_wkb_size(
+    geom: &impl MultiLineStringTrait<T = f64>,
+    options: &WriteOptions,
+) -> usize {
+    let mut sum = options.header_size() + 4;
+    let nested_options = options.nested();
     for line_string in geom.line_strings() {
-        sum += line_string_wkb_size(&line_string);
+        sum += line_string_wkb_size(&line_string, &nested_options);
     }
 
     sum
@@ -24,7 +29,7 @@ pub fn write_multi_line_string(
     options: &WriteOptions,
 ) -> WkbResult<()> {
     // Byte order
-    writer.write_u8(options.endianness.into())?;
+    write_byte_order(writer, options)?;
 
     // Content
     match options.endianness {
@@ -43,13 +48,14 @@ fn write_multi_line_string_content<B: ByteOrder>(
     options: &WriteOptions,
 ) -> WkbResult<()> {
     let wkb_type = WkbType::MultiLineString(geom.dim().try_into()?);
-    writer.write_u32::<B>(wkb_type.into())?;
+    write_geometry_code::<B>(writer, wkb_type, options)?;
 
     // numPoints
     writer.write_u32::<B>(geom.num_line_strings().try_into()?)?;
 
+    let nested_options = options.nested();
     for line_string in geom.line_strings() {
-        write_line_string(writer, &line_string, options)?;
+        write_line_string(writer, &line_string, &nested_options)?;
     }
 
     Ok(())
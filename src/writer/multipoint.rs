@@ -1,5 +1,6 @@
 use crate::common::WkbType;
 use crate::error::WkbResult;
+use crate::writer::header::{write_byte_order, write_geometry_code};
 use crate::writer::point::{point_wkb_size, write_point};
 use crate::writer::WriteOptions;
 use crate::Endianness;
@@ -8,8 +9,9 @@ use geo_traits::MultiPointTrait;
 use std::io::Write;
 
 /// The number of bytes this MultiPoint will take up when encoded as WKB
-pub fn multi_point_wkb_size(geom: &impl MultiPointTrait<T = f64>) -> usize {
-    1 + 4 + 4 + (geom.num_points() * point_wkb_size(geom.dim()))
+pub fn multi_point_wkb_size(geom: &impl MultiPointTrait<T = f64>, options: &WriteOptions) -> usize {
+    let nested_options = options.nested();
+    options.header_size() + 4 + (geom.num_points() * point_wkb_size(geom.dim(), &nested_options))
 }
 
 /// Write a MultiPoint geometry to a Writer encoded as WKB
@@ -19,7 +21,7 @@ pub fn write_multi_point(
     options: &WriteOptions,
 ) -> WkbResult<()> {
     // Byte order
-    writer.write_u8(options.endianness.into())?;
+    write_byte_order(writer, options)?;
 
     // Content
     match options.endianness {
@@ -36,13 +38,14 @@ fn write_multi_point_content<B: ByteOrder>(
     options: &WriteOptions,
 ) -> WkbResult<()> {
     let wkb_type = WkbType::MultiPoint(geom.dim().try_into()?);
-    writer.write_u32::<B>(wkb_type.into())?;
+    write_geometry_code::<B>(writer, wkb_type, options)?;
 
     // numPoints
     writer.write_u32::<B>(geom.num_points().try_into()?)?;
 
+    let nested_options = options.nested();
     for point in geom.points() {
-        write_point(writer, &point, options)?;
+        write_point(writer, &point, &nested_options)?;
     }
 
     Ok(())
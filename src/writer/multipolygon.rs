@@ -1,5 +1,6 @@
 use crate::common::WkbType;
 use crate::error::WkbResult;
+use crate::writer::header::{write_byte_order, write_geometry_code};
 use crate::writer::polygon::{polygon_wkb_size, write_polygon};
 use crate::writer::WriteOptions;
 use crate::Endianness;
@@ -8,10 +9,14 @@ use geo_traits::MultiPolygonTrait;
 use std::io::Write;
 
 /// The number of bytes this MultiPolygon will take up when encoded as WKB
-pub fn multi_polygon_wkb_size(geom: &impl MultiPolygonTrait<T = f64>) -> usize {
-    let mut sum = 1 + 4 + 4;
+pub fn multi_polygon_wkb_size(
+    geom: &impl MultiPolygonTrait<T = f64>,
+    options: &WriteOptions,
+) -> usize {
+    let mut sum = options.header_size() + 4;
+    let nested_options = options.nested();
     for polygon in geom.polygons() {
-        sum += polygon_wkb_size(&polygon);
+        sum += polygon_wkb_size(&polygon, &nested_options);
     }
 
     sum
@@ -24,7 +29,7 @@ pub fn write_multi_polygon(
     options: &WriteOptions,
 ) -> WkbResult<()> {
     // Byte order
-    writer.write_u8(options.endianness.into())?;
+    write_byte_order(writer, options)?;
 
     // Content
     match options.endianness {
@@ -41,13 +46,14 @@ fn write_multi_polygon_content<B: ByteOrder>(
     options: &WriteOptions,
 ) -> WkbResult<()> {
     let wkb_type = WkbType::MultiPolygon(geom.dim().try_into()?);
-    writer.write_u32::<B>(wkb_type.into())?;
+    write_geometry_code::<B>(writer, wkb_type, options)?;
 
     // numPolygons
     writer.write_u32::<B>(geom.num_polygons().try_into()?)?;
 
+    let nested_options = options.nested();
     for polygon in geom.polygons() {
-        write_polygon(writer, &polygon, options)?;
+        write_polygon(writer, &polygon, &nested_options)?;
     }
 
     Ok(())
@@ -0,0 +1,60 @@
+use crate::common::WkbType;
+use crate::error::WkbResult;
+use crate::reader::MultiSurface;
+use crate::writer::header::{write_byte_order, write_geometry_code};
+use crate::writer::wkb::{wkb_size, write_wkb};
+use crate::writer::WriteOptions;
+use crate::Endianness;
+use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
+use std::io::Write;
+
+/// The number of bytes this MultiSurface will take up when encoded as WKB
+pub fn multi_surface_wkb_size(geom: &MultiSurface, options: &WriteOptions) -> usize {
+    let mut sum = options.header_size() + 4;
+    let nested_options = options.nested();
+    for surface in geom.surfaces() {
+        sum += wkb_size(surface, &nested_options);
+    }
+
+    sum
+}
+
+/// Write a MultiSurface geometry to a Writer encoded as WKB
+///
+/// Since [`geo_traits`] has no notion of curves, this takes a parsed [`MultiSurface`] so that the
+/// type of each member is preserved.
+pub fn write_multi_surface(
+    writer: &mut impl Write,
+    geom: &MultiSurface,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    // Byte order
+    write_byte_order(writer, options)?;
+
+    // Content
+    match options.endianness {
+        Endianness::LittleEndian => {
+            write_multi_surface_content::<LittleEndian>(writer, geom, options)
+        }
+        Endianness::BigEndian => write_multi_surface_content::<BigEndian>(writer, geom, options),
+    }
+}
+
+fn write_multi_surface_content<B: ByteOrder>(
+    writer: &mut impl Write,
+    geom: &MultiSurface,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    let wkb_type = WkbType::MultiSurface(geom.dimension());
+    write_geometry_code::<B>(writer, wkb_type, options)?;
+
+    // numSurfaces
+    writer.write_u32::<B>(geom.num_surfaces().try_into()?)?;
+
+    let nested_options = options.nested();
+    for surface in geom.surfaces() {
+        write_wkb(writer, surface, &nested_options)?;
+    }
+
+    Ok(())
+}
@@ -1,8 +1,50 @@
 use crate::Endianness;
 
+/// The flavor of WKB to write
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WkbFlavor {
+    /// ISO WKB, where Z and M dimensions are encoded as 1000, 2000, or 3000 offsets on the
+    /// geometry code.
+    #[default]
+    Iso,
+    /// Extended WKB as used by PostGIS, where Z and M dimensions are encoded as high-bit flags on
+    /// the geometry code and an optional SRID may follow the geometry code.
+    Ewkb,
+}
+
 /// Options for writing geometries to WKB
 #[derive(Debug, Clone, Default)]
 pub struct WriteOptions {
     /// The byte order to use when writing the WKB
     pub endianness: Endianness,
+
+    /// The flavor of WKB to write
+    pub flavor: WkbFlavor,
+
+    /// An SRID to embed in the header of the outermost geometry.
+    ///
+    /// This requires [`WkbFlavor::Ewkb`]. Geometries nested inside a multi geometry or geometry
+    /// collection never carry an SRID of their own.
+    pub srid: Option<u32>,
+
+    /// Whether to write triangles with the native Triangle geometry type.
+    ///
+    /// By default, triangles are written as Polygons for compatibility with readers that only
+    /// support the basic geometry types.
+    pub native_triangles: bool,
+}
+
+impl WriteOptions {
+    /// The options to use when writing a geometry nested inside the current one.
+    pub(crate) fn nested(&self) -> Self {
+        Self {
+            srid: None,
+            ..self.clone()
+        }
+    }
+
+    /// The number of bytes taken by the byte order, the geometry code and any SRID.
+    pub(crate) fn header_size(&self) -> usize {
+        1 + 4 + if self.srid.is_some() { 4 } else { 0 }
+    }
 }
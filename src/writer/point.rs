@@ -1,6 +1,7 @@
 use crate::common::WkbType;
 use crate::error::WkbResult;
 use crate::writer::coord::write_coord;
+use crate::writer::header::{write_byte_order, write_geometry_code};
 use crate::writer::WriteOptions;
 use crate::Endianness;
 use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
@@ -9,8 +10,8 @@ use geo_traits::PointTrait;
 use std::io::Write;
 
 /// The number of bytes this Point will take up when encoded as WKB
-pub fn point_wkb_size(dim: geo_traits::Dimensions) -> usize {
-    let header = 1 + 4;
+pub fn point_wkb_size(dim: geo_traits::Dimensions, options: &WriteOptions) -> usize {
+    let header = options.header_size();
     let coords = dim.size() * 8;
     header + coords
 }
@@ -22,12 +23,12 @@ pub fn write_point(
     options: &WriteOptions,
 ) -> WkbResult<()> {
     // Byte order header
-    writer.write_u8(options.endianness.into())?;
+    write_byte_order(writer, options)?;
 
     // Content
     match options.endianness {
-        Endianness::LittleEndian => write_point_content::<LittleEndian>(writer, geom),
-        Endianness::BigEndian => write_point_content::<BigEndian>(writer, geom),
+        Endianness::LittleEndian => write_point_content::<LittleEndian>(writer, geom, options),
+        Endianness::BigEndian => write_point_content::<BigEndian>(writer, geom, options),
     }
 }
 
@@ -35,9 +36,10 @@ pub fn write_point(
 fn write_point_content<B: ByteOrder>(
     writer: &mut impl Write,
     geom: &impl PointTrait<T = f64>,
+    options: &WriteOptions,
 ) -> WkbResult<()> {
     let wkb_type = WkbType::Point(geom.dim().try_into()?);
-    writer.write_u32::<B>(wkb_type.into())?;
+    write_geometry_code::<B>(writer, wkb_type, options)?;
 
     if let Some(coord) = geom.coord() {
         write_coord::<B>(writer, &coord)?;
@@ -1,6 +1,7 @@
-use crate::common::WkbType;
+use crate::common::{Dimension, WkbType};
 use crate::error::WkbResult;
 use crate::writer::coord::write_coord;
+use crate::writer::header::{write_byte_order, write_geometry_code};
 use crate::writer::WriteOptions;
 use crate::Endianness;
 use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
@@ -8,8 +9,8 @@ use geo_traits::{LineStringTrait, PolygonTrait};
 use std::io::Write;
 
 /// The number of bytes this Polygon will take up when encoded as WKB
-pub fn polygon_wkb_size(geom: &impl PolygonTrait<T = f64>) -> usize {
-    let mut sum = 1 + 4 + 4;
+pub fn polygon_wkb_size(geom: &impl PolygonTrait<T = f64>, options: &WriteOptions) -> usize {
+    let mut sum = options.header_size() + 4;
 
     let each_coord = geom.dim().size() * 8;
 
@@ -29,23 +30,39 @@ pub fn write_polygon(
     writer: &mut impl Write,
     geom: &impl PolygonTrait<T = f64>,
     options: &WriteOptions,
+) -> WkbResult<()> {
+    write_polygon_with_type(writer, geom, WkbType::Polygon, options)
+}
+
+/// Write a geometry that shares the Polygon layout, such as a Triangle, with the given type
+pub(crate) fn write_polygon_with_type(
+    writer: &mut impl Write,
+    geom: &impl PolygonTrait<T = f64>,
+    wkb_type: fn(Dimension) -> WkbType,
+    options: &WriteOptions,
 ) -> WkbResult<()> {
     // Byte order
-    writer.write_u8(options.endianness.into())?;
+    write_byte_order(writer, options)?;
 
     // Content
     match options.endianness {
-        Endianness::LittleEndian => write_polygon_content::<LittleEndian>(writer, geom),
-        Endianness::BigEndian => write_polygon_content::<BigEndian>(writer, geom),
+        Endianness::LittleEndian => {
+            write_polygon_content::<LittleEndian>(writer, geom, wkb_type, options)
+        }
+        Endianness::BigEndian => {
+            write_polygon_content::<BigEndian>(writer, geom, wkb_type, options)
+        }
     }
 }
 
 fn write_polygon_content<B: ByteOrder>(
     writer: &mut impl Write,
     geom: &impl PolygonTrait<T = f64>,
+    wkb_type: fn(Dimension) -> WkbType,
+    options: &WriteOptions,
 ) -> WkbResult<()> {
-    let wkb_type = WkbType::Polygon(geom.dim().try_into()?);
-    writer.write_u32::<B>(wkb_type.into())?;
+    let wkb_type = wkb_type(geom.dim().try_into()?);
+    write_geometry_code::<B>(writer, wkb_type, options)?;
 
     // numRings
     let num_rings = if geom.exterior().is_some() {
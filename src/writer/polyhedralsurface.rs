@@ -0,0 +1,59 @@
+use crate::common::WkbType;
+use crate::error::WkbResult;
+use crate::writer::header::{write_byte_order, write_geometry_code};
+use crate::writer::multipolygon::multi_polygon_wkb_size;
+use crate::writer::polygon::write_polygon_with_type;
+use crate::writer::WriteOptions;
+use crate::Endianness;
+use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
+use geo_traits::MultiPolygonTrait;
+use std::io::Write;
+
+/// The number of bytes this PolyhedralSurface will take up when encoded as WKB
+pub fn polyhedral_surface_wkb_size(
+    geom: &impl MultiPolygonTrait<T = f64>,
+    options: &WriteOptions,
+) -> usize {
+    multi_polygon_wkb_size(geom, options)
+}
+
+/// Write a PolyhedralSurface geometry to a Writer encoded as WKB
+///
+/// Each polygon of `geom` is written as a patch of the surface.
+pub fn write_polyhedral_surface(
+    writer: &mut impl Write,
+    geom: &impl MultiPolygonTrait<T = f64>,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    // Byte order
+    write_byte_order(writer, options)?;
+
+    // Content
+    match options.endianness {
+        Endianness::LittleEndian => {
+            write_polyhedral_surface_content::<LittleEndian>(writer, geom, options)
+        }
+        Endianness::BigEndian => {
+            write_polyhedral_surface_content::<BigEndian>(writer, geom, options)
+        }
+    }
+}
+
+fn write_polyhedral_surface_content<B: ByteOrder>(
+    writer: &mut impl Write,
+    geom: &impl MultiPolygonTrait<T = f64>,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    let wkb_type = WkbType::PolyhedralSurface(geom.dim().try_into()?);
+    write_geometry_code::<B>(writer, wkb_type, options)?;
+
+    // numPolygons
+    writer.write_u32::<B>(geom.num_polygons().try_into()?)?;
+
+    let nested_options = options.nested();
+    for polygon in geom.polygons() {
+        write_polygon_with_type(writer, &polygon, WkbType::Polygon, &nested_options)?;
+    }
+
+    Ok(())
+}
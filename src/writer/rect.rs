@@ -1,5 +1,6 @@
 use crate::common::WkbType;
 use crate::error::WkbResult;
+use crate::writer::header::{write_byte_order, write_geometry_code};
 use crate::writer::WriteOptions;
 use crate::Endianness;
 use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
@@ -10,8 +11,8 @@ use std::io::Write;
 ///
 /// Note that only 2D Rects are supported. Even if the input Rect has more than 2 dimensions, only
 /// the X and Y dimensions will be written.
-pub fn rect_wkb_size(geom: &impl RectTrait<T = f64>) -> usize {
-    let header = 1 + 4 + 4;
+pub fn rect_wkb_size(geom: &impl RectTrait<T = f64>, options: &WriteOptions) -> usize {
+    let header = options.header_size() + 4;
     let each_coord = geom.dim().size() * 8;
     let all_coords = 5 * each_coord;
     header + all_coords
@@ -27,12 +28,12 @@ pub fn write_rect(
     options: &WriteOptions,
 ) -> WkbResult<()> {
     // Byte order
-    writer.write_u8(options.endianness.into())?;
+    write_byte_order(writer, options)?;
 
     // Content
     match options.endianness {
-        Endianness::LittleEndian => write_rect_content::<LittleEndian>(writer, geom),
-        Endianness::BigEndian => write_rect_content::<BigEndian>(writer, geom),
+        Endianness::LittleEndian => write_rect_content::<LittleEndian>(writer, geom, options),
+        Endianness::BigEndian => write_rect_content::<BigEndian>(writer, geom, options),
     }
 }
 
@@ -45,9 +46,10 @@ struct Coord {
 fn write_rect_content<B: ByteOrder>(
     writer: &mut impl Write,
     geom: &impl RectTrait<T = f64>,
+    options: &WriteOptions,
 ) -> WkbResult<()> {
     let wkb_type = WkbType::Polygon(geom.dim().try_into()?);
-    writer.write_u32::<B>(wkb_type.into())?;
+    write_geometry_code::<B>(writer, wkb_type, options)?;
 
     // numRings
     let num_rings = 1;
@@ -0,0 +1,146 @@
+//! Building blocks for the parsers that convert text formats, such as WKT and GeoJSON, to WKB.
+//!
+//! These parsers write each geometry as it is parsed, without building intermediate objects.
+//! Because WKB stores element counts before the elements themselves, each count is reserved by
+//! [`WkbOutput::begin_count`] and filled in by [`WkbOutput::end_count`] once its elements have
+//! been written, which is why the parsers write to a `Vec` rather than any
+//! [`Write`][std::io::Write].
+
+use std::marker::PhantomData;
+
+use byteorder::{ByteOrder, WriteBytesExt};
+
+use crate::common::{Dimension, WkbType};
+use crate::error::{WkbError, WkbPath, WkbResult};
+use crate::reader::{ReadLimit, ReadOptions};
+use crate::writer::header::{check_options, write_geometry_code};
+use crate::writer::WriteOptions;
+
+/// The text being parsed and the position of the next unread byte
+pub(crate) struct TextInput<'t> {
+    pub(crate) text: &'t [u8],
+    pub(crate) pos: usize,
+}
+
+impl<'t> TextInput<'t> {
+    pub(crate) fn new(text: &'t [u8], pos: usize) -> Self {
+        Self { text, pos }
+    }
+
+    /// The next character that is not whitespace
+    pub(crate) fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.text.get(self.pos).copied()
+    }
+
+    /// Consume `expected` if it is the next character that is not whitespace
+    pub(crate) fn consume(&mut self, expected: u8) -> bool {
+        let found = self.peek() == Some(expected);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    pub(crate) fn skip_whitespace(&mut self) {
+        self.pos += self.text[self.pos..]
+            .iter()
+            .take_while(|b| b.is_ascii_whitespace())
+            .count();
+    }
+}
+
+/// Whether `b` can be the first character of a number
+pub(crate) fn starts_number(b: u8) -> bool {
+    b.is_ascii_digit() || matches!(b, b'+' | b'-' | b'.')
+}
+
+/// An element count that has been reserved but not yet filled in
+#[must_use]
+pub(crate) struct CountSlot(usize);
+
+/// The WKB written by a text parser
+pub(crate) struct WkbOutput<'o, B: ByteOrder> {
+    buf: &'o mut Vec<u8>,
+    depth: usize,
+    max_depth: usize,
+    byte_order: PhantomData<B>,
+}
+
+impl<'o, B: ByteOrder> WkbOutput<'o, B> {
+    /// Append to `buf`, limiting nesting to the default [`ReadOptions::max_depth`]
+    pub(crate) fn new(buf: &'o mut Vec<u8>) -> Self {
+        Self {
+            buf,
+            depth: 0,
+            max_depth: ReadOptions::default().max_depth,
+            byte_order: PhantomData,
+        }
+    }
+
+    /// Write the byte order and geometry code of a geometry, returning the offset of the header
+    pub(crate) fn header(&mut self, wkb_type: WkbType, options: &WriteOptions) -> WkbResult<usize> {
+        check_options(options)?;
+        let offset = self.buf.len();
+        self.buf.push(options.endianness.into());
+        write_geometry_code::<B>(self.buf, wkb_type, options)?;
+        Ok(offset)
+    }
+
+    /// Replace the geometry code of the header written at `offset`, once the type or dimension of
+    /// the geometry is known
+    pub(crate) fn rewrite_header(
+        &mut self,
+        offset: usize,
+        wkb_type: WkbType,
+        options: &WriteOptions,
+    ) -> WkbResult<()> {
+        let mut code = &mut self.buf[offset + 1..];
+        write_geometry_code::<B>(&mut code, wkb_type, options)
+    }
+
+    pub(crate) fn ordinate(&mut self, value: f64) -> WkbResult<()> {
+        Ok(self.buf.write_f64::<B>(value)?)
+    }
+
+    /// Write the NaN coordinates of an empty Point
+    pub(crate) fn empty_coord(&mut self, dim: Dimension) -> WkbResult<()> {
+        for _ in 0..dim.size() {
+            self.ordinate(f64::NAN)?;
+        }
+        Ok(())
+    }
+
+    /// Reserve space for an element count, which is zero until it is filled in
+    pub(crate) fn begin_count(&mut self) -> WkbResult<CountSlot> {
+        let offset = self.buf.len();
+        self.buf.write_u32::<B>(0)?;
+        Ok(CountSlot(offset))
+    }
+
+    /// Fill in a reserved element count
+    pub(crate) fn end_count(&mut self, slot: CountSlot, count: usize) -> WkbResult<()> {
+        B::write_u32(&mut self.buf[slot.0..slot.0 + 4], u32::try_from(count)?);
+        Ok(())
+    }
+
+    /// Descend into a geometry that starts at `offset` in the text, failing if it would be
+    /// nested too deeply
+    pub(crate) fn enter(&mut self, offset: usize) -> WkbResult<()> {
+        if self.depth >= self.max_depth {
+            return Err(WkbError::LimitExceeded {
+                limit: ReadLimit::Depth,
+                max: self.max_depth,
+                offset: offset as u64,
+                path: WkbPath::default(),
+            });
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    /// Return from a geometry entered with [`WkbOutput::enter`]
+    pub(crate) fn leave(&mut self) {
+        self.depth -= 1;
+    }
+}
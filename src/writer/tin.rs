@@ -0,0 +1,66 @@
+use crate::common::WkbType;
+use crate::error::{WkbError, WkbResult};
+use crate::writer::header::{write_byte_order, write_geometry_code};
+use crate::writer::multipolygon::multi_polygon_wkb_size;
+use crate::writer::polygon::write_polygon_with_type;
+use crate::writer::WriteOptions;
+use crate::Endianness;
+use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
+use geo_traits::{LineStringTrait, MultiPolygonTrait, PolygonTrait};
+use std::io::Write;
+
+/// The number of bytes this TIN will take up when encoded as WKB
+pub fn tin_wkb_size(geom: &impl MultiPolygonTrait<T = f64>, options: &WriteOptions) -> usize {
+    multi_polygon_wkb_size(geom, options)
+}
+
+/// Write a TIN geometry to a Writer encoded as WKB
+///
+/// Each polygon of `geom` is written as a Triangle, and an error is returned unless every polygon
+/// has a single ring of four points.
+pub fn write_tin(
+    writer: &mut impl Write,
+    geom: &impl MultiPolygonTrait<T = f64>,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    // Byte order
+    write_byte_order(writer, options)?;
+
+    // Content
+    match options.endianness {
+        Endianness::LittleEndian => write_tin_content::<LittleEndian>(writer, geom, options),
+        Endianness::BigEndian => write_tin_content::<BigEndian>(writer, geom, options),
+    }
+}
+
+fn write_tin_content<B: ByteOrder>(
+    writer: &mut impl Write,
+    geom: &impl MultiPolygonTrait<T = f64>,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    if !geom.polygons().all(|polygon| is_triangle(&polygon)) {
+        return Err(WkbError::General(
+            "Each TIN patch must have a single ring of four points".to_string(),
+        ));
+    }
+
+    let wkb_type = WkbType::Tin(geom.dim().try_into()?);
+    write_geometry_code::<B>(writer, wkb_type, options)?;
+
+    // numPolygons
+    writer.write_u32::<B>(geom.num_polygons().try_into()?)?;
+
+    let nested_options = options.nested();
+    for polygon in geom.polygons() {
+        write_polygon_with_type(writer, &polygon, WkbType::Triangle, &nested_options)?;
+    }
+
+    Ok(())
+}
+
+fn is_triangle(polygon: &impl PolygonTrait<T = f64>) -> bool {
+    polygon.num_interiors() == 0
+        && polygon
+            .exterior()
+            .is_some_and(|exterior| exterior.num_coords() == 4)
+}
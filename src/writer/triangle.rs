@@ -1,6 +1,7 @@
 use crate::common::WkbType;
 use crate::error::WkbResult;
 use crate::writer::coord::write_coord;
+use crate::writer::header::{write_byte_order, write_geometry_code};
 use crate::writer::WriteOptions;
 use crate::Endianness;
 use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
@@ -8,35 +9,43 @@ use geo_traits::TriangleTrait;
 use std::io::Write;
 
 /// The number of bytes this Triangle will take up when encoded as WKB
-pub fn triangle_wkb_size(geom: &impl TriangleTrait<T = f64>) -> usize {
-    let header = 1 + 4 + 4;
+pub fn triangle_wkb_size(geom: &impl TriangleTrait<T = f64>, options: &WriteOptions) -> usize {
+    let header = options.header_size() + 4;
     let each_coord = geom.dim().size() * 8;
     let all_coords = 4 * each_coord;
     header + all_coords
 }
 
 /// Write a Triangle geometry to a Writer encoded as WKB
+///
+/// The Triangle is written as a Polygon unless [`WriteOptions::native_triangles`] is set.
 pub fn write_triangle(
     writer: &mut impl Write,
     geom: &impl TriangleTrait<T = f64>,
     options: &WriteOptions,
 ) -> WkbResult<()> {
     // Byte order
-    writer.write_u8(options.endianness.into())?;
+    write_byte_order(writer, options)?;
 
     // Content
     match options.endianness {
-        Endianness::LittleEndian => write_triangle_content::<LittleEndian>(writer, geom),
-        Endianness::BigEndian => write_triangle_content::<BigEndian>(writer, geom),
+        Endianness::LittleEndian => write_triangle_content::<LittleEndian>(writer, geom, options),
+        Endianness::BigEndian => write_triangle_content::<BigEndian>(writer, geom, options),
     }
 }
 
 fn write_triangle_content<B: ByteOrder>(
     writer: &mut impl Write,
     geom: &impl TriangleTrait<T = f64>,
+    options: &WriteOptions,
 ) -> WkbResult<()> {
-    let wkb_type = WkbType::Polygon(geom.dim().try_into()?);
-    writer.write_u32::<B>(wkb_type.into())?;
+    let dim = geom.dim().try_into()?;
+    let wkb_type = if options.native_triangles {
+        WkbType::Triangle(dim)
+    } else {
+        WkbType::Polygon(dim)
+    };
+    write_geometry_code::<B>(writer, wkb_type, options)?;
 
     // numRings
     let num_rings = 1;
@@ -0,0 +1,103 @@
+use crate::common::WkbType;
+use crate::error::WkbResult;
+use crate::reader::{GeometryCollection, Wkb, WkbInner};
+use crate::writer::header::{write_byte_order, write_geometry_code};
+use crate::writer::polygon::write_polygon_with_type;
+use crate::writer::{
+    circular_string_wkb_size, compound_curve_wkb_size, curve_polygon_wkb_size, geometry_wkb_size,
+    multi_curve_wkb_size, multi_surface_wkb_size, polygon_wkb_size, polyhedral_surface_wkb_size,
+    tin_wkb_size, write_circular_string, write_compound_curve, write_curve_polygon, write_geometry,
+    write_multi_curve, write_multi_surface, write_polyhedral_surface, write_tin, WriteOptions,
+};
+use crate::Endianness;
+use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
+use geo_traits::GeometryCollectionTrait;
+use std::io::Write;
+
+/// The number of bytes this parsed WKB geometry will take up when re-encoded as WKB
+pub fn wkb_size(geom: &Wkb, options: &WriteOptions) -> usize {
+    match geom.inner() {
+        WkbInner::CircularString(cs) => circular_string_wkb_size(cs, options),
+        WkbInner::CompoundCurve(cc) => compound_curve_wkb_size(cc, options),
+        WkbInner::CurvePolygon(cp) => curve_polygon_wkb_size(cp, options),
+        WkbInner::MultiCurve(mc) => multi_curve_wkb_size(mc, options),
+        WkbInner::MultiSurface(ms) => multi_surface_wkb_size(ms, options),
+        WkbInner::PolyhedralSurface(ps) => {
+            polyhedral_surface_wkb_size(ps.as_multi_polygon(), options)
+        }
+        WkbInner::Tin(tin) => tin_wkb_size(tin.as_multi_polygon(), options),
+        WkbInner::Triangle(tri) => polygon_wkb_size(tri.as_polygon(), options),
+        WkbInner::GeometryCollection(gc) => {
+            let mut sum = options.header_size() + 4;
+            let nested_options = options.nested();
+            for inner_geom in gc.geometries() {
+                sum += wkb_size(inner_geom, &nested_options);
+            }
+            sum
+        }
+        _ => geometry_wkb_size(geom, options),
+    }
+}
+
+/// Re-encode a parsed WKB geometry to a Writer
+///
+/// Unlike [`write_geometry`], this preserves geometry types that cannot be represented through
+/// [`geo_traits`], such as curves and surfaces, including when they are nested inside a
+/// GeometryCollection. This is useful for converting between byte orders or between ISO WKB and
+/// EWKB.
+pub fn write_wkb(writer: &mut impl Write, geom: &Wkb, options: &WriteOptions) -> WkbResult<()> {
+    match geom.inner() {
+        WkbInner::CircularString(cs) => write_circular_string(writer, cs, options),
+        WkbInner::CompoundCurve(cc) => write_compound_curve(writer, cc, options),
+        WkbInner::CurvePolygon(cp) => write_curve_polygon(writer, cp, options),
+        WkbInner::MultiCurve(mc) => write_multi_curve(writer, mc, options),
+        WkbInner::MultiSurface(ms) => write_multi_surface(writer, ms, options),
+        WkbInner::PolyhedralSurface(ps) => {
+            write_polyhedral_surface(writer, ps.as_multi_polygon(), options)
+        }
+        WkbInner::Tin(tin) => write_tin(writer, tin.as_multi_polygon(), options),
+        WkbInner::Triangle(tri) => {
+            write_polygon_with_type(writer, tri.as_polygon(), WkbType::Triangle, options)
+        }
+        WkbInner::GeometryCollection(gc) => write_wkb_geometry_collection(writer, gc, options),
+        _ => write_geometry(writer, geom, options),
+    }
+}
+
+fn write_wkb_geometry_collection(
+    writer: &mut impl Write,
+    geom: &GeometryCollection,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    // Byte order
+    write_byte_order(writer, options)?;
+
+    // Content
+    match options.endianness {
+        Endianness::LittleEndian => {
+            write_wkb_geometry_collection_content::<LittleEndian>(writer, geom, options)
+        }
+        Endianness::BigEndian => {
+            write_wkb_geometry_collection_content::<BigEndian>(writer, geom, options)
+        }
+    }
+}
+
+fn write_wkb_geometry_collection_content<B: ByteOrder>(
+    writer: &mut impl Write,
+    geom: &GeometryCollection,
+    options: &WriteOptions,
+) -> WkbResult<()> {
+    let wkb_type = WkbType::GeometryCollection(geom.dimension());
+    write_geometry_code::<B>(writer, wkb_type, options)?;
+
+    // numGeometries
+    writer.write_u32::<B>(geom.num_geometries().try_into()?)?;
+
+    let nested_options = options.nested();
+    for inner_geom in geom.geometries() {
+        write_wkb(writer, inner_geom, &nested_options)?;
+    }
+
+    Ok(())
+}